@@ -3,7 +3,9 @@
 
 use crate::cargo_clippy_path;
 use std::ffi::OsStr;
+use std::fmt::Write as _;
 use std::fs;
+use std::io::{self, Write as _};
 use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 use walkdir::{DirEntry, WalkDir};
@@ -11,42 +13,231 @@ use walkdir::{DirEntry, WalkDir};
 static CLIPPY_BUILD_TIME: LazyLock<Option<std::time::SystemTime>> =
     LazyLock::new(|| cargo_clippy_path().metadata().ok()?.modified().ok());
 
+/// Which of the changed reference files are updated
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BlessMode {
+    /// All of them
+    All,
+    /// The ones accepted after showing their diff
+    Interactive,
+    /// The ones where lines were only removed
+    OnlyRemovals,
+    /// The ones where lines were only added, e.g. new test files
+    OnlyAdditions,
+}
+
+/// Updates the reference files of the tests whose path relative to `tests/` contains one of the
+/// `filters`, or of every test if there are none, and of the tests of `lint` if it is given.
+///
 /// # Panics
 ///
 /// Panics if the path to a test file is broken
-pub fn bless(ignore_timestamp: bool) {
+pub fn bless(ignore_timestamp: bool, filters: &[&str], lint: Option<&str>, mut mode: BlessMode) {
     let extensions = ["stdout", "stderr", "fixed"].map(OsStr::new);
 
-    WalkDir::new(build_dir())
+    let mut entries: Vec<_> = WalkDir::new(build_dir())
         .into_iter()
         .map(Result::unwrap)
         .filter(|entry| entry.path().extension().map_or(false, |ext| extensions.contains(&ext)))
-        .for_each(|entry| update_reference_file(&entry, ignore_timestamp));
+        .collect();
+    entries.sort_by(|a, b| a.path().cmp(b.path()));
+
+    for entry in entries {
+        let Some(update) = ReferenceUpdate::new(&entry, ignore_timestamp) else {
+            continue;
+        };
+        if !update.matches(filters, lint) {
+            continue;
+        }
+
+        let diff = diff_lines(&update.reference, &update.output);
+        let reference_path = update.reference_path.display();
+        match mode {
+            BlessMode::All => {},
+            BlessMode::Interactive => {
+                print!("{}", unified_diff(&diff, &update.reference_path));
+                match prompt(&format!("bless {reference_path}? [y]es, [n]o, [a]ll, [q]uit")) {
+                    'y' => {},
+                    'a' => mode = BlessMode::All,
+                    'q' => return,
+                    _ => continue,
+                }
+            },
+            BlessMode::OnlyRemovals if diff.iter().any(|line| matches!(line, Line::Added(_))) => {
+                println!("skipping {reference_path}, it has added lines");
+                continue;
+            },
+            BlessMode::OnlyAdditions if diff.iter().any(|line| matches!(line, Line::Removed(_))) => {
+                println!("skipping {reference_path}, it has removed lines");
+                continue;
+            },
+            BlessMode::OnlyRemovals | BlessMode::OnlyAdditions => {},
+        }
+
+        // If a test run caused an output file to change, update the reference file
+        println!("updating {reference_path}");
+        fs::copy(entry.path(), &update.reference_path).expect("Could not update reference file");
+    }
+}
+
+/// A test output file that differs from its reference file
+struct ReferenceUpdate {
+    reference_path: PathBuf,
+    reference: String,
+    output: String,
 }
 
-fn update_reference_file(test_output_entry: &DirEntry, ignore_timestamp: bool) {
-    let test_output_path = test_output_entry.path();
+impl ReferenceUpdate {
+    fn new(test_output_entry: &DirEntry, ignore_timestamp: bool) -> Option<Self> {
+        let test_output_path = test_output_entry.path();
 
-    let reference_file_name = test_output_entry.file_name().to_str().unwrap().replace(".stage-id", "");
-    let reference_file_path = Path::new("tests")
-        .join(test_output_path.strip_prefix(build_dir()).unwrap())
-        .with_file_name(reference_file_name);
+        let reference_file_name = test_output_entry.file_name().to_str().unwrap().replace(".stage-id", "");
+        let reference_path = Path::new("tests")
+            .join(test_output_path.strip_prefix(build_dir()).unwrap())
+            .with_file_name(reference_file_name);
 
-    // If the test output was not updated since the last clippy build, it may be outdated
-    if !ignore_timestamp && !updated_since_clippy_build(test_output_entry).unwrap_or(true) {
-        return;
+        // If the test output was not updated since the last clippy build, it may be outdated
+        if !ignore_timestamp && !updated_since_clippy_build(test_output_entry).unwrap_or(true) {
+            return None;
+        }
+
+        let test_output_file = fs::read(test_output_path).expect("Unable to read test output file");
+        let reference_file = fs::read(&reference_path).unwrap_or_default();
+
+        (test_output_file != reference_file).then(|| Self {
+            reference_path,
+            reference: String::from_utf8_lossy(&reference_file).into_owned(),
+            output: String::from_utf8_lossy(&test_output_file).into_owned(),
+        })
     }
 
-    let test_output_file = fs::read(test_output_path).expect("Unable to read test output file");
-    let reference_file = fs::read(&reference_file_path).unwrap_or_default();
+    /// Whether the test path relative to `tests/` contains one of the filters, and the test file
+    /// or its outputs mention `lint`
+    fn matches(&self, filters: &[&str], lint: Option<&str>) -> bool {
+        let test_path = self.reference_path.with_extension("rs");
+        let test_name = test_path.strip_prefix("tests").unwrap_or(&test_path).with_extension("");
+        let test_name = test_name.to_string_lossy().replace('\\', "/");
+        if !filters.is_empty() && !filters.iter().any(|filter| test_name.contains(filter)) {
+            return false;
+        }
 
-    if test_output_file != reference_file {
-        // If a test run caused an output file to change, update the reference file
-        println!("updating {}", reference_file_path.display());
-        fs::copy(test_output_path, &reference_file_path).expect("Could not update reference file");
+        lint.map_or(true, |lint| {
+            let lint = lint.trim_start_matches("clippy::");
+            let names = [
+                format!("clippy::{}", lint.replace('-', "_")),
+                format!("clippy::{}", lint.replace('_', "-")),
+            ];
+            let source = fs::read_to_string(&test_path).unwrap_or_default();
+            [&source, &self.reference, &self.output]
+                .iter()
+                .any(|text| names.iter().any(|name| text.contains(name.as_str())))
+        })
     }
 }
 
+/// Prints the question and returns the first character of the answer, `n` if there is none
+fn prompt(question: &str) -> char {
+    print!("{question} ");
+    let _ = io::stdout().flush();
+    let mut answer = String::new();
+    let _ = io::stdin().read_line(&mut answer);
+    answer.trim().chars().next().map_or('n', |c| c.to_ascii_lowercase())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Line<'a> {
+    Equal(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// The lines of `new` compared to the lines of `old`, from their longest common subsequence
+fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<Line<'a>> {
+    let old: Vec<_> = old.lines().collect();
+    let new: Vec<_> = new.lines().collect();
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (a, b) = (&old[prefix..old.len() - suffix], &new[prefix..new.len() - suffix]);
+
+    // `lcs[i][j]` is the length of the longest common subsequence of `a[i..]` and `b[j..]`
+    let mut lcs = vec![vec![0u32; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines: Vec<_> = old[..prefix].iter().map(|line| Line::Equal(line)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            lines.push(Line::Equal(a[i]));
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(Line::Removed(a[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(b[j]));
+            j += 1;
+        }
+    }
+    lines.extend(old[old.len() - suffix..].iter().map(|line| Line::Equal(line)));
+    lines
+}
+
+/// Formats the changed lines with 3 lines of context, like `diff -u`
+fn unified_diff(lines: &[Line<'_>], path: &Path) -> String {
+    const CONTEXT: usize = 3;
+
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for (i, _) in lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Line::Equal(_)))
+    {
+        let (start, end) = (i.saturating_sub(CONTEXT), (i + CONTEXT + 1).min(lines.len()));
+        match hunks.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let path = path.display();
+    let mut diff = format!("--- {path}\n+++ {path} (new output)\n");
+    for (start, end) in hunks {
+        let old_start = 1 + lines[..start]
+            .iter()
+            .filter(|line| !matches!(line, Line::Added(_)))
+            .count();
+        let new_start = 1 + lines[..start]
+            .iter()
+            .filter(|line| !matches!(line, Line::Removed(_)))
+            .count();
+        let hunk = &lines[start..end];
+        let old_len = hunk.iter().filter(|line| !matches!(line, Line::Added(_))).count();
+        let new_len = hunk.iter().filter(|line| !matches!(line, Line::Removed(_))).count();
+        let _ = writeln!(diff, "@@ -{old_start},{old_len} +{new_start},{new_len} @@");
+        for line in hunk {
+            let _ = match line {
+                Line::Equal(line) => writeln!(diff, " {line}"),
+                Line::Removed(line) => writeln!(diff, "-{line}"),
+                Line::Added(line) => writeln!(diff, "+{line}"),
+            };
+        }
+    }
+    diff
+}
+
 fn updated_since_clippy_build(entry: &DirEntry) -> Option<bool> {
     let clippy_build_time = (*CLIPPY_BUILD_TIME)?;
     let modified = entry.metadata().ok()?.modified().ok()?;
@@ -58,3 +249,53 @@ fn build_dir() -> PathBuf {
     path.set_file_name("test");
     path
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines() {
+        assert_eq!(
+            diff_lines("a\nb\nc\nd\n", "a\nc\nx\nd\ne\n"),
+            [
+                Line::Equal("a"),
+                Line::Removed("b"),
+                Line::Equal("c"),
+                Line::Added("x"),
+                Line::Equal("d"),
+                Line::Added("e"),
+            ]
+        );
+        assert_eq!(diff_lines("", "a\n"), [Line::Added("a")]);
+        assert_eq!(diff_lines("a\nb\n", "a\n"), [Line::Equal("a"), Line::Removed("b")]);
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n";
+        let new = "1\n2\nthree\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n";
+        assert_eq!(
+            unified_diff(&diff_lines(old, new), Path::new("tests/ui/a.stderr")),
+            "--- tests/ui/a.stderr\n\
+            +++ tests/ui/a.stderr (new output)\n\
+            @@ -1,6 +1,6 @@\n 1\n 2\n-3\n+three\n 4\n 5\n 6\n\
+            @@ -10,3 +10,4 @@\n 10\n 11\n 12\n+13\n"
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        let update = ReferenceUpdate {
+            reference_path: PathBuf::from("tests/ui-toml/dbg_macro/dbg_macro.stderr"),
+            reference: String::new(),
+            output: "= note: `-D clippy::dbg-macro` implied by `-D warnings`".to_string(),
+        };
+        assert!(update.matches(&[], None));
+        assert!(update.matches(&["ui-toml/dbg"], None));
+        assert!(!update.matches(&["ui/dbg_macro"], None));
+        assert!(update.matches(&["dbg_macro"], Some("dbg_macro")));
+        assert!(update.matches(&[], Some("clippy::dbg-macro")));
+        assert!(!update.matches(&[], Some("needless_return")));
+    }
+}
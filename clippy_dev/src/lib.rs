@@ -14,6 +14,7 @@ pub mod bless;
 pub mod dogfood;
 pub mod fmt;
 pub mod lint;
+pub mod lint_fp;
 pub mod new_lint;
 pub mod serve;
 pub mod setup;
@@ -1,4 +1,6 @@
 use crate::cargo_clippy_path;
+use std::ffi::OsStr;
+use std::path::Path;
 use std::process::{self, Command, ExitStatus};
 use std::{fs, io};
 
@@ -23,18 +25,9 @@ pub fn run<'a>(path: &str, args: impl Iterator<Item = &'a String>) {
     };
 
     if is_file {
-        exit_if_err(
-            Command::new("cargo")
-                .args(["run", "--bin", "clippy-driver", "--"])
-                .args(["-L", "./target/debug"])
-                .args(["-Z", "no-codegen"])
-                .args(["--edition", "2021"])
-                .arg(path)
-                .args(args)
-                .status(),
-        );
+        exit_if_err(driver_command(Path::new(path), "2021").args(args).status());
     } else {
-        exit_if_err(Command::new("cargo").arg("build").status());
+        build_clippy();
 
         // Run in a tempdir as changes to clippy do not retrigger linting
         let target = tempfile::Builder::new()
@@ -42,14 +35,38 @@ pub fn run<'a>(path: &str, args: impl Iterator<Item = &'a String>) {
             .tempdir()
             .expect("failed to create tempdir");
 
-        let status = Command::new(cargo_clippy_path())
-            .arg("clippy")
-            .args(args)
-            .current_dir(path)
-            .env("CARGO_TARGET_DIR", target.as_ref())
-            .status();
+        let status = cargo_clippy_command(Path::new(path), target.path()).args(args).status();
 
         target.close().expect("failed to remove tempdir");
         exit_if_err(status);
     }
 }
+
+/// Builds `cargo-clippy` and `clippy-driver`, exiting if the build fails
+pub(crate) fn build_clippy() {
+    exit_if_err(Command::new("cargo").arg("build").status());
+}
+
+/// Returns the command running `clippy-driver` on a single file, through `cargo run` so the driver
+/// is built first
+pub(crate) fn driver_command(path: &Path, edition: &str) -> Command {
+    let mut command = Command::new("cargo");
+    command
+        .args(["run", "--bin", "clippy-driver", "--"])
+        .args(["-L", "./target/debug"])
+        .args(["-Z", "no-codegen"])
+        .args(["--edition", edition])
+        .arg(path);
+    command
+}
+
+/// Returns the command running `cargo clippy` on the package in `path`, which needs `cargo-clippy`
+/// to be built
+pub(crate) fn cargo_clippy_command(path: &Path, target_dir: impl AsRef<OsStr>) -> Command {
+    let mut command = Command::new(cargo_clippy_path());
+    command
+        .arg("clippy")
+        .current_dir(path)
+        .env("CARGO_TARGET_DIR", target_dir);
+    command
+}
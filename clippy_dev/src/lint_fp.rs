@@ -0,0 +1,807 @@
+//! `lint-fp` reduces a crate where a lint fires to a standalone `tests/ui` file, to triage false
+//! positives. The modules of a copy of the crate are inlined in its root file, then the items,
+//! statements and match arms are removed one by one as long as the crate still compiles and the
+//! lint still fires at the same span.
+
+use crate::lint::{build_clippy, cargo_clippy_command, driver_command};
+use rustc_lexer::TokenKind;
+use serde_json::Value;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, Write as _};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use walkdir::WalkDir;
+
+/// Runs the `lint-fp` command on the package in `path`, writing the reduced file to `output` or
+/// `tests/ui/<lint>_fp.rs`
+pub fn run(path: &str, lint: &str, snippet: Option<&str>, output: Option<&str>) {
+    let lint = lint.trim_start_matches("clippy::").replace('-', "_");
+    let output = output.map_or_else(|| PathBuf::from(format!("tests/ui/{lint}_fp.rs")), PathBuf::from);
+    if output.exists() {
+        eprintln!("error: `{}` already exists", output.display());
+        process::exit(1);
+    }
+
+    build_clippy();
+
+    if let Err(e) = try_run(Path::new(path), &lint, snippet, &output) {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
+
+fn try_run(crate_dir: &Path, lint: &str, snippet: Option<&str>, output: &Path) -> Result<(), String> {
+    let (package, workspace_root) = package_metadata(crate_dir)?;
+    let (root, is_lib) = [("src/lib.rs", true), ("src/main.rs", false)]
+        .into_iter()
+        .find(|(root, _)| crate_dir.join(root).is_file())
+        .ok_or_else(|| format!("`{}` has no `src/lib.rs` or `src/main.rs`", crate_dir.display()))?;
+
+    let work_dir = tempfile::Builder::new()
+        .prefix("lint-fp")
+        .tempdir()
+        .map_err(|e| format!("failed to create the copy of the crate: {e}"))?;
+    copy_crate(crate_dir, work_dir.path(), &package, &workspace_root)
+        .map_err(|e| format!("failed to copy the crate: {e}"))?;
+    // Run in a tempdir as changes to clippy do not retrigger linting
+    let target_dir = tempfile::Builder::new()
+        .prefix("clippy")
+        .tempdir()
+        .map_err(|e| format!("failed to create tempdir: {e}"))?;
+
+    let reducer = Reducer {
+        dir: work_dir.path(),
+        root: Path::new(root),
+        is_lib,
+        lint,
+        target_dir: target_dir.path(),
+    };
+    let root_path = work_dir.path().join(root);
+    let source = inline_modules(&root_path, root_path.parent().unwrap())
+        .map_err(|e| format!("failed to inline the modules of the crate: {e}"))?;
+
+    println!("Linting `{}`", crate_dir.display());
+    let spans = reducer
+        .lint_spans(&source)
+        .map_err(|e| format!("failed to run Clippy: {e}"))?
+        .ok_or("the crate doesn't compile once its modules are inlined")?;
+    let span = spans
+        .into_iter()
+        .find(|span| snippet.map_or(true, |snippet| source[span.clone()].contains(snippet)))
+        .ok_or_else(|| format!("`clippy::{lint}` isn't emitted in the crate"))?;
+    println!("Reducing the crate around `{}`", &source[span.clone()]);
+
+    let source = reducer
+        .reduce(source, span)
+        .map_err(|e| format!("failed to run Clippy: {e}"))?;
+    println!();
+
+    let edition = package["edition"].as_str().unwrap_or("2015");
+    let mut contents = String::new();
+    if edition != "2021" {
+        let _ = writeln!(contents, "// edition:{edition}");
+    }
+    let _ = writeln!(contents, "#![warn(clippy::{lint})]");
+    if is_lib {
+        contents.push_str("#![crate_type = \"lib\"]\n");
+    }
+    contents.push('\n');
+    contents.push_str(&tidy(&source));
+    fs::write(output, contents).map_err(|e| format!("failed to write `{}`: {e}", output.display()))?;
+
+    // The test files are also formatted by `cargo dev fmt`, the reduced source is often far from it
+    let _ = Command::new("rustfmt")
+        .args(["--edition", edition])
+        .arg(output)
+        .status();
+
+    println!("Generated test file: `{}`", output.display());
+    if !fires_standalone(output, lint, edition) {
+        println!(
+            "warning: `clippy::{lint}` isn't emitted in the test file on its own, \
+            it may still use the dependencies of the crate"
+        );
+    }
+    Ok(())
+}
+
+struct Reducer<'a> {
+    /// The copy of the crate
+    dir: &'a Path,
+    /// The root file of the crate, relative to `dir`, with its modules inlined
+    root: &'a Path,
+    is_lib: bool,
+    lint: &'a str,
+    target_dir: &'a Path,
+}
+
+impl Reducer<'_> {
+    /// Writes `source` to the root file and returns the spans where the lint is emitted in it, or
+    /// `None` if the crate doesn't compile
+    fn lint_spans(&self, source: &str) -> io::Result<Option<Vec<Range<usize>>>> {
+        fs::write(self.dir.join(self.root), source)?;
+        let mut command = cargo_clippy_command(self.dir, self.target_dir);
+        if self.is_lib {
+            command.arg("--lib");
+        }
+        let output = command
+            .args(["--message-format=json", "--", "--cap-lints=warn"])
+            .arg(format!("-Wclippy::{}", self.lint))
+            .stderr(Stdio::null())
+            .output()?;
+
+        let code = format!("clippy::{}", self.lint);
+        let mut spans = Vec::new();
+        for diagnostic in String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(parse_diagnostic)
+        {
+            if diagnostic.level == "error" {
+                return Ok(None);
+            }
+            if let Some((file_name, span)) = diagnostic.primary_span
+                && diagnostic.code.as_ref() == Some(&code)
+                && Path::new(&file_name) == self.root
+            {
+                spans.push(span);
+            }
+        }
+        Ok(Some(spans))
+    }
+
+    /// Removes the chunks of `source` that the lint doesn't need to be emitted at `span`, until
+    /// none of them can be removed
+    fn reduce(&self, mut source: String, mut span: Range<usize>) -> io::Result<String> {
+        loop {
+            let mut reduced = false;
+            // The chunks before it were already tried in this pass
+            let mut position = (0, Reverse(usize::MAX), 0);
+            while let Some((chunk, new_span)) = chunks(&source)
+                .into_iter()
+                .filter(|chunk| chunk_key(chunk) >= position)
+                .find_map(|chunk| {
+                    let new_span = chunk
+                        .iter()
+                        .rev()
+                        .try_fold(span.clone(), |span, removed| shift(&span, removed))?;
+                    Some((chunk, new_span))
+                })
+            {
+                let mut candidate = source.clone();
+                for removed in chunk.iter().rev() {
+                    candidate.replace_range(removed.clone(), "");
+                }
+                let (start, end, len) = chunk_key(&chunk);
+                if self
+                    .lint_spans(&candidate)?
+                    .map_or(false, |spans| spans.contains(&new_span))
+                {
+                    source = candidate;
+                    span = new_span;
+                    reduced = true;
+                    position = (start, Reverse(usize::MAX), 0);
+                    print!("\r{} bytes left", source.len());
+                    let _ = io::stdout().flush();
+                } else {
+                    position = (start, end, len + 1);
+                }
+            }
+            if !reduced {
+                return Ok(source);
+            }
+        }
+    }
+}
+
+/// Whether the lint is emitted when linting the reduced file with `clippy-driver`
+fn fires_standalone(path: &Path, lint: &str, edition: &str) -> bool {
+    let code = format!("clippy::{lint}");
+    driver_command(path, edition)
+        .args(["--error-format=json", "--cap-lints=warn"])
+        .arg(format!("-W{code}"))
+        .output()
+        .map_or(false, |output| {
+            String::from_utf8_lossy(&output.stderr)
+                .lines()
+                .filter_map(parse_diagnostic)
+                .any(|diagnostic| diagnostic.code.as_ref() == Some(&code))
+        })
+}
+
+/// The span of the lint once `removed` is removed from the source, if it isn't removed with it
+fn shift(span: &Range<usize>, removed: &Range<usize>) -> Option<Range<usize>> {
+    let len = removed.len();
+    if removed.end <= span.start {
+        Some(span.start - len..span.end - len)
+    } else if removed.start >= span.end {
+        Some(span.clone())
+    } else if span.start <= removed.start && removed.end <= span.end && removed != span {
+        Some(span.start..span.end - len)
+    } else {
+        None
+    }
+}
+
+/// Returns the metadata of the package in `crate_dir` from `cargo metadata`, with the root of its
+/// workspace
+fn package_metadata(crate_dir: &Path) -> Result<(Value, PathBuf), String> {
+    let manifest_path = crate_dir
+        .join("Cargo.toml")
+        .canonicalize()
+        .map_err(|e| format!("failed to find `{}/Cargo.toml`: {e}", crate_dir.display()))?;
+    let output = Command::new("cargo")
+        .args(["metadata", "--format-version=1", "--no-deps", "--manifest-path"])
+        .arg(&manifest_path)
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("failed to run `cargo metadata`: {e}"))?;
+    if !output.status.success() {
+        return Err(format!("`cargo metadata` failed for `{}`", manifest_path.display()));
+    }
+
+    let mut metadata: Value =
+        serde_json::from_slice(&output.stdout).map_err(|e| format!("failed to parse `cargo metadata`: {e}"))?;
+    let workspace_root = PathBuf::from(metadata["workspace_root"].as_str().unwrap_or_default());
+    let package = metadata["packages"]
+        .as_array_mut()
+        .and_then(|packages| {
+            let index = packages
+                .iter()
+                .position(|package| package["manifest_path"].as_str().map(Path::new) == Some(&manifest_path))?;
+            Some(packages.swap_remove(index))
+        })
+        .ok_or_else(|| format!("`{}` isn't the manifest of a package", manifest_path.display()))?;
+    Ok((package, workspace_root))
+}
+
+/// Copies the crate without its `target` directory, with a manifest of its own and the
+/// `Cargo.lock` of its workspace
+fn copy_crate(from: &Path, to: &Path, package: &Value, workspace_root: &Path) -> io::Result<()> {
+    let entries = WalkDir::new(from)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != ".git" && !(entry.depth() == 1 && entry.file_name() == "target"));
+    for entry in entries {
+        let entry = entry?;
+        let path = to.join(entry.path().strip_prefix(from).unwrap());
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(path)?;
+        } else {
+            fs::copy(entry.path(), path)?;
+        }
+    }
+
+    fs::write(to.join("Cargo.toml"), standalone_manifest(package))?;
+    let lock_file = workspace_root.join("Cargo.lock");
+    if lock_file.is_file() {
+        fs::copy(lock_file, to.join("Cargo.lock"))?;
+    }
+    Ok(())
+}
+
+/// The manifest of a package that doesn't need its workspace: the values inherited from the
+/// workspace are the ones resolved by `cargo metadata`, and the path dependencies are absolute.
+/// The dev-dependencies aren't needed to lint the package, so they are left out.
+fn standalone_manifest(package: &Value) -> String {
+    let package_dir = package["manifest_path"]
+        .as_str()
+        .and_then(|path| Path::new(path).parent())
+        .unwrap_or_else(|| Path::new(""));
+    let relative = |path: &Value| {
+        let path = Path::new(path.as_str().unwrap_or_default());
+        Value::from(path.strip_prefix(package_dir).unwrap_or(path).to_string_lossy())
+    };
+
+    let mut manifest = String::from("[package]\n");
+    for (key, field) in [
+        ("name", "name"),
+        ("version", "version"),
+        ("edition", "edition"),
+        ("rust-version", "rust_version"),
+        ("links", "links"),
+    ] {
+        if let Some(value) = package[field].as_str() {
+            let _ = writeln!(manifest, "{key} = {}", Value::from(value));
+        }
+    }
+    let targets = package["targets"].as_array().map_or(&[][..], Vec::as_slice);
+    let has_kind = |target: &Value, kind: &str| {
+        target["kind"]
+            .as_array()
+            .map_or(false, |kinds| kinds.contains(&Value::from(kind)))
+    };
+    if let Some(build) = targets.iter().find(|target| has_kind(target, "custom-build")) {
+        let _ = writeln!(manifest, "build = {}", relative(&build["src_path"]));
+    }
+    let lib_kinds = ["lib", "rlib", "dylib", "cdylib", "staticlib", "proc-macro"];
+    if let Some(lib) = targets
+        .iter()
+        .find(|target| lib_kinds.iter().any(|kind| has_kind(target, kind)))
+    {
+        let _ = write!(
+            manifest,
+            "\n[lib]\nname = {}\npath = {}\n",
+            lib["name"],
+            relative(&lib["src_path"])
+        );
+        if has_kind(lib, "proc-macro") {
+            manifest.push_str("proc-macro = true\n");
+        } else {
+            let _ = writeln!(manifest, "crate-type = {}", lib["crate_types"]);
+        }
+    }
+
+    // The dependencies of each table, like `dependencies` or `target."cfg(unix)".dependencies`
+    let mut tables: BTreeMap<String, String> = BTreeMap::new();
+    for dependency in package["dependencies"].as_array().map_or(&[][..], Vec::as_slice) {
+        let table = match dependency["kind"].as_str() {
+            None => "dependencies",
+            Some("build") => "build-dependencies",
+            Some(_) => continue,
+        };
+        let table = match dependency["target"].as_str() {
+            Some(target) => format!("target.{}.{table}", Value::from(target)),
+            None => table.to_string(),
+        };
+
+        let name = &dependency["name"];
+        let mut fields = vec![format!("version = {}", dependency["req"])];
+        if dependency["rename"].is_string() {
+            fields.push(format!("package = {name}"));
+        }
+        if dependency["path"].is_string() {
+            fields.push(format!("path = {}", dependency["path"]));
+        }
+        // `git+https://github.com/owner/repo?branch=main`
+        if let Some(git) = dependency["source"]
+            .as_str()
+            .and_then(|source| source.strip_prefix("git+"))
+        {
+            let (url, query) = git.split_once('?').unwrap_or((git, ""));
+            fields.push(format!("git = {}", Value::from(url)));
+            for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+                fields.push(format!("{key} = {}", Value::from(value)));
+            }
+        }
+        if dependency["registry"].is_string() {
+            fields.push(format!("registry-index = {}", dependency["registry"]));
+        }
+        if dependency["features"]
+            .as_array()
+            .map_or(false, |features| !features.is_empty())
+        {
+            fields.push(format!("features = {}", dependency["features"]));
+        }
+        if dependency["optional"] == true {
+            fields.push("optional = true".to_string());
+        }
+        if dependency["uses_default_features"] == false {
+            fields.push("default-features = false".to_string());
+        }
+        let key = if dependency["rename"].is_string() {
+            &dependency["rename"]
+        } else {
+            name
+        };
+        let _ = writeln!(tables.entry(table).or_default(), "{key} = {{ {} }}", fields.join(", "));
+    }
+    for (table, dependencies) in tables {
+        let _ = write!(manifest, "\n[{table}]\n{dependencies}");
+    }
+
+    if let Some(features) = package["features"].as_object().filter(|features| !features.is_empty()) {
+        manifest.push_str("\n[features]\n");
+        for (feature, enables) in features {
+            let _ = writeln!(manifest, "{} = {enables}", Value::from(feature.as_str()));
+        }
+    }
+
+    // The copy is a workspace of its own
+    manifest.push_str("\n[workspace]\n");
+    manifest
+}
+
+/// Returns the contents of the module file `path` with its `mod name;` declarations replaced by
+/// the contents of their files, recursively. `dir` is the directory of the files of its submodules.
+fn inline_modules(path: &Path, dir: &Path) -> io::Result<String> {
+    let mut source = fs::read_to_string(path)?;
+    let tokens = tokens(&source);
+    let code: Vec<_> = tokens.iter().filter(|token| !token.is_comment()).collect();
+
+    // The brace depth of the contents and the name of the `mod name { .. }` around the token
+    let mut mod_blocks: Vec<(usize, &str)> = Vec::new();
+    let mut depth = 0;
+    let mut replacements = Vec::new();
+    for (i, token) in code.iter().enumerate() {
+        match token.kind {
+            TokenKind::OpenBrace => depth += 1,
+            TokenKind::CloseBrace => {
+                depth -= 1;
+                if mod_blocks.last().map_or(false, |&(mod_depth, _)| mod_depth > depth) {
+                    mod_blocks.pop();
+                }
+            },
+            TokenKind::Ident if token.text == "mod" => {
+                let [name, next, ..] = &code[i + 1..] else { continue };
+                if name.kind != TokenKind::Ident {
+                    continue;
+                }
+                if next.kind == TokenKind::OpenBrace {
+                    mod_blocks.push((depth + 1, name.text));
+                } else if next.kind == TokenKind::Semi {
+                    let mod_dir = mod_blocks
+                        .iter()
+                        .fold(dir.to_path_buf(), |dir, (_, name)| dir.join(name))
+                        .join(name.text);
+                    match [mod_dir.with_extension("rs"), mod_dir.join("mod.rs")]
+                        .into_iter()
+                        .find(|file| file.is_file())
+                    {
+                        Some(file) => {
+                            let contents = inline_modules(&file, &mod_dir)?;
+                            replacements.push((name.range.end..next.range.end, format!(" {{\n{contents}}}")));
+                        },
+                        None => println!(
+                            "warning: the file of `mod {}` wasn't found, `#[path]` attributes aren't supported",
+                            name.text
+                        ),
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    for (range, contents) in replacements.into_iter().rev() {
+        source.replace_range(range, &contents);
+    }
+    Ok(source)
+}
+
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    range: Range<usize>,
+}
+
+impl Token<'_> {
+    fn is_comment(&self) -> bool {
+        matches!(
+            self.kind,
+            TokenKind::LineComment { .. } | TokenKind::BlockComment { .. }
+        )
+    }
+}
+
+/// The tokens of `source` other than whitespace
+fn tokens(source: &str) -> Vec<Token<'_>> {
+    let mut offset = 0;
+    rustc_lexer::tokenize(source)
+        .map(|token| {
+            let range = offset..offset + token.len as usize;
+            offset = range.end;
+            Token {
+                kind: token.kind,
+                text: &source[range.clone()],
+                range,
+            }
+        })
+        .filter(|token| token.kind != TokenKind::Whitespace)
+        .collect()
+}
+
+/// The ranges of the source removed together
+type Chunk = Vec<Range<usize>>;
+
+/// Returns the chunks of `source` to try to remove, the outer ones first: the items and statements
+/// of the file and of every block, with their attributes and comments, the elements of the blocks
+/// without statements separated by commas, e.g. match arms and fields, and the `mod name {` and `}`
+/// around the contents of the modules
+fn chunks(source: &str) -> Vec<Chunk> {
+    let mut chunks = Vec::new();
+    split_block(&tokens(source), false, &mut chunks);
+    chunks.sort_by_key(|chunk| chunk_key(chunk));
+    chunks.dedup();
+    chunks
+}
+
+fn chunk_key(chunk: &[Range<usize>]) -> (usize, Reverse<usize>, usize) {
+    (chunk[0].start, Reverse(chunk[chunk.len() - 1].end), chunk.len())
+}
+
+fn split_block(tokens: &[Token<'_>], in_braces: bool, chunks: &mut Vec<Chunk>) {
+    let mut depth = 0usize;
+    let (mut semis, mut commas) = (false, false);
+    for token in tokens.iter().filter(|token| !token.is_comment()) {
+        match token.kind {
+            TokenKind::OpenParen | TokenKind::OpenBracket | TokenKind::OpenBrace => depth += 1,
+            TokenKind::CloseParen | TokenKind::CloseBracket | TokenKind::CloseBrace => {
+                depth = depth.saturating_sub(1);
+            },
+            TokenKind::Semi if depth == 0 => semis = true,
+            TokenKind::Comma if depth == 0 => commas = true,
+            _ => {},
+        }
+    }
+    let split_at_commas = in_braces && commas && !semis;
+
+    // The open delimiters, with the index of the braces
+    let mut delimiters: Vec<(TokenKind, usize)> = Vec::new();
+    let mut start = None;
+    for (i, token) in tokens.iter().enumerate() {
+        let chunk_start = *start.get_or_insert(i);
+        if token.is_comment() {
+            continue;
+        }
+        let next = || tokens[i + 1..].iter().find(|token| !token.is_comment());
+        let ends = match token.kind {
+            TokenKind::OpenParen | TokenKind::OpenBracket | TokenKind::OpenBrace => {
+                delimiters.push((token.kind, i));
+                false
+            },
+            TokenKind::CloseParen | TokenKind::CloseBracket | TokenKind::CloseBrace => {
+                let open = delimiters.pop();
+                if let Some((TokenKind::OpenBrace, open)) = open
+                    && !delimiters.iter().any(|(kind, _)| *kind == TokenKind::OpenBrace)
+                {
+                    split_block(&tokens[open + 1..i], true, chunks);
+                }
+                let ends = delimiters.is_empty()
+                    && match token.kind {
+                        TokenKind::CloseBrace => !continues_after_block(next()),
+                        // An inner attribute
+                        TokenKind::CloseBracket => tokens[chunk_start..i]
+                            .iter()
+                            .filter(|token| !token.is_comment())
+                            .take(2)
+                            .map(|token| token.kind)
+                            .eq([TokenKind::Pound, TokenKind::Bang]),
+                        _ => false,
+                    };
+                if ends
+                    && let Some((TokenKind::OpenBrace, open)) = open
+                    && is_module(&tokens[chunk_start..open])
+                {
+                    chunks.push(vec![
+                        tokens[chunk_start].range.start..tokens[open].range.end,
+                        token.range.clone(),
+                    ]);
+                }
+                ends
+            },
+            TokenKind::Semi => delimiters.is_empty(),
+            TokenKind::Comma => delimiters.is_empty() && split_at_commas,
+            _ => false,
+        };
+        if ends {
+            chunks.push(vec![tokens[chunk_start].range.start..token.range.end]);
+            start = None;
+        }
+    }
+    if let (Some(start), Some(last)) = (start, tokens.last()) {
+        chunks.push(vec![tokens[start].range.start..last.range.end]);
+    }
+}
+
+/// Whether the tokens before a block end with `mod name`
+fn is_module(tokens: &[Token<'_>]) -> bool {
+    let mut code = tokens.iter().rev().filter(|token| !token.is_comment());
+    matches!(
+        (code.next(), code.next()),
+        (Some(name), Some(keyword)) if name.kind == TokenKind::Ident && keyword.text == "mod"
+    )
+}
+
+/// Whether the expression or pattern ending with a block goes on with `token`, e.g. `if .. {} else`
+fn continues_after_block(token: Option<&Token<'_>>) -> bool {
+    token.map_or(false, |token| match token.kind {
+        TokenKind::Ident => matches!(token.text, "else" | "as"),
+        TokenKind::Dot
+        | TokenKind::Question
+        | TokenKind::Semi
+        | TokenKind::Comma
+        | TokenKind::Colon
+        | TokenKind::Eq
+        | TokenKind::Lt
+        | TokenKind::Gt
+        | TokenKind::Bang
+        | TokenKind::At
+        | TokenKind::Tilde
+        | TokenKind::Plus
+        | TokenKind::Minus
+        | TokenKind::Star
+        | TokenKind::Slash
+        | TokenKind::Percent
+        | TokenKind::Caret
+        | TokenKind::And
+        | TokenKind::Or => true,
+        _ => false,
+    })
+}
+
+/// Removes the trailing whitespace and the blank lines left by the removed chunks
+fn tidy(source: &str) -> String {
+    let mut tidy = String::new();
+    let mut blank = true;
+    for line in source.lines().map(str::trim_end) {
+        if !line.is_empty() {
+            tidy.push_str(line);
+            tidy.push('\n');
+        } else if !blank {
+            tidy.push('\n');
+        }
+        blank = line.is_empty();
+    }
+    tidy
+}
+
+/// The fields of a JSON diagnostic of rustc used here
+#[derive(Debug, PartialEq, Eq)]
+struct Diagnostic {
+    code: Option<String>,
+    level: String,
+    /// The file name and byte range of the first primary span
+    primary_span: Option<(String, Range<usize>)>,
+}
+
+/// Parses a line of `--error-format=json` output, or a `compiler-message` of
+/// `--message-format=json` output
+fn parse_diagnostic(line: &str) -> Option<Diagnostic> {
+    let value: Value = serde_json::from_str(line).ok()?;
+    // `cargo` wraps the diagnostic in a `compiler-message`
+    let diagnostic = value
+        .get("message")
+        .filter(|message| message.is_object())
+        .unwrap_or(&value);
+    let offset = |span: &Value, name: &str| usize::try_from(span[name].as_u64()?).ok();
+    let primary_span = diagnostic["spans"]
+        .as_array()?
+        .iter()
+        .find(|span| span["is_primary"] == true)
+        .and_then(|span| {
+            Some((
+                span["file_name"].as_str()?.to_string(),
+                offset(span, "byte_start")?..offset(span, "byte_end")?,
+            ))
+        });
+
+    Some(Diagnostic {
+        code: diagnostic["code"]["code"].as_str().map(String::from),
+        level: diagnostic["level"].as_str()?.to_string(),
+        primary_span,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    fn chunk_texts(source: &str) -> Vec<String> {
+        chunks(source)
+            .into_iter()
+            .map(|chunk| chunk.into_iter().map(|range| &source[range]).join(" .. "))
+            .collect()
+    }
+
+    #[test]
+    fn test_chunks() {
+        let source = "#![allow(unused)]\n\
+            /// Doc\n\
+            fn f(x: u8) -> u8 {\n    let y = match x { 0 => 1, _ => { 2 } };\n    if y > 1 { 1 } else { 2 }\n}\n\
+            struct S { a: u8, b: u8 }\n\
+            pub mod m { fn g() {} }";
+        assert_eq!(
+            chunk_texts(source),
+            [
+                "#![allow(unused)]",
+                "/// Doc\nfn f(x: u8) -> u8 {\n    let y = match x { 0 => 1, _ => { 2 } };\n    if y > 1 { 1 } else { 2 }\n}",
+                "let y = match x { 0 => 1, _ => { 2 } };",
+                "0 => 1,",
+                "_ => { 2 }",
+                "2",
+                "if y > 1 { 1 } else { 2 }",
+                "1",
+                "2",
+                "struct S { a: u8, b: u8 }",
+                "a: u8,",
+                "b: u8",
+                "pub mod m { fn g() {} }",
+                "pub mod m { .. }",
+                "fn g() {}",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shift() {
+        assert_eq!(shift(&(10..20), &(0..5)), Some(5..15));
+        assert_eq!(shift(&(10..20), &(20..25)), Some(10..20));
+        assert_eq!(shift(&(10..20), &(12..15)), Some(10..17));
+        assert_eq!(shift(&(10..20), &(5..15)), None);
+        assert_eq!(shift(&(10..20), &(10..20)), None);
+    }
+
+    #[test]
+    fn test_parse_diagnostic() {
+        let line = r#"{"reason":"compiler-message","package_id":"a 0.1.0","target":{"kind":["lib"],"name":"a"},"message":{"rendered":"warning","children":[{"children":[],"code":null,"level":"note","message":"note","spans":[{"file_name":"src/note.rs","byte_start":3,"byte_end":4,"is_primary":true}]}],"code":{"code":"clippy::needless_return","explanation":null},"level":"warning","message":"unneeded `return` statement","spans":[{"file_name":"src/other.rs","byte_start":1,"byte_end":2,"is_primary":false},{"file_name":"src/lib.rs","byte_start":34,"byte_end":42,"is_primary":true}]}}"#;
+        assert_eq!(
+            parse_diagnostic(line),
+            Some(Diagnostic {
+                code: Some("clippy::needless_return".to_string()),
+                level: "warning".to_string(),
+                primary_span: Some(("src/lib.rs".to_string(), 34..42)),
+            })
+        );
+
+        let line = r#"{"message":"aborting due to previous error","code":null,"level":"error","spans":[],"children":[],"rendered":"error"}"#;
+        assert_eq!(
+            parse_diagnostic(line),
+            Some(Diagnostic {
+                code: None,
+                level: "error".to_string(),
+                primary_span: None,
+            })
+        );
+
+        assert_eq!(parse_diagnostic(r#"{"reason":"build-finished","success":true}"#), None);
+    }
+
+    #[test]
+    fn test_standalone_manifest() {
+        let package = serde_json::json!({
+            "name": "a-pkg",
+            "version": "0.2.0",
+            "edition": "2018",
+            "rust_version": "1.56",
+            "links": null,
+            "manifest_path": "/ws/crates/a/Cargo.toml",
+            "features": {"default": ["util"], "util": ["dep:util"]},
+            "targets": [
+                {"kind": ["lib"], "crate_types": ["lib"], "name": "a_lib", "src_path": "/ws/crates/a/src/lib.rs"},
+                {"kind": ["custom-build"], "crate_types": ["bin"], "name": "build-script-build", "src_path": "/ws/crates/a/build.rs"},
+            ],
+            "dependencies": [
+                {"name": "util", "source": null, "req": "*", "kind": null, "rename": null, "optional": true, "uses_default_features": true, "features": ["extra"], "target": null, "registry": null, "path": "/ws/crates/util"},
+                {"name": "util", "source": null, "req": "*", "kind": null, "rename": "util2", "optional": false, "uses_default_features": true, "features": [], "target": "cfg(unix)", "registry": null, "path": "/ws/crates/util"},
+                {"name": "serde", "source": "git+https://github.com/serde-rs/serde?branch=master", "req": "^1.0", "kind": "build", "rename": null, "optional": false, "uses_default_features": false, "features": [], "target": null, "registry": null},
+                {"name": "tempfile", "source": "registry+https://github.com/rust-lang/crates.io-index", "req": "^3", "kind": "dev", "rename": null, "optional": false, "uses_default_features": true, "features": [], "target": null, "registry": null},
+            ],
+        });
+        assert_eq!(
+            standalone_manifest(&package),
+            indoc::indoc! {r#"
+                [package]
+                name = "a-pkg"
+                version = "0.2.0"
+                edition = "2018"
+                rust-version = "1.56"
+                build = "build.rs"
+
+                [lib]
+                name = "a_lib"
+                path = "src/lib.rs"
+                crate-type = ["lib"]
+
+                [build-dependencies]
+                "serde" = { version = "^1.0", git = "https://github.com/serde-rs/serde", branch = "master", default-features = false }
+
+                [dependencies]
+                "util" = { version = "*", path = "/ws/crates/util", features = ["extra"], optional = true }
+
+                [target."cfg(unix)".dependencies]
+                "util2" = { version = "*", package = "util", path = "/ws/crates/util" }
+
+                [features]
+                "default" = ["util"]
+                "util" = ["dep:util"]
+
+                [workspace]
+            "#}
+        );
+    }
+}
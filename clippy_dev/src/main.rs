@@ -3,7 +3,7 @@
 #![warn(rust_2018_idioms, unused_lifetimes)]
 
 use clap::{Arg, ArgAction, ArgMatches, Command, PossibleValue};
-use clippy_dev::{bless, dogfood, fmt, lint, new_lint, serve, setup, update_lints};
+use clippy_dev::{bless, dogfood, fmt, lint, lint_fp, new_lint, serve, setup, update_lints};
 use indoc::indoc;
 
 fn main() {
@@ -11,7 +11,27 @@ fn main() {
 
     match matches.subcommand() {
         Some(("bless", matches)) => {
-            bless::bless(matches.contains_id("ignore-timestamp"));
+            let mode = if matches.contains_id("interactive") {
+                bless::BlessMode::Interactive
+            } else if matches.contains_id("only-removals") {
+                bless::BlessMode::OnlyRemovals
+            } else if matches.contains_id("only-additions") {
+                bless::BlessMode::OnlyAdditions
+            } else {
+                bless::BlessMode::All
+            };
+            let filters: Vec<_> = matches
+                .get_many::<String>("filters")
+                .into_iter()
+                .flatten()
+                .map(String::as_str)
+                .collect();
+            bless::bless(
+                matches.contains_id("ignore-timestamp"),
+                &filters,
+                matches.get_one::<String>("lint").map(String::as_str),
+                mode,
+            );
         },
         Some(("dogfood", matches)) => {
             dogfood::dogfood(
@@ -39,6 +59,9 @@ fn main() {
                 matches.get_one::<String>("category").map(String::as_str),
                 matches.get_one::<String>("type").map(String::as_str),
                 matches.contains_id("msrv"),
+                &matches
+                    .get_many::<String>("config")
+                    .map_or_else(Vec::new, |configs| configs.map(String::as_str).collect::<Vec<_>>()),
             ) {
                 Ok(_) => update_lints::update(update_lints::UpdateMode::Change),
                 Err(e) => eprintln!("Unable to create lint: {e}"),
@@ -88,6 +111,14 @@ fn main() {
             let args = matches.get_many::<String>("args").into_iter().flatten();
             lint::run(path, args);
         },
+        Some(("lint-fp", matches)) => {
+            lint_fp::run(
+                matches.get_one::<String>("path").unwrap(),
+                matches.get_one::<String>("lint").unwrap(),
+                matches.get_one::<String>("snippet").map(String::as_str),
+                matches.get_one::<String>("output").map(String::as_str),
+            );
+        },
         Some(("rename_lint", matches)) => {
             let old_name = matches.get_one::<String>("old_name").unwrap();
             let new_name = matches.get_one::<String>("new_name").unwrap_or(old_name);
@@ -107,11 +138,40 @@ fn get_clap_config() -> ArgMatches {
     Command::new("Clippy developer tooling")
         .arg_required_else_help(true)
         .subcommands([
-            Command::new("bless").about("bless the test output changes").arg(
-                Arg::new("ignore-timestamp")
-                    .long("ignore-timestamp")
-                    .help("Include files updated before clippy was built"),
-            ),
+            Command::new("bless")
+                .about("bless the test output changes")
+                .after_help(indoc! {"
+                    EXAMPLES
+                        Review the changes of the `needless_return` tests:
+                            cargo dev bless --lint needless_return --interactive
+
+                        Bless the changes of the `ui-toml` tests that only remove lines:
+                            cargo dev bless ui-toml/ --only-removals
+                "})
+                .args([
+                    Arg::new("ignore-timestamp")
+                        .long("ignore-timestamp")
+                        .help("Include files updated before clippy was built"),
+                    Arg::new("filters").action(ArgAction::Append).help(
+                        "Only bless the tests whose path in `tests/` contains one of these, ex: ui/needless_return",
+                    ),
+                    Arg::new("lint")
+                        .long("lint")
+                        .takes_value(true)
+                        .help("Only bless the tests whose file or output mention this lint"),
+                    Arg::new("interactive")
+                        .short('i')
+                        .long("interactive")
+                        .help("Show the diff of each file and ask whether to bless it"),
+                    Arg::new("only-removals")
+                        .long("only-removals")
+                        .help("Only bless the files where lines were removed, but not added")
+                        .conflicts_with("interactive"),
+                    Arg::new("only-additions")
+                        .long("only-additions")
+                        .help("Only bless the files where lines were added, but not removed")
+                        .conflicts_with_all(&["interactive", "only-removals"]),
+                ]),
             Command::new("dogfood").about("Runs the dogfood test").args([
                 Arg::new("fix").long("fix").help("Apply the suggestions when possible"),
                 Arg::new("allow-dirty")
@@ -191,6 +251,11 @@ fn get_clap_config() -> ArgMatches {
                         .takes_value(true)
                         .required(false),
                     Arg::new("msrv").long("msrv").help("Add MSRV config code to the lint"),
+                    Arg::new("config")
+                        .long("config")
+                        .help("Add a `clippy.toml` option to the lint, as `name:type=default`, ex: max_depth:u64=3")
+                        .action(ArgAction::Append)
+                        .conflicts_with("type"),
                 ]),
             Command::new("setup")
                 .about("Support for setting up your personal development environment")
@@ -284,6 +349,37 @@ fn get_clap_config() -> ArgMatches {
                         .action(ArgAction::Append)
                         .help("Pass extra arguments to cargo/clippy-driver"),
                 ]),
+            Command::new("lint-fp")
+                .about("Reduce a package where a lint fires to a `tests/ui` file, to triage false positives")
+                .after_help(indoc! {"
+                    A copy of the package has its modules inlined in `src/lib.rs` or `src/main.rs`,
+                    then the items, statements and match arms are removed as long as the package
+                    still compiles and the lint still fires at the same span.
+
+                    EXAMPLES
+                        Reduce the first `needless_borrow` warning:
+                            cargo dev lint-fp ~/my-project needless_borrow
+
+                        Reduce the first warning whose span contains `&path`:
+                            cargo dev lint-fp ~/my-project needless_borrow --snippet '&path'
+                "})
+                .args([
+                    Arg::new("path")
+                        .required(true)
+                        .help("The path to the package directory"),
+                    Arg::new("lint")
+                        .required(true)
+                        .help("The name of the lint, ex: needless_borrow"),
+                    Arg::new("snippet")
+                        .long("snippet")
+                        .takes_value(true)
+                        .help("Reduce the first warning whose span contains this text"),
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .takes_value(true)
+                        .help("The test file to write, `tests/ui/<lint>_fp.rs` by default"),
+                ]),
             Command::new("rename_lint").about("Renames the given lint").args([
                 Arg::new("old_name")
                     .index(1)
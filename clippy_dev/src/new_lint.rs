@@ -1,5 +1,6 @@
 use crate::clippy_project_root;
 use indoc::{formatdoc, writedoc};
+use itertools::Itertools;
 use std::fmt::Write as _;
 use std::fs::{self, OpenOptions};
 use std::io::prelude::*;
@@ -11,9 +12,75 @@ struct LintData<'a> {
     name: &'a str,
     category: &'a str,
     ty: Option<&'a str>,
+    configs: Vec<ConfigOption<'a>>,
     project_root: PathBuf,
 }
 
+/// A `clippy.toml` option of the lint, given as `name:type=default`
+#[derive(Debug, PartialEq, Eq)]
+struct ConfigOption<'a> {
+    name: String,
+    ty: &'a str,
+    default: &'a str,
+}
+
+impl<'a> ConfigOption<'a> {
+    fn parse(option: &'a str) -> io::Result<Self> {
+        let parsed = option.split_once(':').and_then(|(name, rest)| {
+            let (ty, default) = rest.split_once('=')?;
+            let name = name.trim().replace('-', "_");
+            let is_ident = name.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+            (is_ident && !ty.trim().is_empty() && !default.trim().is_empty()).then(|| Self {
+                name,
+                ty: ty.trim(),
+                default: default.trim(),
+            })
+        });
+        parsed.ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid configuration `{option}`, expected `name:type=default`"),
+            )
+        })
+    }
+
+    /// Whether the value is copied out of the `Conf` rather than cloned
+    fn is_copy(&self) -> bool {
+        matches!(
+            self.ty,
+            "bool"
+                | "char"
+                | "u8"
+                | "u16"
+                | "u32"
+                | "u64"
+                | "u128"
+                | "usize"
+                | "i8"
+                | "i16"
+                | "i32"
+                | "i64"
+                | "i128"
+                | "isize"
+                | "f32"
+                | "f64"
+        )
+    }
+
+    /// The default value as a TOML value, if it is written the same way in Rust
+    fn toml_default(&self) -> Option<&'a str> {
+        let default = self.default;
+        let is_literal = matches!(default, "true" | "false")
+            || default.parse::<i64>().is_ok()
+            || default.parse::<f64>().is_ok()
+            || default.len() > 1 && default.starts_with('"') && default.ends_with('"');
+        is_literal.then_some(default)
+    }
+}
+
 trait Context {
     fn context<C: AsRef<str>>(self, text: C) -> Self;
 }
@@ -41,6 +108,7 @@ pub fn create(
     category: Option<&str>,
     mut ty: Option<&str>,
     msrv: bool,
+    configs: &[&str],
 ) -> io::Result<()> {
     if category == Some("cargo") && ty.is_none() {
         // `cargo` is a special category, these lints should always be in `clippy_lints/src/cargo`
@@ -52,6 +120,10 @@ pub fn create(
         name: lint_name.expect("`name` argument is validated by clap"),
         category: category.expect("`category` argument is validated by clap"),
         ty,
+        configs: configs
+            .iter()
+            .map(|config| ConfigOption::parse(config))
+            .collect::<io::Result<_>>()?,
         project_root: clippy_project_root(),
     };
 
@@ -62,6 +134,11 @@ pub fn create(
         add_lint(&lint, msrv).context("Unable to add lint to clippy_lints/src/lib.rs")?;
     }
 
+    if !lint.configs.is_empty() {
+        add_configs(&lint).context("Unable to add the configuration to clippy_lints/src/utils/conf.rs")?;
+        create_config_test(&lint).context("Unable to create a configuration test for the new lint")?;
+    }
+
     Ok(())
 }
 
@@ -118,13 +195,28 @@ fn add_lint(lint: &LintData<'_>, enable_msrv: bool) -> io::Result<()> {
 
     let comment_start = lib_rs.find("// add lints here,").expect("Couldn't find comment");
 
-    let new_lint = if enable_msrv {
-        format!(
-            "store.register_{lint_pass}_pass(move || Box::new({module_name}::{camel_name}::new(msrv)));\n    ",
+    let new_lint = if enable_msrv || !lint.configs.is_empty() {
+        let mut new_lint = String::new();
+        let mut args: Vec<String> = enable_msrv.then(|| "msrv".to_string()).into_iter().collect();
+        for config in &lint.configs {
+            let name = &config.name;
+            if config.is_copy() {
+                let _ = write!(new_lint, "let {name} = conf.{name};\n    ");
+                args.push(name.clone());
+            } else {
+                let _ = write!(new_lint, "let {name} = conf.{name}.clone();\n    ");
+                args.push(format!("{name}.clone()"));
+            }
+        }
+        let _ = write!(
+            new_lint,
+            "store.register_{lint_pass}_pass(move || Box::new({module_name}::{camel_name}::new({args})));\n    ",
             lint_pass = lint.pass,
             module_name = lint.name,
             camel_name = to_camel_case(lint.name),
-        )
+            args = args.join(", "),
+        );
+        new_lint
     } else {
         format!(
             "store.register_{lint_pass}_pass(|| Box::new({module_name}::{camel_name}));\n    ",
@@ -139,6 +231,97 @@ fn add_lint(lint: &LintData<'_>, enable_msrv: bool) -> io::Result<()> {
     fs::write(path, lib_rs).context("writing")
 }
 
+/// Adds the configuration options of the lint to `define_Conf!`, before `overrides` and `lints`
+/// which are kept last. The lint is added to the `Lint:` line of the options that already exist.
+fn add_configs(lint: &LintData<'_>) -> io::Result<()> {
+    let path = "clippy_lints/src/utils/conf.rs";
+    let conf_rs = fs::read_to_string(path).context("reading")?;
+    let conf_rs = add_configs_to_conf(conf_rs, lint.name, &lint.configs);
+    fs::write(path, conf_rs).context("writing")
+}
+
+fn add_configs_to_conf(mut conf_rs: String, lint_name: &str, configs: &[ConfigOption<'_>]) -> String {
+    // The start of the doc comment of the option declared at `option`
+    let doc_start = |conf_rs: &str, option: usize| {
+        let mut start = option;
+        while let Some(line_start) = conf_rs[..start - 1].rfind('\n').map(|i| i + 1)
+            && conf_rs[line_start..].starts_with("    ///")
+        {
+            start = line_start;
+        }
+        start
+    };
+
+    let name_upper = lint_name.to_uppercase();
+    let mut new_configs = String::new();
+    for ConfigOption { name, ty, default } in configs {
+        let Some(option) = conf_rs.find(&format!("\n    ({name}: ")).map(|i| i + 1) else {
+            let _ = write!(
+                new_configs,
+                "    /// Lint: {name_upper}.\n    ///\n    /// TODO: Describe `{name}`\n    ({name}: {ty} = {default}),\n"
+            );
+            continue;
+        };
+
+        let existing_ty = conf_rs[option..]
+            .split_once(": ")
+            .and_then(|(_, rest)| rest.split_once(" = "))
+            .map_or("", |(ty, _)| ty);
+        if existing_ty != *ty {
+            println!("warning: the existing option `{name}` has the type `{existing_ty}`, not `{ty}`");
+        }
+        let start = doc_start(&conf_rs, option);
+        if conf_rs[start..].starts_with("    /// Lint: ") {
+            let lint_line_end = start + conf_rs[start..].find('\n').unwrap();
+            let lints_end = lint_line_end - usize::from(conf_rs[..lint_line_end].ends_with('.'));
+            conf_rs.insert_str(lints_end, &format!(", {name_upper}"));
+        } else {
+            conf_rs.insert_str(start, &format!("    /// Lint: {name_upper}.\n    ///\n"));
+        }
+        println!("Added `{name_upper}` to the lints of the existing option `{name}`");
+    }
+
+    // Start of the doc comment of `overrides`
+    let overrides = conf_rs
+        .find("\n    (overrides: ")
+        .expect("Couldn't find the `overrides` option");
+    conf_rs.insert_str(doc_start(&conf_rs, overrides + 1), &new_configs);
+    conf_rs
+}
+
+fn create_config_test(lint: &LintData<'_>) -> io::Result<()> {
+    let test_dir = format!("tests/ui-toml/{}", lint.name);
+    fs::create_dir(lint.project_root.join(&test_dir)).context(format!("creating directory: {test_dir}"))?;
+
+    let mut clippy_toml = String::new();
+    for config in &lint.configs {
+        let key = config.name.replace('_', "-");
+        match config.toml_default() {
+            Some(default) => {
+                let _ = writeln!(clippy_toml, "{key} = {default}");
+            },
+            None => {
+                let _ = writeln!(
+                    clippy_toml,
+                    "# TODO: Set a value for `{key}`, defaults to `{}`",
+                    config.default
+                );
+            },
+        }
+    }
+    write_file(lint.project_root.join(&test_dir).join("clippy.toml"), clippy_toml)?;
+
+    let test_path = format!("{test_dir}/{}.rs", lint.name);
+    write_file(
+        lint.project_root.join(&test_path),
+        get_test_file_contents(lint.name, None),
+    )?;
+
+    println!("Generated configuration test: `{test_dir}/clippy.toml`, `{test_path}`");
+
+    Ok(())
+}
+
 fn write_file<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> io::Result<()> {
     fn inner(path: &Path, contents: &[u8]) -> io::Result<()> {
         OpenOptions::new()
@@ -242,6 +425,15 @@ fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
             use rustc_semver::RustcVersion;
             use rustc_session::{{declare_tool_lint, impl_lint_pass}};
 
+        "#
+        )
+    } else if !lint.configs.is_empty() {
+        formatdoc!(
+            r#"
+            {pass_import}
+            use rustc_lint::{{{context_import}, {pass_type}}};
+            use rustc_session::{{declare_tool_lint, impl_lint_pass}};
+
         "#
         )
     } else {
@@ -257,39 +449,57 @@ fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
 
     let _ = write!(result, "{}", get_lint_declaration(&name_upper, category));
 
-    result.push_str(&if enable_msrv {
+    let fields: Vec<(&str, &str)> = enable_msrv
+        .then_some(("msrv", "Option<RustcVersion>"))
+        .into_iter()
+        .chain(lint.configs.iter().map(|config| (config.name.as_str(), config.ty)))
+        .collect();
+
+    result.push_str(&if fields.is_empty() {
         formatdoc!(
             r#"
-            pub struct {name_camel} {{
-                msrv: Option<RustcVersion>,
+            declare_lint_pass!({name_camel} => [{name_upper}]);
+
+            impl {pass_type}{pass_lifetimes} for {name_camel} {{}}
+        "#
+        )
+    } else {
+        let struct_fields: String = fields.iter().map(|(name, ty)| format!("\n    {name}: {ty},")).collect();
+        let params = fields.iter().map(|(name, ty)| format!("{name}: {ty}")).join(", ");
+        let field_names = fields.iter().map(|(name, _)| name).join(", ");
+        let pass_impl = if enable_msrv {
+            format!(" {{\n    extract_msrv_attr!({context_import});\n}}")
+        } else {
+            " {}".to_string()
+        };
+        let mut contents = formatdoc!(
+            r#"
+            pub struct {name_camel} {{{struct_fields}
             }}
 
             impl {name_camel} {{
                 #[must_use]
-                pub fn new(msrv: Option<RustcVersion>) -> Self {{
-                    Self {{ msrv }}
+                pub fn new({params}) -> Self {{
+                    Self {{ {field_names} }}
                 }}
             }}
 
             impl_lint_pass!({name_camel} => [{name_upper}]);
 
-            impl {pass_type}{pass_lifetimes} for {name_camel} {{
-                extract_msrv_attr!({context_import});
-            }}
-
-            // TODO: Add MSRV level to `clippy_utils/src/msrvs.rs` if needed.
-            // TODO: Add MSRV test to `tests/ui/min_rust_version_attr.rs`.
-            // TODO: Update msrv config comment in `clippy_lints/src/utils/conf.rs`
+            impl {pass_type}{pass_lifetimes} for {name_camel}{pass_impl}
         "#
-        )
-    } else {
-        formatdoc!(
-            r#"
-            declare_lint_pass!({name_camel} => [{name_upper}]);
-
-            impl {pass_type}{pass_lifetimes} for {name_camel} {{}}
-        "#
-        )
+        );
+        if enable_msrv {
+            contents.push_str(&formatdoc!(
+                r#"
+
+                // TODO: Add MSRV level to `clippy_utils/src/msrvs.rs` if needed.
+                // TODO: Add MSRV test to `tests/ui/min_rust_version_attr.rs`.
+                // TODO: Update msrv config comment in `clippy_lints/src/utils/conf.rs`
+            "#
+            ));
+        }
+        contents
     });
 
     result
@@ -555,3 +765,65 @@ fn test_camel_case() {
     let name4 = to_camel_case(name3);
     assert_eq!(name4, "LintName");
 }
+
+#[test]
+fn test_parse_config_option() {
+    let option = ConfigOption::parse("max-depth:u64=3").unwrap();
+    assert_eq!(
+        option,
+        ConfigOption {
+            name: "max_depth".to_string(),
+            ty: "u64",
+            default: "3",
+        }
+    );
+    assert!(option.is_copy());
+    assert_eq!(option.toml_default(), Some("3"));
+
+    let option = ConfigOption::parse("allowed:Vec<String>=Vec::new()").unwrap();
+    assert_eq!(option.ty, "Vec<String>");
+    assert!(!option.is_copy());
+    assert_eq!(option.toml_default(), None);
+
+    let option = ConfigOption::parse("ignored:std::collections::BTreeSet<String>=<_>::default()").unwrap();
+    assert_eq!(option.ty, "std::collections::BTreeSet<String>");
+
+    assert!(ConfigOption::parse("max_depth:u64").is_err());
+    assert!(ConfigOption::parse("max_depth=3").is_err());
+    assert!(ConfigOption::parse("MaxDepth:u64=3").is_err());
+}
+
+#[test]
+fn test_add_configs_to_conf() {
+    let conf_rs = indoc::indoc! {"
+        define_Conf! {
+            /// Lint: TOO_MANY_LINES.
+            ///
+            /// The maximum number of lines
+            (too_many_lines_threshold: u64 = 100),
+            /// Changes the configuration for some paths
+            (overrides: Vec<ConfOverride> = Vec::new()),
+        }
+    "};
+    let configs = [
+        ConfigOption::parse("too_many_lines_threshold:u64=100").unwrap(),
+        ConfigOption::parse("max_depth:u64=3").unwrap(),
+    ];
+    assert_eq!(
+        add_configs_to_conf(conf_rs.to_string(), "new_lint", &configs),
+        indoc::indoc! {"
+            define_Conf! {
+                /// Lint: TOO_MANY_LINES, NEW_LINT.
+                ///
+                /// The maximum number of lines
+                (too_many_lines_threshold: u64 = 100),
+                /// Lint: NEW_LINT.
+                ///
+                /// TODO: Describe `max_depth`
+                (max_depth: u64 = 3),
+                /// Changes the configuration for some paths
+                (overrides: Vec<ConfOverride> = Vec::new()),
+            }
+        "}
+    );
+}
@@ -125,6 +125,7 @@ fn generate_lint_files(
     process_file("clippy_lints/src/lib.register_all.rs", update_mode, &content);
 
     update_docs(update_mode, &usable_lints);
+    update_lint_list(update_mode, &usable_lints, deprecated_lints, renamed_lints);
 
     for (lint_group, lints) in Lint::by_lint_group(usable_lints.into_iter().chain(internal_lints)) {
         let content = gen_lint_group_list(&lint_group, lints.iter());
@@ -186,6 +187,335 @@ fn update_docs(update_mode: UpdateMode, usable_lints: &[Lint]) {
     }
 }
 
+fn update_lint_list(
+    update_mode: UpdateMode,
+    usable_lints: &[Lint],
+    deprecated_lints: &[DeprecatedLint],
+    renamed_lints: &[RenamedLint],
+) {
+    let configs = parse_configs(usable_lints);
+    let msrvs = lint_msrvs(&configs);
+    replace_region_in_file(
+        update_mode,
+        Path::new("src/lint_list.rs"),
+        "pub static LINTS: &[LintInfo] = &[\n",
+        "];\n",
+        |res| {
+            for lint in usable_lints.iter().sorted_by_key(|lint| &lint.name) {
+                writeln!(
+                    res,
+                    r#"    LintInfo {{ name: "{}", group: "{}", level: "{}", version: "{}", msrv: {:?}, desc: {:?} }},"#,
+                    lint.name,
+                    lint.group,
+                    default_level(&lint.group),
+                    lint.version(),
+                    msrvs.get(&lint.name),
+                    lint.desc,
+                )
+                .unwrap();
+            }
+        },
+    );
+
+    replace_region_in_file(
+        update_mode,
+        Path::new("src/lint_list.rs"),
+        "pub static CONFIGS: &[ConfigInfo] = &[\n",
+        "];\n",
+        |res| {
+            for config in &configs {
+                writeln!(
+                    res,
+                    "    ConfigInfo {{ name: {:?}, ty: {:?}, default: {:?}, doc: {:?}, lints: &[{}] }},",
+                    config.name,
+                    config.ty,
+                    config.default,
+                    config.doc,
+                    config.lints.iter().map(|lint| format!("{lint:?}")).join(", "),
+                )
+                .unwrap();
+            }
+        },
+    );
+
+    replace_region_in_file(
+        update_mode,
+        Path::new("src/lint_list.rs"),
+        "pub static DEPRECATED: &[DeprecatedInfo] = &[\n",
+        "];\n",
+        |res| {
+            for lint in deprecated_lints.iter().sorted_by_key(|lint| &lint.name) {
+                writeln!(
+                    res,
+                    "    DeprecatedInfo {{ name: {:?}, reason: {:?} }},",
+                    lint.name, lint.reason
+                )
+                .unwrap();
+            }
+        },
+    );
+
+    replace_region_in_file(
+        update_mode,
+        Path::new("src/lint_list.rs"),
+        "pub static RENAMED: &[RenamedInfo] = &[\n",
+        "];\n",
+        |res| {
+            let renamed_lints = renamed_lints
+                .iter()
+                .map(|lint| (lint.old_name.strip_prefix("clippy::").unwrap_or(&lint.old_name), lint))
+                .sorted_by_key(|&(old_name, _)| old_name);
+            for (old_name, lint) in renamed_lints {
+                writeln!(
+                    res,
+                    "    RenamedInfo {{ old_name: {old_name:?}, new_name: {:?} }},",
+                    lint.new_name
+                )
+                .unwrap();
+            }
+        },
+    );
+}
+
+/// Returns the level of the lints of `group`, like `declare_clippy_lint!` does
+fn default_level(group: &str) -> &'static str {
+    match group {
+        "correctness" => "deny",
+        "style" | "suspicious" | "complexity" | "perf" => "warn",
+        _ => "allow",
+    }
+}
+
+impl Lint {
+    /// The file declaring the lint
+    fn declaration_file(&self) -> PathBuf {
+        let module = clippy_project_root()
+            .join("clippy_lints/src")
+            .join(self.module.replace("::", "/"));
+        let mod_rs = module.join("mod.rs");
+        if mod_rs.exists() {
+            mod_rs
+        } else {
+            module.with_extension("rs")
+        }
+    }
+
+    /// The version of the `#[clippy::version]` attribute of the lint
+    fn version(&self) -> String {
+        let declaration_file = self.declaration_file();
+        let declaration =
+            fs::read_to_string(&declaration_file).unwrap_or_else(|e| panic_file(e, &declaration_file, "read"));
+        declaration[self.declaration_range.clone()]
+            .split_once("#[clippy::version = \"")
+            .and_then(|(_, rest)| rest.split_once('"'))
+            .map_or("Unknown", |(version, _)| version)
+            .to_string()
+    }
+}
+
+/// Returns the versions of the aliases in `clippy_utils/src/msrvs.rs`, like `1.53.0` for
+/// `OR_PATTERNS`
+fn parse_msrvs() -> HashMap<String, Vec<u32>> {
+    let path = clippy_project_root().join("clippy_utils/src/msrvs.rs");
+    let contents = fs::read_to_string(&path).unwrap_or_else(|e| panic_file(e, &path, "read"));
+    let mut msrvs = HashMap::new();
+    for line in contents.lines() {
+        // `1,53,0 { OR_PATTERNS, MANUAL_BITS }`
+        let Some((version, names)) = line.trim().split_once(" {") else {
+            continue;
+        };
+        let Ok(version) = version.split(',').map(str::parse).collect::<Result<Vec<u32>, _>>() else {
+            continue;
+        };
+        for name in names.trim_end_matches('}').split(',') {
+            msrvs.insert(name.trim().to_string(), version.clone());
+        }
+    }
+    msrvs
+}
+
+/// Returns the Rust version required by each lint affected by the `msrv` option: the highest of
+/// the `msrvs` checked in the files emitting the lint, or in their `mod.rs` right before calling
+/// into them.
+fn lint_msrvs(configs: &[Config]) -> HashMap<String, String> {
+    let versions = parse_msrvs();
+    let files: Vec<(PathBuf, String)> = clippy_lints_src_files()
+        .filter(|(path, _)| !path.to_string_lossy().starts_with("lib."))
+        .map(|(path, entry)| {
+            let contents = fs::read_to_string(entry.path()).unwrap_or_else(|e| panic_file(e, entry.path(), "read"));
+            (path, contents)
+        })
+        .collect();
+    let msrv_lints = configs
+        .iter()
+        .find(|config| config.name == "msrv")
+        .map_or(&[][..], |config| &config.lints);
+
+    let mut lint_msrvs = HashMap::new();
+    for lint in msrv_lints {
+        let lint_const = lint.to_uppercase();
+        let mentioning: Vec<&(PathBuf, String)> = files
+            .iter()
+            .filter(|(_, contents)| contains_word(contents, &lint_const))
+            .collect();
+        // Skip the `mod.rs` declaring the lint when it's emitted in the other files of the module
+        let emitting = mentioning.iter().filter(|(path, _)| {
+            !path.ends_with("mod.rs")
+                || !mentioning
+                    .iter()
+                    .any(|(other, _)| other != path && other.parent() == path.parent())
+        });
+
+        let mut checked = Vec::new();
+        for (path, contents) in emitting {
+            checked.extend(msrv_names(contents));
+            // `if meets_msrv(self.msrv, msrvs::STR_REPEAT) { manual_str_repeat::check(..) }`
+            let mod_rs = path.with_file_name("mod.rs");
+            let Some(module) = path.file_stem().and_then(OsStr::to_str) else {
+                continue;
+            };
+            let Some((_, mod_contents)) = files.iter().find(|(file, _)| *file == mod_rs && file != path) else {
+                continue;
+            };
+            let lines: Vec<&str> = mod_contents.lines().collect();
+            for (i, line) in lines.iter().enumerate() {
+                if lines[i..lines.len().min(i + 4)]
+                    .iter()
+                    .any(|line| line.contains(&format!("{module}::")))
+                {
+                    checked.extend(msrv_names(line));
+                }
+            }
+        }
+        if let Some(version) = checked.into_iter().filter_map(|name| versions.get(name)).max() {
+            lint_msrvs.insert(lint.clone(), version.iter().join("."));
+        }
+    }
+    lint_msrvs
+}
+
+/// The names of the `msrvs` aliases used in `contents`, like `STR_REPEAT` for `msrvs::STR_REPEAT`
+fn msrv_names(contents: &str) -> impl Iterator<Item = &str> {
+    contents.split("msrvs::").skip(1).map(|rest| {
+        let end = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        &rest[..end]
+    })
+}
+
+/// Returns `true` if `word` appears in `contents` as a whole identifier
+fn contains_word(contents: &str, word: &str) -> bool {
+    let is_ident_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    contents.match_indices(word).any(|(start, _)| {
+        !contents[..start].ends_with(is_ident_char) && !contents[start + word.len()..].starts_with(is_ident_char)
+    })
+}
+
+/// Removes the module paths from a type, `Vec<crate::utils::conf::Rename>` becomes `Vec<Rename>`
+fn strip_paths(ty: &str) -> String {
+    let mut segments: Vec<&str> = ty.split("::").collect();
+    let last = segments.pop().unwrap_or_default();
+    segments
+        .into_iter()
+        .map(|segment| segment.trim_end_matches(|c: char| c.is_ascii_alphanumeric() || c == '_'))
+        .chain([last])
+        .collect()
+}
+
+/// Replaces a default like `super::DEFAULT_NAMES.iter().map(ToString::to_string).collect()` with
+/// the elements of the `DEFAULT_NAMES` constant of `conf.rs`
+fn expand_default_list(contents: &str, default: &str) -> String {
+    let list = default
+        .strip_prefix("super::")
+        .and_then(|default| default.strip_suffix(".iter().map(ToString::to_string).collect()"))
+        .and_then(|name| contents.split_once(&format!("const {name}: &[&str] = &[")))
+        .and_then(|(_, rest)| rest.split_once("];"));
+    match list {
+        Some((elements, _)) => format!(
+            "[{}]",
+            elements
+                .split(',')
+                .map(str::trim)
+                .filter(|element| !element.is_empty() && !element.starts_with("//"))
+                .join(", ")
+        ),
+        None => default.to_string(),
+    }
+}
+
+/// A configuration option of `define_Conf!`
+#[derive(Debug, PartialEq, Eq)]
+struct Config {
+    name: String,
+    ty: String,
+    /// The default value, as a Rust expression
+    default: String,
+    doc: String,
+    lints: Vec<String>,
+}
+
+/// Returns the configuration options of `clippy_lints/src/utils/conf.rs`, except the deprecated
+/// ones, with the lints of their `Lint:` doc comment that are in `lints`
+fn parse_configs(lints: &[Lint]) -> Vec<Config> {
+    let path = clippy_project_root().join("clippy_lints/src/utils/conf.rs");
+    let contents = fs::read_to_string(&path).unwrap_or_else(|e| panic_file(e, &path, "read"));
+    parse_configs_contents(&contents, lints)
+}
+
+/// Added to the documentation of the options marked with `#[per_path]`, as by `define_Conf!`
+const PER_PATH_DOC: &str = "Can also be set per path in `[[overrides]]`.";
+
+fn parse_configs_contents(contents: &str, lints: &[Lint]) -> Vec<Config> {
+    let mut configs = Vec::new();
+    let definitions = contents
+        .split_once("\ndefine_Conf! {\n")
+        .and_then(|(_, rest)| rest.split_once("\n}"))
+        .map_or("", |(definitions, _)| definitions);
+    let mut doc: Vec<&str> = Vec::new();
+    let mut deprecated = false;
+    let mut per_path = false;
+    for line in definitions.lines().map(str::trim) {
+        if let Some(doc_line) = line.strip_prefix("///") {
+            doc.push(doc_line.strip_prefix(' ').unwrap_or(doc_line));
+        } else if line.starts_with("#[conf_deprecated") {
+            deprecated = true;
+        } else if line == "#[per_path]" {
+            per_path = true;
+        } else if let Some(definition) = line.strip_prefix('(').and_then(|line| line.strip_suffix("),")) {
+            let mut doc = std::mem::take(&mut doc);
+            if std::mem::take(&mut deprecated) {
+                continue;
+            }
+            if std::mem::take(&mut per_path) {
+                doc.extend(["", PER_PATH_DOC]);
+            }
+            let (name, rest) = definition.split_once(": ").unwrap();
+            let (ty, default) = rest.split_once(" = ").unwrap();
+            // `Lint: LINT_A, LINT_B.`, followed by an empty line and the documentation. The options
+            // applying to all lints only have the documentation.
+            let (lint_names, doc) = doc
+                .split_first()
+                .and_then(|(first, rest)| Some((first.strip_prefix("Lint: ")?, rest)))
+                .unwrap_or(("", &doc));
+            let config_lints = lint_names
+                .trim_end_matches('.')
+                .split(", ")
+                .map(str::to_lowercase)
+                .filter(|name| lints.iter().any(|lint| lint.name == *name))
+                .collect();
+            configs.push(Config {
+                name: name.replace('_', "-"),
+                ty: strip_paths(ty),
+                default: expand_default_list(contents, default),
+                doc: doc.join("\n").trim().to_string(),
+                lints: config_lints,
+            });
+        }
+    }
+    configs
+}
+
 fn print_lint_names(header: &str, lints: &BTreeSet<String>) -> bool {
     if lints.is_empty() {
         return false;
@@ -893,6 +1223,28 @@ pub(crate) struct LintDeclSearchResult<'a> {
     pub range: Range<usize>,
 }
 
+/// The language of a code block with the info string `info`, or `None` if rustdoc tests it as Rust
+fn code_block_language(info: &str) -> Option<&str> {
+    info.split(',').map(str::trim).find(|token| {
+        !matches!(
+            *token,
+            "" | "rust" | "rs" | "ignore" | "no_run" | "should_panic" | "compile_fail"
+        ) && !token.starts_with("edition")
+    })
+}
+
+/// The line of a Rust code block as rustdoc shows it, or `None` for the lines hidden with `# `
+fn visible_doctest_line(line: &str) -> Option<String> {
+    let trimmed = line.trim_start();
+    if trimmed == "#" || trimmed.starts_with("# ") {
+        None
+    } else if trimmed.starts_with("##") {
+        Some(line.replacen("##", "#", 1))
+    } else {
+        Some(line.to_string())
+    }
+}
+
 /// Parse a source file looking for `declare_clippy_lint` macro invocations.
 fn parse_contents(contents: &str, module: &str, lints: &mut Vec<Lint>) {
     let mut offset = 0usize;
@@ -917,15 +1269,30 @@ fn parse_contents(contents: &str, module: &str, lints: &mut Vec<Lint>) {
         let mut iter = iter.by_ref().filter(|t| !matches!(t.token_kind, TokenKind::Whitespace));
         // matches `!{`
         match_tokens!(iter, Bang OpenBrace);
-        let mut in_code = false;
+        // `None` outside of code blocks, and whether the code is Rust inside of them
+        let mut in_rust_code = None;
         while let Some(t) = iter.next() {
             match t.token_kind {
                 TokenKind::LineComment { .. } => {
                     if let Some(line) = t.content.strip_prefix("/// ").or_else(|| t.content.strip_prefix("///")) {
-                        if line.starts_with("```") {
-                            docs += "```\n";
-                            in_code = !in_code;
-                        } else if !(in_code && line.starts_with("# ")) {
+                        if let Some(info) = line.strip_prefix("```") {
+                            if in_rust_code.is_some() {
+                                docs += "```\n";
+                                in_rust_code = None;
+                            } else {
+                                // Rust code blocks are written without a language, the other ones keep theirs
+                                let lang = code_block_language(info);
+                                docs += "```";
+                                docs += lang.unwrap_or_default();
+                                docs.push('\n');
+                                in_rust_code = Some(lang.is_none());
+                            }
+                        } else if in_rust_code == Some(true) {
+                            if let Some(line) = visible_doctest_line(line) {
+                                docs += &line;
+                                docs.push('\n');
+                            }
+                        } else {
                             docs += line;
                             docs.push('\n');
                         }
@@ -1228,6 +1595,77 @@ mod tests {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn test_parse_configs_contents() {
+        static CONTENTS: &str = r#"
+const DEFAULT_NAMES: &[&str] = &["foo", "bar"];
+
+define_Conf! {
+    /// Lint: TOO_MANY_ARGUMENTS, UNKNOWN_LINT.
+    ///
+    /// The maximum number of arguments
+    #[per_path]
+    (too_many_arguments_threshold: u64 = 7),
+    /// DEPRECATED LINT: BLACKLISTED_NAME.
+    ///
+    /// Use the Disallowed Names lint instead
+    #[conf_deprecated("Please use `disallowed-names` instead", disallowed_names)]
+    (blacklisted_names: Vec<String> = Vec::new()),
+    /// Lint: DISALLOWED_NAMES.
+    ///
+    /// The list of disallowed names
+    (disallowed_names: Vec<crate::utils::conf::Name> = super::DEFAULT_NAMES.iter().map(ToString::to_string).collect()),
+    /// Lint levels for all lints
+    (lints: BTreeMap<String, LintLevel> = BTreeMap::new()),
+}
+"#;
+        let lints = ["too_many_arguments", "disallowed_names"]
+            .map(|name| Lint::new(name, "style", "\"abc\"", "module_name", Range::default(), String::new()));
+
+        let expected = vec![
+            Config {
+                name: "too-many-arguments-threshold".into(),
+                ty: "u64".into(),
+                default: "7".into(),
+                doc: "The maximum number of arguments\n\nCan also be set per path in `[[overrides]]`.".into(),
+                lints: vec!["too_many_arguments".into()],
+            },
+            Config {
+                name: "disallowed-names".into(),
+                ty: "Vec<Name>".into(),
+                default: r#"["foo", "bar"]"#.into(),
+                doc: "The list of disallowed names".into(),
+                lints: vec!["disallowed_names".into()],
+            },
+            Config {
+                name: "lints".into(),
+                ty: "BTreeMap<String, LintLevel>".into(),
+                default: "BTreeMap::new()".into(),
+                doc: "Lint levels for all lints".into(),
+                lints: Vec::new(),
+            },
+        ];
+        assert_eq!(expected, parse_configs_contents(CONTENTS, &lints));
+    }
+
+    #[test]
+    fn test_msrv_names() {
+        let contents = "use clippy_utils::msrvs::{self, Msrv};\n\
+            if meets_msrv(self.msrv, msrvs::STR_REPEAT) && !meets_msrv(self.msrv, msrvs::OR_PATTERNS) {";
+        assert_eq!(
+            msrv_names(contents).collect::<Vec<_>>(),
+            ["", "STR_REPEAT", "OR_PATTERNS"]
+        );
+    }
+
+    #[test]
+    fn test_contains_word() {
+        assert!(contains_word("span_lint(cx, MAP_CLONE, span)", "MAP_CLONE"));
+        assert!(contains_word("MAP_CLONE", "MAP_CLONE"));
+        assert!(!contains_word("span_lint(cx, MAP_CLONE_X, span)", "MAP_CLONE"));
+        assert!(!contains_word("NEEDLESS_MAP_CLONE", "MAP_CLONE"));
+    }
+
     #[test]
     fn test_usable_lints() {
         let lints = vec![
@@ -0,0 +1,59 @@
+use cargo_metadata::{DependencyKind, Metadata};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::overrides::glob_match_segments;
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_lint::LateContext;
+use rustc_span::source_map::DUMMY_SP;
+
+use super::DISALLOWED_DEPENDENCIES;
+use crate::utils::conf::DisallowedPath;
+
+pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata, disallowed: &[DisallowedPath]) {
+    let crate_name = cx.tcx.crate_name(LOCAL_CRATE);
+    let package_name = std::env::var("CARGO_PKG_NAME").ok();
+    let package = metadata
+        .packages
+        .iter()
+        .find(|package| Some(&package.name) == package_name.as_ref())
+        .or_else(|| metadata.packages.first());
+    let package = match package {
+        Some(package) => package,
+        None => return,
+    };
+
+    // Only whole crates can be dependencies, paths to modules are left to `DISALLOWED_IMPORTS`
+    for conf in disallowed.iter().filter(|conf| !conf.path().contains("::")) {
+        // A dependency is allowed for a whole crate, so paths to its modules don't allow it
+        let allowed = conf.allow_in().iter().any(|module| {
+            let mut module: Vec<_> = module.split("::").collect();
+            if module[0] == "crate" {
+                module[0] = crate_name.as_str();
+            }
+            glob_match_segments(&module, &[crate_name.as_str()])
+        });
+        if allowed {
+            continue;
+        }
+
+        for dep in &package.dependencies {
+            // Tests can use any crate
+            if dep.kind == DependencyKind::Development {
+                continue;
+            }
+            let key = dep.rename.as_ref().unwrap_or(&dep.name);
+            if key.replace('-', "_") == conf.path() || dep.name.replace('-', "_") == conf.path() {
+                span_lint_and_then(
+                    cx,
+                    DISALLOWED_DEPENDENCIES,
+                    DUMMY_SP,
+                    &format!("dependency on the disallowed crate `{}`", dep.name),
+                    |diag| {
+                        if let Some(reason) = conf.reason() {
+                            diag.note(&format!("{reason} (from clippy.toml)"));
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
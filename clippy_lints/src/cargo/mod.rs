@@ -1,4 +1,5 @@
 mod common_metadata;
+mod disallowed_dependencies;
 mod feature_name;
 mod multiple_crate_versions;
 mod wildcard_dependencies;
@@ -11,6 +12,8 @@ use rustc_lint::{LateContext, LateLintPass, Lint};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::DUMMY_SP;
 
+use crate::utils::conf::DisallowedPath;
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks to see if all common metadata is defined in
@@ -162,8 +165,37 @@ declare_clippy_lint! {
     "wildcard dependencies being used"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for dependencies on the crates listed in the `disallowed-imports`
+    /// configuration. Development dependencies are not checked, and an `allow-in`
+    /// entry only allows a dependency if it names the whole crate, like `crate`.
+    ///
+    /// Unlike the other lints reading `Cargo.toml`, this lint is warn-by-default, as
+    /// it does nothing until `disallowed-imports` is configured.
+    ///
+    /// ### Why is this bad?
+    /// Some crates shouldn't be used in parts of a project, and depending on them
+    /// adds them to the build even if they are never imported.
+    ///
+    /// ### Example
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-imports = [{ path = "tokio", reason = "`core` is runtime independent" }]
+    ///
+    /// # Cargo.toml
+    /// [dependencies]
+    /// tokio = "1"
+    /// ```
+    #[clippy::version = "1.65.0"]
+    pub DISALLOWED_DEPENDENCIES,
+    style,
+    "dependencies on disallowed crates"
+}
+
 pub struct Cargo {
     pub ignore_publish: bool,
+    pub disallowed_imports: Vec<DisallowedPath>,
 }
 
 impl_lint_pass!(Cargo => [
@@ -171,7 +203,8 @@ impl_lint_pass!(Cargo => [
     REDUNDANT_FEATURE_NAMES,
     NEGATIVE_FEATURE_NAMES,
     MULTIPLE_CRATE_VERSIONS,
-    WILDCARD_DEPENDENCIES
+    WILDCARD_DEPENDENCIES,
+    DISALLOWED_DEPENDENCIES
 ]);
 
 impl LateLintPass<'_> for Cargo {
@@ -184,18 +217,24 @@ impl LateLintPass<'_> for Cargo {
         ];
         static WITH_DEPS_LINTS: &[&Lint] = &[MULTIPLE_CRATE_VERSIONS];
 
-        if !NO_DEPS_LINTS
-            .iter()
-            .all(|&lint| is_lint_allowed(cx, lint, CRATE_HIR_ID))
+        // Only read the metadata for the warn-by-default `DISALLOWED_DEPENDENCIES` if it is configured
+        let check_dependencies =
+            !self.disallowed_imports.is_empty() && !is_lint_allowed(cx, DISALLOWED_DEPENDENCIES, CRATE_HIR_ID);
+        if check_dependencies
+            || !NO_DEPS_LINTS
+                .iter()
+                .all(|&lint| is_lint_allowed(cx, lint, CRATE_HIR_ID))
         {
             match MetadataCommand::new().no_deps().exec() {
                 Ok(metadata) => {
                     common_metadata::check(cx, &metadata, self.ignore_publish);
                     feature_name::check(cx, &metadata);
                     wildcard_dependencies::check(cx, &metadata);
+                    disallowed_dependencies::check(cx, &metadata, &self.disallowed_imports);
                 },
                 Err(e) => {
-                    for lint in NO_DEPS_LINTS {
+                    let dependencies_lint = check_dependencies.then_some(&DISALLOWED_DEPENDENCIES);
+                    for lint in NO_DEPS_LINTS.iter().chain(dependencies_lint) {
                         span_lint(cx, lint, DUMMY_SP, &format!("could not read cargo metadata: {e}"));
                     }
                 },
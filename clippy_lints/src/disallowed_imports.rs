@@ -0,0 +1,124 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, CRATE_DEF_INDEX};
+use rustc_hir::{HirId, Item, ItemKind, Path};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::symbol::kw;
+use rustc_span::Span;
+
+use crate::utils::conf;
+use crate::utils::disallowed_path::DisallowedPaths;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Denies importing the crates and modules configured in clippy.toml, with `use` items,
+    /// `extern crate` items or paths starting with the crate name.
+    ///
+    /// Note: Even though this lint is warn-by-default, it will only trigger if
+    /// imports are defined in the clippy.toml file.
+    ///
+    /// ### Why is this bad?
+    /// Some crates or modules shouldn't be used in parts of a project, like an async
+    /// runtime in a crate that should stay runtime independent.
+    ///
+    /// ### Example
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-imports = [
+    ///     # Can use a string as the path of the disallowed crate or module.
+    ///     "tokio",
+    ///     # When using an inline table, can add a `reason` for why the crate or
+    ///     # module is disallowed, and the modules where it is allowed.
+    ///     { path = "std::net", reason = "use the `transport` module", allow-in = ["crate::transport"] },
+    /// ]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// use tokio::sync::Mutex;
+    ///
+    /// fn connect() {
+    ///     let _ = std::net::TcpStream::connect("localhost:80");
+    /// }
+    /// ```
+    #[clippy::version = "1.65.0"]
+    pub DISALLOWED_IMPORTS,
+    style,
+    "use of a disallowed crate or module"
+}
+
+pub struct DisallowedImports {
+    conf_disallowed: Vec<conf::DisallowedPath>,
+    disallowed: DisallowedPaths,
+    /// `use` items importing from several namespaces are split into several items with the same
+    /// span
+    linted: FxHashSet<Span>,
+}
+
+impl DisallowedImports {
+    pub fn new(conf_disallowed: Vec<conf::DisallowedPath>) -> Self {
+        Self {
+            conf_disallowed,
+            disallowed: DisallowedPaths::default(),
+            linted: FxHashSet::default(),
+        }
+    }
+
+    fn check(&mut self, cx: &LateContext<'_>, def_id: DefId, hir_id: HirId, span: Span) {
+        if let Some(index) = self.disallowed.find_def(cx, def_id, hir_id) {
+            if !self.linted.insert(span) {
+                return;
+            }
+            let conf = &self.conf_disallowed[index];
+            let msg = format!("use of a disallowed import `{}`", conf.path());
+            span_lint_and_then(cx, DISALLOWED_IMPORTS, span, &msg, |diag| {
+                if let Some(reason) = conf.reason() {
+                    diag.note(&format!("{reason} (from clippy.toml)"));
+                }
+            });
+        }
+    }
+}
+
+impl_lint_pass!(DisallowedImports => [DISALLOWED_IMPORTS]);
+
+impl LateLintPass<'_> for DisallowedImports {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        self.disallowed = DisallowedPaths::with_nested_items(
+            cx,
+            self.conf_disallowed.iter().map(|conf| (conf.path(), conf.allow_in())),
+        );
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
+        if self.conf_disallowed.is_empty() {
+            return;
+        }
+        if let ItemKind::ExternCrate(_) = item.kind
+            && let Some(krate) = cx.tcx.extern_mod_stmt_cnum(item.def_id)
+        {
+            self.check(cx, krate.as_def_id(), item.hir_id(), item.span);
+        }
+    }
+
+    /// Checks the paths starting with the name of a crate, which includes the paths of `use`
+    /// items. Other paths refer to items that were imported already.
+    fn check_path(&mut self, cx: &LateContext<'_>, path: &Path<'_>, hir_id: HirId) {
+        if self.conf_disallowed.is_empty() {
+            return;
+        }
+        let first = path.segments.iter().find(|segment| segment.ident.name != kw::PathRoot);
+        if let Some(first) = first
+            && let Res::Def(DefKind::Mod, krate) = first.res
+            && krate.index == CRATE_DEF_INDEX
+            && !krate.is_local()
+        {
+            // Paths to items that don't have a `DefId`, like primitive types, are checked for the
+            // crate they start with
+            let def_id = path.res.opt_def_id().unwrap_or(krate);
+            self.check(cx, def_id, hir_id, path.span);
+        }
+    }
+}
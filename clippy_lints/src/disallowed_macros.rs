@@ -0,0 +1,148 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::macro_backtrace;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::{Expr, ExprKind, FieldDef, HirId, Item, Pat, Path, Stmt, Ty};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{ExpnId, Span};
+
+use crate::utils::conf;
+use crate::utils::disallowed_path::DisallowedPaths;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Denies the configured macros in clippy.toml
+    ///
+    /// Note: Even though this lint is warn-by-default, it will only trigger if
+    /// macros are defined in the clippy.toml file.
+    ///
+    /// ### Why is this bad?
+    /// Some macros are undesirable in certain contexts, and it's beneficial to
+    /// lint for them as needed.
+    ///
+    /// ### Example
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-macros = [
+    ///     # Can use a string as the path of the disallowed macro.
+    ///     "std::print",
+    ///     # Can also use an inline table with a `path` key.
+    ///     { path = "std::println" },
+    ///     # When using an inline table, can add a `reason` for why the macro
+    ///     # is disallowed.
+    ///     { path = "serde::Serialize", reason = "no serializing" },
+    ///     # `allow-in` lists the modules where the macro can still be used.
+    ///     { path = "std::todo", allow-in = ["crate::prototype"] },
+    /// ]
+    /// ```
+    /// ```rust,ignore
+    /// use serde::Serialize;
+    ///
+    /// // Example code where Clippy issues a warning
+    /// println!("warns");
+    ///
+    /// // The diagnostic will contain the message "no serializing"
+    /// #[derive(Serialize)]
+    /// struct Data {
+    ///     name: String,
+    ///     value: usize,
+    /// }
+    /// ```
+    #[clippy::version = "1.65.0"]
+    pub DISALLOWED_MACROS,
+    style,
+    "use of a disallowed macro"
+}
+
+pub struct DisallowedMacros {
+    conf_disallowed: Vec<conf::DisallowedPath>,
+    disallowed: DisallowedPaths,
+    seen: FxHashSet<ExpnId>,
+}
+
+impl DisallowedMacros {
+    pub fn new(conf_disallowed: Vec<conf::DisallowedPath>) -> Self {
+        Self {
+            conf_disallowed,
+            disallowed: DisallowedPaths::default(),
+            seen: FxHashSet::default(),
+        }
+    }
+
+    /// Lints the disallowed macros the code at `span` was expanded from. The macros are found by
+    /// their definition, so a macro is linted whatever name it is imported or re-exported under.
+    fn check(&mut self, cx: &LateContext<'_>, span: Span, hir_id: HirId) {
+        if self.conf_disallowed.is_empty() {
+            return;
+        }
+
+        for mac in macro_backtrace(span) {
+            if !self.seen.insert(mac.expn) {
+                return;
+            }
+
+            if let Some(index) = self.disallowed.find_def(cx, mac.def_id, hir_id) {
+                let conf = &self.conf_disallowed[index];
+                let is_pattern = conf.path().contains('*');
+                let msg = if is_pattern {
+                    format!("use of a disallowed macro `{}`", cx.tcx.def_path_str(mac.def_id))
+                } else {
+                    format!("use of a disallowed macro `{}`", conf.path())
+                };
+                span_lint_and_then(cx, DISALLOWED_MACROS, mac.span, &msg, |diag| {
+                    if is_pattern {
+                        diag.note(&format!("disallowed by the pattern `{}`", conf.path()));
+                    }
+                    if let Some(reason) = conf.reason() {
+                        diag.note(&format!("{reason} (from clippy.toml)"));
+                    }
+                });
+            }
+        }
+    }
+}
+
+impl_lint_pass!(DisallowedMacros => [DISALLOWED_MACROS]);
+
+impl LateLintPass<'_> for DisallowedMacros {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        self.disallowed = DisallowedPaths::new(
+            cx,
+            self.conf_disallowed.iter().map(|conf| (conf.path(), conf.allow_in())),
+        );
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+        self.check(cx, expr.span, expr.hir_id);
+        // `$t + $t` can have the context of $t, check also the span of the binary operator
+        if let ExprKind::Binary(op, ..) = expr.kind {
+            self.check(cx, op.span, expr.hir_id);
+        }
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'_>, stmt: &Stmt<'_>) {
+        self.check(cx, stmt.span, stmt.hir_id);
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'_>, ty: &Ty<'_>) {
+        self.check(cx, ty.span, ty.hir_id);
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'_>, pat: &Pat<'_>) {
+        self.check(cx, pat.span, pat.hir_id);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
+        self.check(cx, item.span, item.hir_id());
+        self.check(cx, item.vis_span, item.hir_id());
+    }
+
+    fn check_field_def(&mut self, cx: &LateContext<'_>, field: &FieldDef<'_>) {
+        self.check(cx, field.span, field.hir_id);
+    }
+
+    fn check_path(&mut self, cx: &LateContext<'_>, path: &Path<'_>, hir_id: HirId) {
+        self.check(cx, path.span, hir_id);
+    }
+}
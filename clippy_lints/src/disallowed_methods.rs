@@ -1,11 +1,12 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::{fn_def_id, get_parent_expr, path_def_id};
 
-use rustc_hir::{def::Res, def_id::DefIdMap, Expr, ExprKind};
+use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 
 use crate::utils::conf;
+use crate::utils::disallowed_path::DisallowedPaths;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -30,6 +31,14 @@ declare_clippy_lint! {
     ///     # When using an inline table, can add a `reason` for why the method
     ///     # is disallowed.
     ///     { path = "std::vec::Vec::leak", reason = "no leaking memory" },
+    ///     # `*` matches any characters within a segment, and `**` any number of segments.
+    ///     # A pattern also matches the items within the items it matches, like the methods
+    ///     # of `std::process::Command` here.
+    ///     { path = "std::process::*", reason = "use the `cli` module to run processes" },
+    ///     # Disallowing a trait method disallows all of its implementations as well.
+    ///     "std::io::Write::flush",
+    ///     # `allow-in` lists the modules where the method can still be used.
+    ///     { path = "std::env::var", allow-in = ["crate::config", "my_crate::cli::**"] },
     /// ]
     /// ```
     ///
@@ -58,15 +67,15 @@ declare_clippy_lint! {
 
 #[derive(Clone, Debug)]
 pub struct DisallowedMethods {
-    conf_disallowed: Vec<conf::DisallowedMethod>,
-    disallowed: DefIdMap<usize>,
+    conf_disallowed: Vec<conf::DisallowedPath>,
+    disallowed: DisallowedPaths,
 }
 
 impl DisallowedMethods {
-    pub fn new(conf_disallowed: Vec<conf::DisallowedMethod>) -> Self {
+    pub fn new(conf_disallowed: Vec<conf::DisallowedPath>) -> Self {
         Self {
             conf_disallowed,
-            disallowed: DefIdMap::default(),
+            disallowed: DisallowedPaths::default(),
         }
     }
 }
@@ -75,12 +84,10 @@ impl_lint_pass!(DisallowedMethods => [DISALLOWED_METHODS]);
 
 impl<'tcx> LateLintPass<'tcx> for DisallowedMethods {
     fn check_crate(&mut self, cx: &LateContext<'_>) {
-        for (index, conf) in self.conf_disallowed.iter().enumerate() {
-            let segs: Vec<_> = conf.path().split("::").collect();
-            if let Res::Def(_, id) = clippy_utils::def_path_res(cx, &segs) {
-                self.disallowed.insert(id, index);
-            }
-        }
+        self.disallowed = DisallowedPaths::new(
+            cx,
+            self.conf_disallowed.iter().map(|conf| (conf.path(), conf.allow_in())),
+        );
     }
 
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
@@ -96,16 +103,21 @@ impl<'tcx> LateLintPass<'tcx> for DisallowedMethods {
             Some(def_id) => def_id,
             None => return,
         };
-        let conf = match self.disallowed.get(&def_id) {
-            Some(&index) => &self.conf_disallowed[index],
+        let conf = match self.disallowed.find_def(cx, def_id, expr.hir_id) {
+            Some(index) => &self.conf_disallowed[index],
             None => return,
         };
-        let msg = format!("use of a disallowed method `{}`", conf.path());
+        let is_pattern = conf.path().contains('*');
+        let msg = if is_pattern {
+            format!("use of a disallowed method `{}`", cx.tcx.def_path_str(def_id))
+        } else {
+            format!("use of a disallowed method `{}`", conf.path())
+        };
         span_lint_and_then(cx, DISALLOWED_METHODS, expr.span, &msg, |diag| {
-            if let conf::DisallowedMethod::WithReason {
-                reason: Some(reason), ..
-            } = conf
-            {
+            if is_pattern {
+                diag.note(&format!("disallowed by the pattern `{}`", conf.path()));
+            }
+            if let Some(reason) = conf.reason() {
                 diag.note(&format!("{reason} (from clippy.toml)"));
             }
         });
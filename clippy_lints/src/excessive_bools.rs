@@ -1,6 +1,7 @@
 use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::overrides::PerPath;
 use rustc_ast::ast::{AssocItemKind, Extern, Fn, FnSig, Impl, Item, ItemKind, Trait, Ty, TyKind};
-use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::{sym, Span};
 
@@ -79,13 +80,13 @@ declare_clippy_lint! {
 }
 
 pub struct ExcessiveBools {
-    max_struct_bools: u64,
-    max_fn_params_bools: u64,
+    max_struct_bools: PerPath<u64>,
+    max_fn_params_bools: PerPath<u64>,
 }
 
 impl ExcessiveBools {
     #[must_use]
-    pub fn new(max_struct_bools: u64, max_fn_params_bools: u64) -> Self {
+    pub fn new(max_struct_bools: PerPath<u64>, max_fn_params_bools: PerPath<u64>) -> Self {
         Self {
             max_struct_bools,
             max_fn_params_bools,
@@ -106,12 +107,13 @@ impl ExcessiveBools {
             .count()
             .try_into()
             .unwrap();
-        if self.max_fn_params_bools < fn_sig_bools {
+        let max_fn_params_bools = self.max_fn_params_bools.get(cx.sess(), span);
+        if max_fn_params_bools < fn_sig_bools {
             span_lint_and_help(
                 cx,
                 FN_PARAMS_EXCESSIVE_BOOLS,
                 span,
-                &format!("more than {} bools in function parameters", self.max_fn_params_bools),
+                &format!("more than {max_fn_params_bools} bools in function parameters"),
                 None,
                 "consider refactoring bools into two-variant enums",
             );
@@ -148,12 +150,13 @@ impl EarlyLintPass for ExcessiveBools {
                     .count()
                     .try_into()
                     .unwrap();
-                if self.max_struct_bools < struct_bools {
+                let max_struct_bools = self.max_struct_bools.get(cx.sess(), item.span);
+                if max_struct_bools < struct_bools {
                     span_lint_and_help(
                         cx,
                         STRUCT_EXCESSIVE_BOOLS,
                         item.span,
-                        &format!("more than {} bools in a struct", self.max_struct_bools),
+                        &format!("more than {max_struct_bools} bools in a struct"),
                         None,
                         "consider using a state machine or refactoring bools into two-variant enums",
                     );
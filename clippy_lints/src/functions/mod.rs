@@ -4,9 +4,10 @@ mod result;
 mod too_many_arguments;
 mod too_many_lines;
 
+use clippy_utils::overrides::PerPath;
 use rustc_hir as hir;
 use rustc_hir::intravisit;
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::Span;
 
@@ -260,15 +261,19 @@ declare_clippy_lint! {
     "function returning `Result` with large `Err` type"
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct Functions {
-    too_many_arguments_threshold: u64,
-    too_many_lines_threshold: u64,
-    large_error_threshold: u64,
+    too_many_arguments_threshold: PerPath<u64>,
+    too_many_lines_threshold: PerPath<u64>,
+    large_error_threshold: PerPath<u64>,
 }
 
 impl Functions {
-    pub fn new(too_many_arguments_threshold: u64, too_many_lines_threshold: u64, large_error_threshold: u64) -> Self {
+    pub fn new(
+        too_many_arguments_threshold: PerPath<u64>,
+        too_many_lines_threshold: PerPath<u64>,
+        large_error_threshold: PerPath<u64>,
+    ) -> Self {
         Self {
             too_many_arguments_threshold,
             too_many_lines_threshold,
@@ -298,25 +303,29 @@ impl<'tcx> LateLintPass<'tcx> for Functions {
         span: Span,
         hir_id: hir::HirId,
     ) {
-        too_many_arguments::check_fn(cx, kind, decl, span, hir_id, self.too_many_arguments_threshold);
-        too_many_lines::check_fn(cx, kind, span, body, self.too_many_lines_threshold);
+        let too_many_arguments_threshold = self.too_many_arguments_threshold.get(cx.sess(), span);
+        let too_many_lines_threshold = self.too_many_lines_threshold.get(cx.sess(), span);
+        too_many_arguments::check_fn(cx, kind, decl, span, hir_id, too_many_arguments_threshold);
+        too_many_lines::check_fn(cx, kind, span, body, too_many_lines_threshold);
         not_unsafe_ptr_arg_deref::check_fn(cx, kind, decl, body, hir_id);
     }
 
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
         must_use::check_item(cx, item);
-        result::check_item(cx, item, self.large_error_threshold);
+        result::check_item(cx, item, self.large_error_threshold.get(cx.sess(), item.span));
     }
 
     fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'_>) {
         must_use::check_impl_item(cx, item);
-        result::check_impl_item(cx, item, self.large_error_threshold);
+        result::check_impl_item(cx, item, self.large_error_threshold.get(cx.sess(), item.span));
     }
 
     fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'_>) {
-        too_many_arguments::check_trait_item(cx, item, self.too_many_arguments_threshold);
+        let too_many_arguments_threshold = self.too_many_arguments_threshold.get(cx.sess(), item.span);
+        too_many_arguments::check_trait_item(cx, item, too_many_arguments_threshold);
         not_unsafe_ptr_arg_deref::check_trait_item(cx, item);
         must_use::check_trait_item(cx, item);
-        result::check_trait_item(cx, item, self.large_error_threshold);
+        let large_error_threshold = self.large_error_threshold.get(cx.sess(), item.span);
+        result::check_trait_item(cx, item, large_error_threshold);
     }
 }
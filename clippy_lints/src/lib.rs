@@ -49,11 +49,17 @@ extern crate rustc_typeck;
 #[macro_use]
 extern crate clippy_utils;
 
+use clippy_utils::overrides::OverrideLevel;
 use clippy_utils::parse_msrv;
-use rustc_data_structures::fx::FxHashSet;
-use rustc_lint::LintId;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_lint::{Level, LintId};
+use rustc_middle::ty::query::Providers;
 use rustc_semver::RustcVersion;
 use rustc_session::Session;
+use rustc_span::Symbol;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::PathBuf;
 
 /// Macro used to declare a Clippy lint.
 ///
@@ -199,6 +205,8 @@ mod default_union_representation;
 mod dereference;
 mod derivable_impls;
 mod derive;
+mod disallowed_imports;
+mod disallowed_macros;
 mod disallowed_methods;
 mod disallowed_names;
 mod disallowed_script_idents;
@@ -401,7 +409,9 @@ mod zero_sized_map_values;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
 pub use crate::utils::conf::Conf;
-use crate::utils::conf::{format_error, TryConf};
+#[doc(hidden)]
+pub use crate::utils::conf::TryConf;
+use crate::utils::conf::{format_error, qualified_lint_name};
 
 /// Register all pre expansion lints
 ///
@@ -426,7 +436,16 @@ pub fn register_pre_expansion_lints(store: &mut rustc_lint::LintStore, sess: &Se
     store.register_pre_expansion_pass(move || Box::new(attrs::EarlyAttributes { msrv }));
 }
 
-fn read_msrv(conf: &Conf, sess: &Session) -> Option<RustcVersion> {
+/// Where the MSRV comes from
+#[derive(Clone, Copy)]
+enum MsrvSource {
+    /// `msrv` in `clippy.toml`
+    ClippyToml,
+    /// `rust-version` in `Cargo.toml`
+    CargoToml,
+}
+
+fn read_msrv(conf: &Conf, sess: &Session) -> Option<(RustcVersion, MsrvSource)> {
     let cargo_msrv = std::env::var("CARGO_PKG_RUST_VERSION")
         .ok()
         .and_then(|v| parse_msrv(&v, None, None));
@@ -448,47 +467,186 @@ fn read_msrv(conf: &Conf, sess: &Session) -> Option<RustcVersion> {
                 ));
             }
 
-            Some(clippy_msrv)
+            Some((clippy_msrv, MsrvSource::ClippyToml))
         } else {
-            Some(cargo_msrv)
+            Some((cargo_msrv, MsrvSource::CargoToml))
         }
     } else {
-        clippy_msrv
+        clippy_msrv.map(|msrv| (msrv, MsrvSource::ClippyToml))
     }
 }
 
 #[doc(hidden)]
-pub fn read_conf(sess: &Session) -> Conf {
-    let file_name = match utils::conf::lookup_conf_file() {
+pub use crate::utils::conf::lookup_conf_file;
+#[doc(hidden)]
+pub use crate::utils::lint_timings;
+#[doc(hidden)]
+pub use clippy_utils::add_allows;
+#[doc(hidden)]
+pub use clippy_utils::baseline;
+
+/// Reads the configuration file and the files it inherits from. The errors are reported by
+/// `report_conf_errors` once there is a session.
+#[doc(hidden)]
+pub fn read_conf(conf_file: &io::Result<Option<PathBuf>>) -> TryConf {
+    match conf_file {
+        Ok(Some(path)) => utils::conf::read(path),
+        _ => TryConf::default(),
+    }
+}
+
+#[doc(hidden)]
+pub fn report_conf_errors(sess: &Session, conf_file: &io::Result<Option<PathBuf>>, conf: &TryConf) {
+    let file_name = match conf_file {
         Ok(Some(path)) => path,
-        Ok(None) => return Conf::default(),
+        Ok(None) => return,
         Err(error) => {
             sess.struct_err(&format!("error finding Clippy's configuration file: {error}"))
                 .emit();
-            return Conf::default();
+            return;
         },
     };
 
-    let TryConf { conf, errors, warnings } = utils::conf::read(&file_name);
     // all conf errors are non-fatal, we just use the default conf in case of error
-    for error in errors {
+    for error in &conf.errors {
         sess.err(&format!(
             "error reading Clippy's configuration file `{}`: {}",
             file_name.display(),
-            format_error(error)
+            format_error(&**error)
         ));
     }
 
-    for warning in warnings {
+    for warning in &conf.warnings {
         sess.struct_warn(&format!(
             "error reading Clippy's configuration file `{}`: {}",
             file_name.display(),
-            format_error(warning)
+            format_error(&**warning)
         ))
         .emit();
     }
 
-    conf
+    if std::env::var_os("CLIPPY_CONF_VERBOSE").is_some() {
+        print_conf_sources(sess, &conf.sources);
+    }
+}
+
+/// Passes the lint levels of the `[[overrides]]` tables in `clippy.toml` on to the `lint_levels`
+/// query, with lint groups expanded to their lints.
+fn register_override_lint_levels(store: &rustc_lint::LintStore, sess: &Session, conf: &Conf) {
+    let levels = conf
+        .overrides
+        .iter()
+        .map(|conf_override| {
+            let mut lints: Vec<_> = conf_override
+                .lints
+                .iter()
+                .map(|(name, level)| (qualified_lint_name(name), Level::from(*level)))
+                .collect();
+            // Lint groups go first, so that the levels of single lints take precedence over them
+            lints.sort_by_key(|(name, _)| !store.is_lint_group(Symbol::intern(name)));
+
+            let mut levels = FxHashMap::default();
+            for (name, level) in lints {
+                let level = OverrideLevel {
+                    level,
+                    name: Symbol::intern(&name),
+                };
+                match store.find_lints(&name) {
+                    Ok(lints) => levels.extend(lints.into_iter().map(|lint| (lint, level))),
+                    Err(_) => sess.warn(&format!(
+                        "unknown lint `{name}` in the `[[overrides]]` of Clippy's configuration file"
+                    )),
+                }
+            }
+            (conf_override.globs(), levels)
+        })
+        .collect();
+    clippy_utils::overrides::set_lint_levels(levels);
+}
+
+/// Overrides the queries of rustc that Clippy changes: the lint levels include the ones of the
+/// `[[overrides]]` tables in `clippy.toml`.
+pub fn override_queries(providers: &mut Providers) {
+    providers.lint_levels = clippy_utils::overrides::lint_levels;
+}
+
+/// Prints which configuration file each of the configured values comes from. Lists and tables can
+/// come from several files.
+fn print_conf_sources(sess: &Session, sources: &[(PathBuf, Vec<String>)]) {
+    let mut keys = BTreeMap::<&str, Vec<String>>::new();
+    for (path, path_keys) in sources {
+        for key in path_keys {
+            keys.entry(key).or_default().push(format!("`{}`", path.display()));
+        }
+    }
+    for (key, paths) in keys {
+        sess.note_without_error(&format!("Clippy configuration `{key}` from {}", paths.join(", ")));
+    }
+}
+
+/// Prints the configuration Clippy uses for the current crate as TOML, with a comment saying
+/// where each value comes from.
+///
+/// Used by `cargo clippy --print-config`.
+#[doc(hidden)]
+pub fn print_conf(sess: &Session, conf: &TryConf) {
+    let crate_name = sess.opts.crate_name.as_deref().unwrap_or("<unknown>");
+    // Its diagnostics are the same as when the lints are registered, so they are only shown once
+    let msrv = match read_msrv(&conf.conf, sess) {
+        Some((msrv, MsrvSource::ClippyToml)) => format!("{msrv} (from `msrv` in `clippy.toml`)"),
+        Some((msrv, MsrvSource::CargoToml)) => format!("{msrv} (from `rust-version` in `Cargo.toml`)"),
+        None => "not set".to_string(),
+    };
+    print!(
+        "# Clippy configuration for `{crate_name}`\n# MSRV: {msrv}\n{}",
+        format_conf(conf)
+    );
+}
+
+/// Formats the configuration as TOML, with comments saying which files were read and where each
+/// value comes from.
+///
+/// Used by `cargo clippy --print-config`, and by `cargo clippy --explain` through
+/// `clippy-driver --print-clippy-conf`.
+#[doc(hidden)]
+pub fn format_conf(conf: &TryConf) -> String {
+    let TryConf { conf, sources, .. } = conf;
+    let mut out = String::new();
+    if sources.is_empty() {
+        out.push_str("# No configuration file found, all values are the defaults\n");
+    }
+    for (path, _) in sources {
+        out.push_str(&format!("# Read `{}`\n", path.display()));
+    }
+
+    // Tables have to come after the plain values, or the values would become part of them
+    let mut values = String::new();
+    let mut tables = String::new();
+    for (name, value) in conf.values() {
+        let files: Vec<_> = sources
+            .iter()
+            .filter(|(_, keys)| keys.contains(&name))
+            .map(|(path, _)| format!("`{}`", path.display()))
+            .collect();
+        let origin = if files.is_empty() {
+            "default".to_string()
+        } else {
+            format!("from {}", files.join(", "))
+        };
+
+        if let Some(value) = value {
+            let mut table = toml::value::Table::new();
+            table.insert(name.clone(), value);
+            match toml::to_string(&toml::Value::Table(table)) {
+                Ok(rendered) if rendered.starts_with('[') => tables.push_str(&format!("\n# {origin}\n{rendered}")),
+                Ok(rendered) => values.push_str(&format!("\n# {origin}\n{rendered}")),
+                Err(e) => values.push_str(&format!("\n# {origin}\n# {name} can't be shown: {e}\n")),
+            }
+        } else {
+            values.push_str(&format!("\n# {origin}\n# {name} is not set\n"));
+        }
+    }
+    format!("{out}{values}{tables}")
 }
 
 /// Register all lints and lint groups with the rustc plugin registry
@@ -516,6 +674,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     include!("lib.register_cargo.rs");
     include!("lib.register_nursery.rs");
 
+    register_override_lint_levels(store, sess, conf);
+
     #[cfg(feature = "internal")]
     {
         if std::env::var("ENABLE_METADATA_COLLECTION").eq(&Ok("1".to_string())) {
@@ -556,12 +716,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     });
     store.register_late_pass(|| Box::new(serde_api::SerdeApi));
     let vec_box_size_threshold = conf.vec_box_size_threshold;
-    let type_complexity_threshold = conf.type_complexity_threshold;
+    let type_complexity_threshold = conf.per_path(conf.type_complexity_threshold, |conf_override| {
+        conf_override.type_complexity_threshold
+    });
     let avoid_breaking_exported_api = conf.avoid_breaking_exported_api;
     store.register_late_pass(move || {
         Box::new(types::Types::new(
             vec_box_size_threshold,
-            type_complexity_threshold,
+            type_complexity_threshold.clone(),
             avoid_breaking_exported_api,
         ))
     });
@@ -590,7 +752,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| Box::new(non_octal_unix_permissions::NonOctalUnixPermissions));
     store.register_early_pass(|| Box::new(unnecessary_self_imports::UnnecessarySelfImports));
 
-    let msrv = read_msrv(conf, sess);
+    let msrv = read_msrv(conf, sess).map(|(msrv, _)| msrv);
     let avoid_breaking_exported_api = conf.avoid_breaking_exported_api;
     let allow_expect_in_tests = conf.allow_expect_in_tests;
     let allow_unwrap_in_tests = conf.allow_unwrap_in_tests;
@@ -667,14 +829,20 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| Box::new(new_without_default::NewWithoutDefault::default()));
     let disallowed_names = conf.disallowed_names.iter().cloned().collect::<FxHashSet<_>>();
     store.register_late_pass(move || Box::new(disallowed_names::DisallowedNames::new(disallowed_names.clone())));
-    let too_many_arguments_threshold = conf.too_many_arguments_threshold;
-    let too_many_lines_threshold = conf.too_many_lines_threshold;
-    let large_error_threshold = conf.large_error_threshold;
+    let too_many_arguments_threshold = conf.per_path(conf.too_many_arguments_threshold, |conf_override| {
+        conf_override.too_many_arguments_threshold
+    });
+    let too_many_lines_threshold = conf.per_path(conf.too_many_lines_threshold, |conf_override| {
+        conf_override.too_many_lines_threshold
+    });
+    let large_error_threshold = conf.per_path(conf.large_error_threshold, |conf_override| {
+        conf_override.large_error_threshold
+    });
     store.register_late_pass(move || {
         Box::new(functions::Functions::new(
-            too_many_arguments_threshold,
-            too_many_lines_threshold,
-            large_error_threshold,
+            too_many_arguments_threshold.clone(),
+            too_many_lines_threshold.clone(),
+            large_error_threshold.clone(),
         ))
     });
     let doc_valid_idents = conf.doc_valid_idents.iter().cloned().collect::<FxHashSet<_>>();
@@ -785,12 +953,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_early_pass(|| Box::new(as_conversions::AsConversions));
     store.register_late_pass(|| Box::new(let_underscore::LetUnderscore));
     store.register_early_pass(|| Box::new(single_component_path_imports::SingleComponentPathImports));
-    let max_fn_params_bools = conf.max_fn_params_bools;
-    let max_struct_bools = conf.max_struct_bools;
+    let max_fn_params_bools = conf.per_path(conf.max_fn_params_bools, |conf_override| {
+        conf_override.max_fn_params_bools
+    });
+    let max_struct_bools = conf.per_path(conf.max_struct_bools, |conf_override| conf_override.max_struct_bools);
     store.register_early_pass(move || {
         Box::new(excessive_bools::ExcessiveBools::new(
-            max_struct_bools,
-            max_fn_params_bools,
+            max_struct_bools.clone(),
+            max_fn_params_bools.clone(),
         ))
     });
     store.register_early_pass(|| Box::new(option_env_unwrap::OptionEnvUnwrap));
@@ -869,9 +1039,11 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     let allow_dbg_in_tests = conf.allow_dbg_in_tests;
     store.register_late_pass(move || Box::new(dbg_macro::DbgMacro::new(allow_dbg_in_tests)));
     let cargo_ignore_publish = conf.cargo_ignore_publish;
+    let disallowed_imports = conf.disallowed_imports.clone();
     store.register_late_pass(move || {
         Box::new(cargo::Cargo {
             ignore_publish: cargo_ignore_publish,
+            disallowed_imports: disallowed_imports.clone(),
         })
     });
     store.register_late_pass(|| Box::new(write::Write::default()));
@@ -903,6 +1075,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| Box::new(unused_peekable::UnusedPeekable));
     store.register_early_pass(|| Box::new(multi_assignments::MultiAssignments));
     store.register_late_pass(|| Box::new(bool_to_int_with_if::BoolToIntWithIf));
+    let disallowed_macros = conf.disallowed_macros.clone();
+    store.register_late_pass(move || Box::new(disallowed_macros::DisallowedMacros::new(disallowed_macros.clone())));
+    let disallowed_imports = conf.disallowed_imports.clone();
+    store.register_late_pass(move || Box::new(disallowed_imports::DisallowedImports::new(disallowed_imports.clone())));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
@@ -14,7 +14,8 @@ use rustc_lint::{EarlyContext, EarlyLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::hygiene::{ExpnKind, MacroKind};
 use rustc_span::Span;
-use serde::{de, Deserialize};
+use serde::ser::SerializeStruct;
+use serde::{de, ser, Deserialize, Serialize};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -217,6 +218,18 @@ impl PartialEq for MacroMatcher {
 }
 impl Eq for MacroMatcher {}
 
+impl Serialize for MacroMatcher {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let mut state = serializer.serialize_struct("MacroMatcher", 2)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("brace", &self.braces.0)?;
+        state.end()
+    }
+}
+
 impl<'de> Deserialize<'de> for MacroMatcher {
     fn deserialize<D>(deser: D) -> Result<Self, D::Error>
     where
@@ -9,13 +9,14 @@ mod type_complexity;
 mod utils;
 mod vec_box;
 
+use clippy_utils::overrides::PerPath;
 use rustc_hir as hir;
 use rustc_hir::intravisit::FnKind;
 use rustc_hir::{
     Body, FnDecl, FnRetTy, GenericArg, HirId, ImplItem, ImplItemKind, Item, ItemKind, Local, MutTy, QPath, TraitItem,
     TraitItemKind, TyKind,
 };
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
 
@@ -304,7 +305,7 @@ declare_clippy_lint! {
 
 pub struct Types {
     vec_box_size_threshold: u64,
-    type_complexity_threshold: u64,
+    type_complexity_threshold: PerPath<u64>,
     avoid_breaking_exported_api: bool,
 }
 
@@ -421,7 +422,11 @@ impl<'tcx> LateLintPass<'tcx> for Types {
 }
 
 impl Types {
-    pub fn new(vec_box_size_threshold: u64, type_complexity_threshold: u64, avoid_breaking_exported_api: bool) -> Self {
+    pub fn new(
+        vec_box_size_threshold: u64,
+        type_complexity_threshold: PerPath<u64>,
+        avoid_breaking_exported_api: bool,
+    ) -> Self {
         Self {
             vec_box_size_threshold,
             type_complexity_threshold,
@@ -461,7 +466,9 @@ impl Types {
             return;
         }
 
-        if !context.is_nested_call && type_complexity::check(cx, hir_ty, self.type_complexity_threshold) {
+        if !context.is_nested_call
+            && type_complexity::check(cx, hir_ty, self.type_complexity_threshold.get(cx.sess(), hir_ty.span))
+        {
             return;
         }
 
@@ -2,16 +2,21 @@
 //! to generate a clippy lint detecting said code automatically.
 
 use clippy_utils::{get_attr, higher};
+use itertools::Itertools;
 use rustc_ast::ast::{LitFloatType, LitKind};
 use rustc_ast::LitIntType;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
 use rustc_hir::{
-    ArrayLen, BindingAnnotation, Closure, ExprKind, FnRetTy, HirId, Lit, PatKind, QPath, StmtKind, TyKind,
+    ArrayLen, BindingAnnotation, Closure, EnumDef, ExprKind, FnRetTy, FnSig, HirId, ImplItemKind, ItemKind, Lit, MutTy,
+    PatKind, QPath, StmtKind, TraitFn, TraitItemKind, TyKind, VariantData,
 };
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::symbol::{Ident, Symbol};
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{Display, Formatter, Write as _};
 
 declare_clippy_lint! {
@@ -48,6 +53,10 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    ///
+    /// With `#[clippy::author = "lint"]`, the output is instead a complete `LateLintPass` that
+    /// matches the code with `if let` chains, `matches!` and the helpers of `clippy_utils`, like
+    /// `higher::If`, `higher::IfLet` and `is_path_diagnostic_item`.
     pub LINT_AUTHOR,
     internal_warn,
     "helper for writing lints"
@@ -55,13 +64,6 @@ declare_clippy_lint! {
 
 declare_lint_pass!(Author => [LINT_AUTHOR]);
 
-/// Writes a line of output with indentation added
-macro_rules! out {
-    ($($t:tt)*) => {
-        println!("    {}", format_args!($($t)*))
-    };
-}
-
 /// The variables passed in are replaced with `&Binding`s where the `value` field is set
 /// to the original value of the variable. The `name` field is set to the name of the variable
 /// (using `stringify!`) and is adjusted to avoid duplicate names.
@@ -91,38 +93,33 @@ macro_rules! field {
     };
 }
 
-fn prelude() {
-    println!("if_chain! {{");
-}
-
-fn done() {
-    println!("    then {{");
-    println!("        // report your lint here");
-    println!("    }}");
-    println!("}}");
-}
-
 impl<'tcx> LateLintPass<'tcx> for Author {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
-        check_item(cx, item.hir_id());
+        check_node(cx, item.hir_id(), Method::Item, |v| {
+            v.item(&v.bind("item", item));
+        });
     }
 
     fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'_>) {
-        check_item(cx, item.hir_id());
+        check_node(cx, item.hir_id(), Method::ImplItem, |v| {
+            v.impl_item(&v.bind("item", item));
+        });
     }
 
     fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'_>) {
-        check_item(cx, item.hir_id());
+        check_node(cx, item.hir_id(), Method::TraitItem, |v| {
+            v.trait_item(&v.bind("item", item));
+        });
     }
 
     fn check_arm(&mut self, cx: &LateContext<'tcx>, arm: &'tcx hir::Arm<'_>) {
-        check_node(cx, arm.hir_id, |v| {
+        check_node(cx, arm.hir_id, Method::Arm, |v| {
             v.arm(&v.bind("arm", arm));
         });
     }
 
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
-        check_node(cx, expr.hir_id, |v| {
+        check_node(cx, expr.hir_id, Method::Expr, |v| {
             v.expr(&v.bind("expr", expr));
         });
     }
@@ -132,27 +129,201 @@ impl<'tcx> LateLintPass<'tcx> for Author {
             StmtKind::Expr(e) | StmtKind::Semi(e) if has_attr(cx, e.hir_id) => return,
             _ => {},
         }
-        check_node(cx, stmt.hir_id, |v| {
+        check_node(cx, stmt.hir_id, Method::Stmt, |v| {
             v.stmt(&v.bind("stmt", stmt));
         });
     }
 }
 
-fn check_item(cx: &LateContext<'_>, hir_id: HirId) {
-    let hir = cx.tcx.hir();
-    if let Some(body_id) = hir.maybe_body_owned_by(hir_id.expect_owner()) {
-        check_node(cx, hir_id, |v| {
-            v.expr(&v.bind("expr", hir.body(body_id).value));
-        });
+fn check_node(cx: &LateContext<'_>, hir_id: HirId, method: Method, f: impl Fn(&PrintVisitor<'_, '_>)) {
+    let attrs = cx.tcx.hir().attrs(hir_id);
+    if let Some(attr) = get_attr(cx.sess(), attrs, "author").next() {
+        let lint = attr.value_str().map_or(false, |value| value.as_str() == "lint");
+        let visitor = PrintVisitor::new(cx, lint);
+        f(&visitor);
+        if lint {
+            print_lint(method, &visitor.conds.take());
+        } else {
+            print_if_chain(&visitor.conds.take());
+        }
     }
 }
 
-fn check_node(cx: &LateContext<'_>, hir_id: HirId, f: impl Fn(&PrintVisitor<'_, '_>)) {
-    if has_attr(cx, hir_id) {
-        prelude();
-        f(&PrintVisitor::new(cx));
-        done();
+/// The `LateLintPass` method checking the node with the author attribute
+#[derive(Clone, Copy)]
+enum Method {
+    Arm,
+    Expr,
+    Stmt,
+    Item,
+    ImplItem,
+    TraitItem,
+}
+
+/// A condition of the matcher
+enum Cond {
+    /// `let <pattern> = <scrutinee>`
+    Let(String, String),
+    /// A pattern without bindings, `matches!(<scrutinee>, <pattern>)`
+    Matches(String, String),
+    /// A boolean expression
+    Check(String),
+    /// `let <name> = <value>`, always matching
+    Bind(String, String),
+    Comment(String),
+}
+
+fn print_if_chain(conds: &[Cond]) {
+    println!("if_chain! {{");
+    for cond in conds {
+        match cond {
+            Cond::Let(pat, scrutinee) | Cond::Matches(pat, scrutinee) => println!("    if let {pat} = {scrutinee};"),
+            Cond::Check(check) => println!("    if {check};"),
+            Cond::Bind(name, value) => println!("    let {name} = &{value};"),
+            Cond::Comment(comment) => println!("    {comment}"),
+        }
     }
+    println!("    then {{");
+    println!("        // report your lint here");
+    println!("    }}");
+    println!("}}");
+}
+
+/// The items used by the matchers, with the modules they are imported from
+const IMPORTS: &[(&str, &str)] = &[
+    ("higher", "clippy_utils"),
+    ("is_path_diagnostic_item", "clippy_utils"),
+    ("match_def_path", "clippy_utils"),
+    ("match_path", "clippy_utils"),
+    ("match_qpath", "clippy_utils"),
+    ("path_def_id", "clippy_utils"),
+    ("FloatTy", "rustc_ast::ast"),
+    ("IntTy", "rustc_ast::ast"),
+    ("LitFloatType", "rustc_ast::ast"),
+    ("LitIntType", "rustc_ast::ast"),
+    ("LitKind", "rustc_ast::ast"),
+    ("UintTy", "rustc_ast::ast"),
+    ("ArrayLen", "rustc_hir"),
+    ("BinOpKind", "rustc_hir"),
+    ("BindingAnnotation", "rustc_hir"),
+    ("BorrowKind", "rustc_hir"),
+    ("CaptureBy", "rustc_hir"),
+    ("Closure", "rustc_hir"),
+    ("EnumDef", "rustc_hir"),
+    ("ExprKind", "rustc_hir"),
+    ("FnRetTy", "rustc_hir"),
+    ("FnSig", "rustc_hir"),
+    ("Guard", "rustc_hir"),
+    ("ImplItemKind", "rustc_hir"),
+    ("IsAuto", "rustc_hir"),
+    ("ItemKind", "rustc_hir"),
+    ("LangItem", "rustc_hir"),
+    ("LoopSource", "rustc_hir"),
+    ("MatchSource", "rustc_hir"),
+    ("Movability", "rustc_hir"),
+    ("MutTy", "rustc_hir"),
+    ("Mutability", "rustc_hir"),
+    ("PatKind", "rustc_hir"),
+    ("QPath", "rustc_hir"),
+    ("RangeEnd", "rustc_hir"),
+    ("StmtKind", "rustc_hir"),
+    ("TraitFn", "rustc_hir"),
+    ("TraitItemKind", "rustc_hir"),
+    ("TraitObjectSyntax", "rustc_hir"),
+    ("TyKind", "rustc_hir"),
+    ("UnOp", "rustc_hir"),
+    ("Unsafety", "rustc_hir"),
+    ("VariantData", "rustc_hir"),
+    ("YieldSource", "rustc_hir"),
+];
+
+/// Prints a lint pass reporting the nodes matching `conds`, which can be added to `clippy_lints`
+/// as it is
+fn print_lint(method: Method, conds: &[Cond]) {
+    let (method_name, node, node_ty) = match method {
+        Method::Arm => ("check_arm", "arm", "Arm"),
+        Method::Expr => ("check_expr", "expr", "Expr"),
+        Method::Stmt => ("check_stmt", "stmt", "Stmt"),
+        Method::Item => ("check_item", "item", "Item"),
+        Method::ImplItem => ("check_impl_item", "item", "ImplItem"),
+        Method::TraitItem => ("check_trait_item", "item", "TraitItem"),
+    };
+
+    let mut chain = String::new();
+    let mut first = true;
+    for cond in conds {
+        let cond = match cond {
+            Cond::Let(pat, scrutinee) | Cond::Bind(pat, scrutinee) => format!("let {pat} = {scrutinee}"),
+            Cond::Matches(pat, scrutinee) => format!("matches!({scrutinee}, {pat})"),
+            Cond::Check(check) => check.clone(),
+            Cond::Comment(comment) => {
+                let _ = writeln!(chain, "            {comment}");
+                continue;
+            },
+        };
+        if first {
+            let _ = writeln!(chain, "        if {cond}");
+            first = false;
+        } else {
+            let _ = writeln!(chain, "            && {cond}");
+        }
+    }
+
+    // import the items the conditions use, ignoring the string literals
+    let mut imports: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    imports.entry("rustc_hir").or_default().insert(node_ty);
+    let unquoted: String = chain.split('"').step_by(2).collect();
+    for word in unquoted.split(|c: char| !(c.is_alphanumeric() || c == '_')) {
+        if let Some((item, module)) = IMPORTS.iter().find(|(item, _)| *item == word) {
+            imports.entry(module).or_default().insert(item);
+        }
+    }
+
+    println!("use clippy_utils::diagnostics::span_lint;");
+    for (module, items) in &imports {
+        match items.iter().collect::<Vec<_>>()[..] {
+            [item] => println!("use {module}::{item};"),
+            ref items => println!("use {module}::{{{}}};", items.iter().join(", ")),
+        }
+    }
+    println!("use rustc_lint::{{LateContext, LateLintPass}};");
+    println!("use rustc_session::{{declare_lint_pass, declare_tool_lint}};");
+    println!();
+    println!("declare_clippy_lint! {{");
+    println!("    /// ### What it does");
+    println!("    ///");
+    println!("    /// ### Why is this bad?");
+    println!("    ///");
+    println!("    /// ### Example");
+    println!("    /// ```rust");
+    println!("    /// // example code where clippy issues a warning");
+    println!("    /// ```");
+    println!("    /// Use instead:");
+    println!("    /// ```rust");
+    println!("    /// // example code which does not raise clippy warning");
+    println!("    /// ```");
+    println!("    #[clippy::version = \"{}\"]", clippy_version());
+    println!("    pub AUTHORED_LINT,");
+    println!("    nursery,");
+    println!("    \"default lint description\"");
+    println!("}}");
+    println!();
+    println!("declare_lint_pass!(AuthoredLint => [AUTHORED_LINT]);");
+    println!();
+    println!("impl<'tcx> LateLintPass<'tcx> for AuthoredLint {{");
+    println!("    fn {method_name}(&mut self, cx: &LateContext<'tcx>, {node}: &'tcx {node_ty}<'_>) {{");
+    print!("{chain}");
+    println!("        {{");
+    println!("            span_lint(cx, AUTHORED_LINT, {node}.span, \"default lint description\");");
+    println!("        }}");
+    println!("    }}");
+    println!("}}");
+}
+
+/// The version of Clippy in the format of `#[clippy::version]`
+fn clippy_version() -> String {
+    let minor = env!("CARGO_PKG_VERSION").rsplit('.').next().unwrap_or_default();
+    format!("1.{minor}.0")
 }
 
 struct Binding<T> {
@@ -195,19 +366,27 @@ struct PrintVisitor<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     /// Fields are the current index that needs to be appended to pattern
     /// binding names
-    ids: std::cell::Cell<FxHashMap<&'static str, u32>>,
+    ids: Cell<FxHashMap<&'static str, u32>>,
+    /// The number of bindings created, to tell apart the patterns binding nothing
+    bindings: Cell<u32>,
+    /// Whether the conditions are printed as a lint pass, see `print_lint`
+    lint: bool,
+    conds: RefCell<Vec<Cond>>,
 }
 
-#[allow(clippy::unused_self)]
 impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
-    fn new(cx: &'a LateContext<'tcx>) -> Self {
+    fn new(cx: &'a LateContext<'tcx>, lint: bool) -> Self {
         Self {
             cx,
-            ids: std::cell::Cell::default(),
+            ids: Cell::default(),
+            bindings: Cell::default(),
+            lint,
+            conds: RefCell::default(),
         }
     }
 
     fn next(&self, s: &'static str) -> String {
+        self.bindings.set(self.bindings.get() + 1);
         let mut ids = self.ids.take();
         let out = match *ids.entry(s).and_modify(|n| *n += 1).or_default() {
             // first usage of the name, use it as is
@@ -224,12 +403,110 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
         Binding { name, value }
     }
 
+    fn check(&self, check: impl Display) {
+        self.conds.borrow_mut().push(Cond::Check(check.to_string()));
+    }
+
+    /// Matches `scrutinee` against `pat`, with `matches!` in lint mode if no bindings were created
+    /// since `start`. As the nodes are only borrowed, a pattern with `ref` bindings matches a
+    /// reference to the scrutinee instead in lint mode.
+    fn pat_cond(&self, pat: String, scrutinee: String, start: u32) {
+        let (pat, scrutinee) = if self.lint && pat.contains("ref ") {
+            (pat.replace("ref ", ""), format!("&{scrutinee}"))
+        } else {
+            (pat, scrutinee)
+        };
+        let cond = if self.bindings.get() == start {
+            Cond::Matches(pat, scrutinee)
+        } else {
+            Cond::Let(pat, scrutinee)
+        };
+        self.conds.borrow_mut().push(cond);
+    }
+
+    /// Checks the resolution of a path node in lint mode, with `is_path_diagnostic_item` or
+    /// `match_def_path`. Returns `false` if the path has to be matched by its segments instead,
+    /// like the paths of locals, primitive types and the items of the crate being checked.
+    fn resolved_path(&self, node: &dyn Display, qpath: &QPath<'_>, hir_id: HirId) -> bool {
+        if !self.lint || matches!(qpath, QPath::LangItem(..)) {
+            return false;
+        }
+        match self
+            .cx
+            .qpath_res(qpath, hir_id)
+            .opt_def_id()
+            .filter(|def_id| !def_id.is_local())
+        {
+            Some(def_id) => {
+                let node = node_ref(node);
+                if let Some(name) = self.cx.tcx.get_diagnostic_name(def_id) {
+                    self.check(format_args!("is_path_diagnostic_item(cx, {node}, sym!({name}))"));
+                } else {
+                    let def_id_name = self.next("def_id");
+                    self.conds.borrow_mut().push(Cond::Let(
+                        format!("Some({def_id_name})"),
+                        format!("path_def_id(cx, {node})"),
+                    ));
+                    self.def_path(&def_id_name, def_id);
+                }
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Checks the item of the `QPath` of a struct or tuple struct in lint mode, see
+    /// `resolved_path`
+    fn resolved_qpath(&self, qpath: &Binding<&QPath<'_>>, node: &dyn Display, hir_id: HirId) -> bool {
+        if !self.lint || matches!(qpath.value, QPath::LangItem(..)) {
+            return false;
+        }
+        match self
+            .cx
+            .qpath_res(qpath.value, hir_id)
+            .opt_def_id()
+            .filter(|def_id| !def_id.is_local())
+        {
+            Some(def_id) => {
+                let def_id_name = self.next("def_id");
+                self.conds.borrow_mut().push(Cond::Let(
+                    format!("Some({def_id_name})"),
+                    format!("cx.qpath_res({qpath}, {node}.hir_id).opt_def_id()"),
+                ));
+                self.item_def_id(&def_id_name, def_id);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Checks the item of the `DefId` bound to `def_id_name`, with its diagnostic name if it has
+    /// one
+    fn item_def_id(&self, def_id_name: &str, def_id: DefId) {
+        if let Some(name) = self.cx.tcx.get_diagnostic_name(def_id) {
+            self.check(format_args!("cx.tcx.is_diagnostic_item(sym!({name}), {def_id_name})"));
+        } else {
+            self.def_path(def_id_name, def_id);
+        }
+    }
+
+    fn def_path(&self, def_id_name: &str, def_id: DefId) {
+        let path = self
+            .cx
+            .get_def_path(def_id)
+            .iter()
+            .map(|segment| format!("{:?}", segment.as_str()))
+            .join(", ");
+        self.check(format_args!("match_def_path(cx, {def_id_name}, &[{path}])"));
+    }
+
     fn option<T: Copy>(&self, option: &Binding<Option<T>>, name: &'static str, f: impl Fn(&Binding<T>)) {
         match option.value {
-            None => out!("if {option}.is_none();"),
+            None => self.check(format_args!("{option}.is_none()")),
             Some(value) => {
+                let start = self.bindings.get();
                 let value = &self.bind(name, value);
-                out!("if let Some({value}) = {option};");
+                self.pat_cond(format!("Some({value})"), option.to_string(), start);
                 f(value);
             },
         }
@@ -237,9 +514,9 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
 
     fn slice<T>(&self, slice: &Binding<&[T]>, f: impl Fn(&Binding<&T>)) {
         if slice.value.is_empty() {
-            out!("if {slice}.is_empty();");
+            self.check(format_args!("{slice}.is_empty()"));
         } else {
-            out!("if {slice}.len() == {};", slice.value.len());
+            self.check(format_args!("{slice}.len() == {}", slice.value.len()));
             for (i, value) in slice.value.iter().enumerate() {
                 let name = format!("{slice}[{i}]");
                 f(&Binding { name, value });
@@ -254,23 +531,27 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
     }
 
     fn ident(&self, ident: &Binding<Ident>) {
-        out!("if {ident}.as_str() == {:?};", ident.value.as_str());
+        self.check(format_args!("{ident}.as_str() == {:?}", ident.value.as_str()));
     }
 
     fn symbol(&self, symbol: &Binding<Symbol>) {
-        out!("if {symbol}.as_str() == {:?};", symbol.value.as_str());
+        self.check(format_args!("{symbol}.as_str() == {:?}", symbol.value.as_str()));
     }
 
     fn qpath(&self, qpath: &Binding<&QPath<'_>>) {
         if let QPath::LangItem(lang_item, ..) = *qpath.value {
-            out!("if matches!({qpath}, QPath::LangItem(LangItem::{lang_item:?}, _));");
+            let rest = if self.lint { ".." } else { "_" };
+            self.check(format_args!(
+                "matches!({qpath}, QPath::LangItem(LangItem::{lang_item:?}, {rest}))"
+            ));
         } else {
-            out!("if match_qpath({qpath}, &[{}]);", path_to_string(qpath.value));
+            self.check(format_args!("match_qpath({qpath}, &[{}])", path_to_string(qpath.value)));
         }
     }
 
     fn lit(&self, lit: &Binding<&Lit>) {
-        let kind = |kind| out!("if let LitKind::{kind} = {lit}.node;");
+        let start = self.bindings.get();
+        let kind = |kind| self.pat_cond(format!("LitKind::{kind}"), format!("{lit}.node"), start);
         macro_rules! kind {
             ($($t:tt)*) => (kind(format_args!($($t)*)));
         }
@@ -298,7 +579,7 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
             LitKind::ByteStr(ref vec) => {
                 bind!(self, vec);
                 kind!("ByteStr(ref {vec})");
-                out!("if let [{:?}] = **{vec};", vec.value);
+                self.pat_cond(format!("{:?}", vec.value), format!("**{vec}"), self.bindings.get());
             },
             LitKind::Str(s, _) => {
                 bind!(self, s);
@@ -311,15 +592,17 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
     fn arm(&self, arm: &Binding<&hir::Arm<'_>>) {
         self.pat(field!(arm.pat));
         match arm.value.guard {
-            None => out!("if {arm}.guard.is_none();"),
+            None => self.check(format_args!("{arm}.guard.is_none()")),
             Some(hir::Guard::If(expr)) => {
+                let start = self.bindings.get();
                 bind!(self, expr);
-                out!("if let Some(Guard::If({expr})) = {arm}.guard;");
+                self.pat_cond(format!("Some(Guard::If({expr}))"), format!("{arm}.guard"), start);
                 self.expr(expr);
             },
             Some(hir::Guard::IfLet(let_expr)) => {
+                let start = self.bindings.get();
                 bind!(self, let_expr);
-                out!("if let Some(Guard::IfLet({let_expr}) = {arm}.guard;");
+                self.pat_cond(format!("Some(Guard::IfLet({let_expr}))"), format!("{arm}.guard"), start);
                 self.pat(field!(let_expr.pat));
                 self.expr(field!(let_expr.init));
             },
@@ -329,11 +612,17 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
 
     #[allow(clippy::too_many_lines)]
     fn expr(&self, expr: &Binding<&hir::Expr<'_>>) {
+        let start = self.bindings.get();
+        let higher = |pat, ty| self.pat_cond(pat, format!("higher::{ty}::hir({})", node_ref(expr)), start);
+
         if let Some(higher::While { condition, body }) = higher::While::hir(expr.value) {
             bind!(self, condition, body);
-            out!(
-                "if let Some(higher::While {{ condition: {condition}, body: {body} }}) \
-                = higher::While::hir({expr});"
+            higher(
+                format!(
+                    "Some(higher::While {{ {} }})",
+                    struct_fields(&[("condition", condition), ("body", body)])
+                ),
+                "While",
             );
             self.expr(condition);
             self.expr(body);
@@ -347,9 +636,12 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
         }) = higher::WhileLet::hir(expr.value)
         {
             bind!(self, let_pat, let_expr, if_then);
-            out!(
-                "if let Some(higher::WhileLet {{ let_pat: {let_pat}, let_expr: {let_expr}, if_then: {if_then} }}) \
-                = higher::WhileLet::hir({expr});"
+            higher(
+                format!(
+                    "Some(higher::WhileLet {{ {} }})",
+                    struct_fields(&[("let_pat", let_pat), ("let_expr", let_expr), ("if_then", if_then)])
+                ),
+                "WhileLet",
             );
             self.pat(let_pat);
             self.expr(let_expr);
@@ -359,9 +651,12 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
 
         if let Some(higher::ForLoop { pat, arg, body, .. }) = higher::ForLoop::hir(expr.value) {
             bind!(self, pat, arg, body);
-            out!(
-                "if let Some(higher::ForLoop {{ pat: {pat}, arg: {arg}, body: {body}, .. }}) \
-                = higher::ForLoop::hir({expr});"
+            higher(
+                format!(
+                    "Some(higher::ForLoop {{ {}, .. }})",
+                    struct_fields(&[("pat", pat), ("arg", arg), ("body", body)])
+                ),
+                "ForLoop",
             );
             self.pat(pat);
             self.expr(arg);
@@ -369,7 +664,54 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
             return;
         }
 
-        let kind = |kind| out!("if let ExprKind::{kind} = {expr}.kind;");
+        if self.lint
+            && let Some(higher::IfLet {
+                let_pat,
+                let_expr,
+                if_then,
+                if_else,
+            }) = higher::IfLet::hir(self.cx, expr.value)
+        {
+            bind!(self, let_pat, let_expr, if_then);
+            opt_bind!(self, if_else);
+            self.pat_cond(
+                format!(
+                    "Some(higher::IfLet {{ {} }})",
+                    struct_fields(&[
+                        ("let_pat", let_pat),
+                        ("let_expr", let_expr),
+                        ("if_then", if_then),
+                        ("if_else", &if_else)
+                    ])
+                ),
+                format!("higher::IfLet::hir(cx, {})", node_ref(expr)),
+                start,
+            );
+            self.pat(let_pat);
+            self.expr(let_expr);
+            self.expr(if_then);
+            if_else.if_some(|e| self.expr(e));
+            return;
+        }
+
+        if self.lint && let Some(higher::If { cond, then, r#else }) = higher::If::hir(expr.value) {
+            bind!(self, cond, then);
+            let else_expr = r#else;
+            opt_bind!(self, else_expr);
+            higher(
+                format!(
+                    "Some(higher::If {{ {} }})",
+                    struct_fields(&[("cond", cond), ("then", then), ("r#else", &else_expr)])
+                ),
+                "If",
+            );
+            self.expr(cond);
+            self.expr(then);
+            else_expr.if_some(|e| self.expr(e));
+            return;
+        }
+
+        let kind = |kind| self.pat_cond(format!("ExprKind::{kind}"), format!("{expr}.kind"), start);
         macro_rules! kind {
             ($($t:tt)*) => (kind(format_args!($($t)*)));
         }
@@ -379,12 +721,8 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
                 bind!(self, let_expr);
                 kind!("Let({let_expr})");
                 self.pat(field!(let_expr.pat));
-                // Does what ExprKind::Cast does, only adds a clause for the type
-                // if it's a path
-                if let Some(TyKind::Path(ref qpath)) = let_expr.value.ty.as_ref().map(|ty| &ty.kind) {
-                    bind!(self, qpath);
-                    out!("if let TyKind::Path(ref {qpath}) = {let_expr}.ty.kind;");
-                    self.qpath(qpath);
+                if let_expr.value.ty.is_some() {
+                    self.option(field!(let_expr.ty), "ty", |ty| self.ty(ty));
                 }
                 self.expr(field!(let_expr.init));
             },
@@ -419,7 +757,7 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
             ExprKind::Binary(op, left, right) => {
                 bind!(self, op, left, right);
                 kind!("Binary({op}, {left}, {right})");
-                out!("if BinOpKind::{:?} == {op}.node;", op.value.node);
+                self.check(format_args!("BinOpKind::{:?} == {op}.node", op.value.node));
                 self.expr(left);
                 self.expr(right);
             },
@@ -436,11 +774,7 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
             ExprKind::Cast(expr, cast_ty) => {
                 bind!(self, expr, cast_ty);
                 kind!("Cast({expr}, {cast_ty})");
-                if let TyKind::Path(ref qpath) = cast_ty.value.kind {
-                    bind!(self, qpath);
-                    out!("if let TyKind::Path(ref {qpath}) = {cast_ty}.kind;");
-                    self.qpath(qpath);
-                }
+                self.ty(cast_ty);
                 self.expr(expr);
             },
             ExprKind::Type(expr, _ty) => {
@@ -484,13 +818,28 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
                 };
 
                 bind!(self, fn_decl, body_id);
-                kind!("Closure(CaptureBy::{capture_clause:?}, {fn_decl}, {body_id}, _, {movability})");
-                out!("if let {ret_ty} = {fn_decl}.output;");
+                if self.lint {
+                    let capture_clause = format!("CaptureBy::{capture_clause:?}");
+                    let fields = struct_fields(&[
+                        ("capture_clause", &capture_clause),
+                        ("fn_decl", fn_decl),
+                        ("body", body_id),
+                        ("movability", &movability),
+                    ]);
+                    kind!("Closure(&Closure {{ {fields}, .. }})");
+                } else {
+                    kind!("Closure(CaptureBy::{capture_clause:?}, {fn_decl}, {body_id}, _, {movability})");
+                }
+                self.pat_cond(ret_ty.to_string(), format!("{fn_decl}.output"), self.bindings.get());
                 self.body(body_id);
             },
             ExprKind::Yield(sub, source) => {
                 bind!(self, sub);
-                kind!("Yield(sub, YieldSource::{source:?})");
+                if self.lint && let hir::YieldSource::Await { .. } = source {
+                    kind!("Yield({sub}, YieldSource::Await {{ .. }})");
+                } else {
+                    kind!("Yield({sub}, YieldSource::{source:?})");
+                }
                 self.expr(sub);
             },
             ExprKind::Block(block, label) => {
@@ -509,7 +858,7 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
             ExprKind::AssignOp(op, target, value) => {
                 bind!(self, op, target, value);
                 kind!("AssignOp({op}, {target}, {value})");
-                out!("if BinOpKind::{:?} == {op}.node;", op.value.node);
+                self.check(format_args!("BinOpKind::{:?} == {op}.node", op.value.node));
                 self.expr(target);
                 self.expr(value);
             },
@@ -526,9 +875,11 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
                 self.expr(index);
             },
             ExprKind::Path(ref qpath) => {
-                bind!(self, qpath);
-                kind!("Path(ref {qpath})");
-                self.qpath(qpath);
+                if !self.resolved_path(expr, qpath, expr.value.hir_id) {
+                    bind!(self, qpath);
+                    kind!("Path(ref {qpath})");
+                    self.qpath(qpath);
+                }
             },
             ExprKind::AddrOf(kind, mutability, inner) => {
                 bind!(self, inner);
@@ -554,13 +905,17 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
             },
             ExprKind::InlineAsm(_) => {
                 kind!("InlineAsm(_)");
-                out!("// unimplemented: `ExprKind::InlineAsm` is not further destructured at the moment");
+                self.conds.borrow_mut().push(Cond::Comment(String::from(
+                    "// unimplemented: `ExprKind::InlineAsm` is not further destructured at the moment",
+                )));
             },
             ExprKind::Struct(qpath, fields, base) => {
                 bind!(self, qpath, fields);
                 opt_bind!(self, base);
                 kind!("Struct({qpath}, {fields}, {base})");
-                self.qpath(qpath);
+                if !self.resolved_qpath(qpath, expr, expr.value.hir_id) {
+                    self.qpath(qpath);
+                }
                 self.slice(fields, |field| {
                     self.ident(field!(field.ident));
                     self.expr(field!(field.expr));
@@ -572,14 +927,7 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
                 bind!(self, value, length);
                 kind!("Repeat({value}, {length})");
                 self.expr(value);
-                match length.value {
-                    ArrayLen::Infer(..) => out!("if let ArrayLen::Infer(..) = length;"),
-                    ArrayLen::Body(anon_const) => {
-                        bind!(self, anon_const);
-                        out!("if let ArrayLen::Body({anon_const}) = {length};");
-                        self.body(field!(anon_const.body));
-                    },
-                }
+                self.array_len(length);
             },
             ExprKind::Err => kind!("Err"),
             ExprKind::DropTemps(expr) => {
@@ -600,12 +948,16 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
     fn body(&self, body_id: &Binding<hir::BodyId>) {
         let expr = self.cx.tcx.hir().body(body_id.value).value;
         bind!(self, expr);
-        out!("let {expr} = &cx.tcx.hir().body({body_id}).value;");
+        self.conds.borrow_mut().push(Cond::Bind(
+            expr.to_string(),
+            format!("cx.tcx.hir().body({body_id}).value"),
+        ));
         self.expr(expr);
     }
 
     fn pat(&self, pat: &Binding<&hir::Pat<'_>>) {
-        let kind = |kind| out!("if let PatKind::{kind} = {pat}.kind;");
+        let start = self.bindings.get();
+        let kind = |kind| self.pat_cond(format!("PatKind::{kind}"), format!("{pat}.kind"), start);
         macro_rules! kind {
             ($($t:tt)*) => (kind(format_args!($($t)*)));
         }
@@ -628,7 +980,9 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
             PatKind::Struct(ref qpath, fields, ignore) => {
                 bind!(self, qpath, fields);
                 kind!("Struct(ref {qpath}, {fields}, {ignore})");
-                self.qpath(qpath);
+                if !self.resolved_qpath(qpath, pat, pat.value.hir_id) {
+                    self.qpath(qpath);
+                }
                 self.slice(fields, |field| {
                     self.ident(field!(field.ident));
                     self.pat(field!(field.pat));
@@ -642,13 +996,17 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
             PatKind::TupleStruct(ref qpath, fields, skip_pos) => {
                 bind!(self, qpath, fields);
                 kind!("TupleStruct(ref {qpath}, {fields}, {skip_pos:?})");
-                self.qpath(qpath);
+                if !self.resolved_qpath(qpath, pat, pat.value.hir_id) {
+                    self.qpath(qpath);
+                }
                 self.slice(fields, |pat| self.pat(pat));
             },
             PatKind::Path(ref qpath) => {
-                bind!(self, qpath);
-                kind!("Path(ref {qpath})");
-                self.qpath(qpath);
+                if !self.resolved_path(pat, qpath, pat.value.hir_id) {
+                    bind!(self, qpath);
+                    kind!("Path(ref {qpath})");
+                    self.qpath(qpath);
+                }
             },
             PatKind::Tuple(fields, skip_pos) => {
                 bind!(self, fields);
@@ -687,8 +1045,331 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
         }
     }
 
+    fn array_len(&self, length: &Binding<ArrayLen>) {
+        let start = self.bindings.get();
+        match length.value {
+            ArrayLen::Infer(..) => self.pat_cond("ArrayLen::Infer(..)".into(), length.to_string(), start),
+            ArrayLen::Body(anon_const) => {
+                bind!(self, anon_const);
+                self.pat_cond(format!("ArrayLen::Body({anon_const})"), length.to_string(), start);
+                self.body(field!(anon_const.body));
+            },
+        }
+    }
+
+    fn ty(&self, ty: &Binding<&hir::Ty<'_>>) {
+        let start = self.bindings.get();
+        let kind = |kind| self.pat_cond(format!("TyKind::{kind}"), format!("{ty}.kind"), start);
+        macro_rules! kind {
+            ($($t:tt)*) => (kind(format_args!($($t)*)));
+        }
+
+        match ty.value.kind {
+            TyKind::Slice(inner) => {
+                bind!(self, inner);
+                kind!("Slice({inner})");
+                self.ty(inner);
+            },
+            TyKind::Array(inner, length) => {
+                bind!(self, inner, length);
+                kind!("Array({inner}, {length})");
+                self.ty(inner);
+                self.array_len(length);
+            },
+            TyKind::Ptr(MutTy { ty: inner, mutbl }) => {
+                bind!(self, inner);
+                kind!("Ptr(MutTy {{ ty: {inner}, mutbl: Mutability::{mutbl:?} }})");
+                self.ty(inner);
+            },
+            TyKind::Rptr(lifetime, MutTy { ty: inner, mutbl }) => {
+                bind!(self, lifetime, inner);
+                kind!("Rptr({lifetime}, MutTy {{ ty: {inner}, mutbl: Mutability::{mutbl:?} }})");
+                self.lifetime(lifetime);
+                self.ty(inner);
+            },
+            TyKind::BareFn(_) => {
+                kind!("BareFn(_)");
+                self.conds.borrow_mut().push(Cond::Comment(String::from(
+                    "// unimplemented: `TyKind::BareFn` is not further destructured at the moment",
+                )));
+            },
+            TyKind::Never => kind!("Never"),
+            TyKind::Tup(elements) => {
+                bind!(self, elements);
+                kind!("Tup({elements})");
+                self.slice(elements, |ty| self.ty(ty));
+            },
+            TyKind::Path(ref qpath) => {
+                if !self.resolved_path(ty, qpath, ty.value.hir_id) {
+                    bind!(self, qpath);
+                    kind!("Path(ref {qpath})");
+                    self.qpath(qpath);
+                }
+            },
+            TyKind::OpaqueDef(..) => kind!("OpaqueDef(..)"),
+            TyKind::TraitObject(bounds, lifetime, syntax) => {
+                bind!(self, bounds, lifetime);
+                kind!("TraitObject({bounds}, {lifetime}, TraitObjectSyntax::{syntax:?})");
+                self.slice(bounds, |bound| {
+                    self.trait_ref(&Binding {
+                        name: format!("{bound}.trait_ref"),
+                        value: &bound.value.trait_ref,
+                    });
+                });
+                self.lifetime(lifetime);
+            },
+            TyKind::Typeof(_) => kind!("Typeof(_)"),
+            TyKind::Infer => kind!("Infer"),
+            TyKind::Err => kind!("Err"),
+        }
+    }
+
+    fn lifetime(&self, lifetime: &Binding<hir::Lifetime>) {
+        if lifetime.value.is_elided() {
+            self.check(format_args!("{lifetime}.is_elided()"));
+        } else {
+            self.check(format_args!(
+                "{lifetime}.name.ident().as_str() == {:?}",
+                lifetime.value.name.ident().as_str()
+            ));
+        }
+    }
+
+    fn trait_ref(&self, trait_ref: &Binding<&hir::TraitRef<'_>>) {
+        match trait_ref.value.trait_def_id() {
+            Some(def_id) if self.lint && !def_id.is_local() => {
+                let def_id_name = self.next("def_id");
+                self.conds.borrow_mut().push(Cond::Let(
+                    format!("Some({def_id_name})"),
+                    format!("{trait_ref}.trait_def_id()"),
+                ));
+                self.item_def_id(&def_id_name, def_id);
+            },
+            _ => self.check(format_args!(
+                "match_path({trait_ref}.path, &[{}])",
+                trait_ref
+                    .value
+                    .path
+                    .segments
+                    .iter()
+                    .map(|segment| format!("{:?}", segment.ident.as_str()))
+                    .join(", ")
+            )),
+        }
+    }
+
+    fn fn_decl(&self, decl: &Binding<&hir::FnDecl<'_>>) {
+        self.slice(field!(decl.inputs), |input| self.ty(input));
+        let start = self.bindings.get();
+        match decl.value.output {
+            FnRetTy::DefaultReturn(_) => {
+                self.pat_cond("FnRetTy::DefaultReturn(_)".into(), format!("{decl}.output"), start);
+            },
+            FnRetTy::Return(ty) => {
+                bind!(self, ty);
+                self.pat_cond(format!("FnRetTy::Return({ty})"), format!("{decl}.output"), start);
+                self.ty(ty);
+            },
+        }
+    }
+
+    /// The body of a function, with the patterns of its parameters
+    fn fn_body(&self, body_id: &Binding<hir::BodyId>) {
+        let body = self.cx.tcx.hir().body(body_id.value);
+        bind!(self, body);
+        self.conds
+            .borrow_mut()
+            .push(Cond::Bind(body.to_string(), format!("cx.tcx.hir().body({body_id})")));
+        self.slice(field!(body.params), |param| self.pat(field!(param.pat)));
+        self.expr(field!(body.value));
+    }
+
+    fn variant_data(&self, data: &Binding<&hir::VariantData<'_>>) {
+        let start = self.bindings.get();
+        let kind = |kind| self.pat_cond(format!("VariantData::{kind}"), data.to_string(), start);
+        macro_rules! kind {
+            ($($t:tt)*) => (kind(format_args!($($t)*)));
+        }
+
+        match *data.value {
+            VariantData::Struct(fields, _) => {
+                bind!(self, fields);
+                kind!("Struct({fields}, _)");
+                self.slice(fields, |field| {
+                    self.ident(field!(field.ident));
+                    self.ty(field!(field.ty));
+                });
+            },
+            VariantData::Tuple(fields, _) => {
+                bind!(self, fields);
+                kind!("Tuple({fields}, _)");
+                self.slice(fields, |field| self.ty(field!(field.ty)));
+            },
+            VariantData::Unit(_) => kind!("Unit(_)"),
+        }
+    }
+
+    fn item(&self, item: &Binding<&hir::Item<'_>>) {
+        let start = self.bindings.get();
+        let kind = |kind| self.pat_cond(format!("ItemKind::{kind}"), format!("{item}.kind"), start);
+        macro_rules! kind {
+            ($($t:tt)*) => (kind(format_args!($($t)*)));
+        }
+
+        match item.value.kind {
+            ItemKind::ExternCrate(_) => kind!("ExternCrate(_)"),
+            ItemKind::Use(..) => kind!("Use(..)"),
+            ItemKind::Static(ty, mutbl, body_id) => {
+                bind!(self, ty, body_id);
+                kind!("Static({ty}, Mutability::{mutbl:?}, {body_id})");
+                self.ty(ty);
+                self.body(body_id);
+            },
+            ItemKind::Const(ty, body_id) => {
+                bind!(self, ty, body_id);
+                kind!("Const({ty}, {body_id})");
+                self.ty(ty);
+                self.body(body_id);
+            },
+            ItemKind::Fn(FnSig { decl, .. }, _, body_id) => {
+                bind!(self, decl, body_id);
+                kind!("Fn(FnSig {{ {}, .. }}, _, {body_id})", struct_fields(&[("decl", decl)]));
+                self.fn_decl(decl);
+                self.fn_body(body_id);
+            },
+            ItemKind::Macro(..) => kind!("Macro(..)"),
+            ItemKind::Mod(_) => kind!("Mod(_)"),
+            ItemKind::ForeignMod { .. } => kind!("ForeignMod {{ .. }}"),
+            ItemKind::GlobalAsm(_) => kind!("GlobalAsm(_)"),
+            ItemKind::TyAlias(ty, _) => {
+                bind!(self, ty);
+                kind!("TyAlias({ty}, _)");
+                self.ty(ty);
+            },
+            ItemKind::OpaqueTy(_) => kind!("OpaqueTy(_)"),
+            ItemKind::Enum(EnumDef { variants }, _) => {
+                bind!(self, variants);
+                kind!("Enum(EnumDef {{ {} }}, _)", struct_fields(&[("variants", variants)]));
+                self.slice(variants, |variant| {
+                    self.ident(field!(variant.ident));
+                    self.variant_data(&Binding {
+                        name: format!("{variant}.data"),
+                        value: &variant.value.data,
+                    });
+                });
+            },
+            ItemKind::Struct(ref data, _) => {
+                bind!(self, data);
+                kind!("Struct(ref {data}, _)");
+                self.variant_data(data);
+            },
+            ItemKind::Union(ref data, _) => {
+                bind!(self, data);
+                kind!("Union(ref {data}, _)");
+                self.variant_data(data);
+            },
+            ItemKind::Trait(is_auto, unsafety, _, _, items) => {
+                bind!(self, items);
+                kind!("Trait(IsAuto::{is_auto:?}, Unsafety::{unsafety:?}, _, _, {items})");
+                self.slice(items, |item| self.ident(field!(item.ident)));
+            },
+            ItemKind::TraitAlias(..) => kind!("TraitAlias(..)"),
+            ItemKind::Impl(impl_) => {
+                bind!(self, impl_);
+                kind!("Impl({impl_})");
+                match impl_.value.of_trait {
+                    None => self.check(format_args!("{impl_}.of_trait.is_none()")),
+                    Some(ref of_trait) => {
+                        let start = self.bindings.get();
+                        bind!(self, of_trait);
+                        self.pat_cond(format!("Some(ref {of_trait})"), format!("{impl_}.of_trait"), start);
+                        self.trait_ref(of_trait);
+                    },
+                }
+                self.ty(field!(impl_.self_ty));
+                self.slice(field!(impl_.items), |item| self.ident(field!(item.ident)));
+            },
+        }
+        if !item.value.ident.name.is_empty() {
+            self.ident(field!(item.ident));
+        }
+    }
+
+    fn impl_item(&self, item: &Binding<&hir::ImplItem<'_>>) {
+        let start = self.bindings.get();
+        let kind = |kind| self.pat_cond(format!("ImplItemKind::{kind}"), format!("{item}.kind"), start);
+        macro_rules! kind {
+            ($($t:tt)*) => (kind(format_args!($($t)*)));
+        }
+
+        match item.value.kind {
+            ImplItemKind::Const(ty, body_id) => {
+                bind!(self, ty, body_id);
+                kind!("Const({ty}, {body_id})");
+                self.ty(ty);
+                self.body(body_id);
+            },
+            ImplItemKind::Fn(FnSig { decl, .. }, body_id) => {
+                bind!(self, decl, body_id);
+                kind!("Fn(FnSig {{ {}, .. }}, {body_id})", struct_fields(&[("decl", decl)]));
+                self.fn_decl(decl);
+                self.fn_body(body_id);
+            },
+            ImplItemKind::TyAlias(ty) => {
+                bind!(self, ty);
+                kind!("TyAlias({ty})");
+                self.ty(ty);
+            },
+        }
+        self.ident(field!(item.ident));
+    }
+
+    fn trait_item(&self, item: &Binding<&hir::TraitItem<'_>>) {
+        let start = self.bindings.get();
+        let kind = |kind| self.pat_cond(format!("TraitItemKind::{kind}"), format!("{item}.kind"), start);
+        macro_rules! kind {
+            ($($t:tt)*) => (kind(format_args!($($t)*)));
+        }
+
+        match item.value.kind {
+            TraitItemKind::Const(ty, body_id) => {
+                bind!(self, ty);
+                opt_bind!(self, body_id);
+                kind!("Const({ty}, {body_id})");
+                self.ty(ty);
+                body_id.if_some(|body_id| self.body(body_id));
+            },
+            TraitItemKind::Fn(FnSig { decl, .. }, TraitFn::Required(names)) => {
+                bind!(self, decl, names);
+                let sig = struct_fields(&[("decl", decl)]);
+                kind!("Fn(FnSig {{ {sig}, .. }}, TraitFn::Required({names}))");
+                self.fn_decl(decl);
+                self.slice(names, |name| {
+                    self.ident(&Binding {
+                        name: name.name.clone(),
+                        value: *name.value,
+                    });
+                });
+            },
+            TraitItemKind::Fn(FnSig { decl, .. }, TraitFn::Provided(body_id)) => {
+                bind!(self, decl, body_id);
+                let sig = struct_fields(&[("decl", decl)]);
+                kind!("Fn(FnSig {{ {sig}, .. }}, TraitFn::Provided({body_id}))");
+                self.fn_decl(decl);
+                self.fn_body(body_id);
+            },
+            TraitItemKind::Type(_, ty) => {
+                opt_bind!(self, ty);
+                kind!("Type(_, {ty})");
+                ty.if_some(|ty| self.ty(ty));
+            },
+        }
+        self.ident(field!(item.ident));
+    }
+
     fn stmt(&self, stmt: &Binding<&hir::Stmt<'_>>) {
-        let kind = |kind| out!("if let StmtKind::{kind} = {stmt}.kind;");
+        let start = self.bindings.get();
+        let kind = |kind| self.pat_cond(format!("StmtKind::{kind}"), format!("{stmt}.kind"), start);
         macro_rules! kind {
             ($($t:tt)*) => (kind(format_args!($($t)*)));
         }
@@ -701,7 +1382,13 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
                     self.expr(init);
                 });
                 self.pat(field!(local.pat));
+                // unlike the other optional nodes, a missing type annotation isn't checked, as it
+                // rarely matters to the lints
+                if local.value.ty.is_some() {
+                    self.option(field!(local.ty), "ty", |ty| self.ty(ty));
+                }
             },
+            StmtKind::Item(_) if self.lint => kind!("Item(_)"),
             StmtKind::Item(_) => kind!("Item(item_id)"),
             StmtKind::Expr(e) => {
                 bind!(self, e);
@@ -717,11 +1404,32 @@ impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
     }
 }
 
+/// A reference to the node named `node`, borrowing the elements of slices
+fn node_ref(node: &dyn Display) -> String {
+    let node = node.to_string();
+    if node.ends_with(']') { format!("&{node}") } else { node }
+}
+
 fn has_attr(cx: &LateContext<'_>, hir_id: hir::HirId) -> bool {
     let attrs = cx.tcx.hir().attrs(hir_id);
     get_attr(cx.sess(), attrs, "author").count() > 0
 }
 
+/// The fields of a struct pattern, using the shorthand when the binding has the field's name
+fn struct_fields(fields: &[(&str, &dyn Display)]) -> String {
+    fields
+        .iter()
+        .map(|(field, binding)| {
+            let binding = binding.to_string();
+            if binding == *field {
+                binding
+            } else {
+                format!("{field}: {binding}")
+            }
+        })
+        .join(", ")
+}
+
 fn path_to_string(path: &QPath<'_>) -> String {
     fn inner(s: &mut String, path: &QPath<'_>) {
         match *path {
@@ -2,8 +2,12 @@
 
 #![allow(clippy::module_name_repetitions)]
 
+use clippy_utils::overrides::{PathGlobs, PerPath};
+use rustc_lint::Level;
 use serde::de::{Deserializer, IgnoredAny, IntoDeserializer, MapAccess, Visitor};
-use serde::Deserialize;
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::error::Error;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -31,28 +35,61 @@ const DEFAULT_DOC_VALID_IDENTS: &[&str] = &[
     "CamelCase",
 ];
 const DEFAULT_DISALLOWED_NAMES: &[&str] = &["foo", "baz", "quux"];
+const CLIPPY_LINT_GROUPS: &[&str] = &[
+    "all",
+    "cargo",
+    "complexity",
+    "correctness",
+    "nursery",
+    "pedantic",
+    "perf",
+    "restriction",
+    "style",
+    "suspicious",
+];
 
 /// Holds information used by `MISSING_ENFORCED_IMPORT_RENAMES` lint.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Rename {
     pub path: String,
     pub rename: String,
 }
 
-/// A single disallowed method, used by the `DISALLOWED_METHODS` lint.
+/// A single disallowed path, used by the `DISALLOWED_METHODS`, `DISALLOWED_MACROS` and
+/// `DISALLOWED_IMPORTS` lints.
 #[derive(Clone, Debug, Deserialize)]
 #[serde(untagged)]
-pub enum DisallowedMethod {
+pub enum DisallowedPath {
     Simple(String),
-    WithReason { path: String, reason: Option<String> },
+    WithReason {
+        path: String,
+        reason: Option<String>,
+        /// Paths of the modules where the item is allowed
+        #[serde(default, rename = "allow-in", alias = "allow_in")]
+        allow_in: Vec<String>,
+    },
 }
 
-impl DisallowedMethod {
+impl DisallowedPath {
     pub fn path(&self) -> &str {
         let (Self::Simple(path) | Self::WithReason { path, .. }) = self;
 
         path
     }
+
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::Simple(_) => None,
+            Self::WithReason { reason, .. } => reason.as_deref(),
+        }
+    }
+
+    pub fn allow_in(&self) -> &[String] {
+        match self {
+            Self::Simple(_) => &[],
+            Self::WithReason { allow_in, .. } => allow_in,
+        }
+    }
 }
 
 /// A single disallowed type, used by the `DISALLOWED_TYPES` lint.
@@ -60,23 +97,127 @@ impl DisallowedMethod {
 #[serde(untagged)]
 pub enum DisallowedType {
     Simple(String),
-    WithReason { path: String, reason: Option<String> },
+    WithReason {
+        path: String,
+        reason: Option<String>,
+        /// Paths of the modules where the type is allowed
+        #[serde(default, rename = "allow-in", alias = "allow_in")]
+        allow_in: Vec<String>,
+    },
+}
+
+impl DisallowedType {
+    pub fn path(&self) -> &str {
+        let (Self::Simple(path) | Self::WithReason { path, .. }) = self;
+
+        path
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::Simple(_) => None,
+            Self::WithReason { reason, .. } => reason.as_deref(),
+        }
+    }
+
+    pub fn allow_in(&self) -> &[String] {
+        match self {
+            Self::Simple(_) => &[],
+            Self::WithReason { allow_in, .. } => allow_in,
+        }
+    }
+}
+
+// Both forms are written as tables, since TOML arrays mixing strings and tables can't be written
+// as arrays of tables.
+impl Serialize for DisallowedPath {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_disallowed(serializer, self.path(), self.reason(), self.allow_in())
+    }
+}
+
+impl Serialize for DisallowedType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_disallowed(serializer, self.path(), self.reason(), self.allow_in())
+    }
+}
+
+fn serialize_disallowed<S: Serializer>(
+    serializer: S,
+    path: &str,
+    reason: Option<&str>,
+    allow_in: &[String],
+) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("Disallowed", 3)?;
+    state.serialize_field("path", path)?;
+    if let Some(reason) = reason {
+        state.serialize_field("reason", reason)?;
+    }
+    if !allow_in.is_empty() {
+        state.serialize_field("allow-in", allow_in)?;
+    }
+    state.end()
+}
+
+/// A lint level, as written in `clippy.toml`.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+    Forbid,
+}
+
+impl From<LintLevel> for Level {
+    fn from(level: LintLevel) -> Self {
+        match level {
+            LintLevel::Allow => Level::Allow,
+            LintLevel::Warn => Level::Warn,
+            LintLevel::Deny => Level::Deny,
+            LintLevel::Forbid => Level::Forbid,
+        }
+    }
+}
+
+/// Returns the full name of a lint or lint group written in `clippy.toml`. Names without a tool
+/// prefix are Clippy lints.
+pub fn qualified_lint_name(name: &str) -> String {
+    if name.contains("::") {
+        name.to_string()
+    } else {
+        format!("clippy::{name}")
+    }
+}
+
+impl ConfOverride {
+    pub fn globs(&self) -> PathGlobs {
+        PathGlobs::new(self.file.parent().unwrap_or_else(|| Path::new(".")), self.path.clone())
+    }
 }
 
 /// Conf with parse errors
 #[derive(Default)]
 pub struct TryConf {
     pub conf: Conf,
-    pub errors: Vec<Box<dyn Error>>,
-    pub warnings: Vec<Box<dyn Error>>,
+    pub errors: Vec<Box<dyn Error + Send + Sync>>,
+    pub warnings: Vec<Box<dyn Error + Send + Sync>>,
+    /// The configuration files that were read, starting with the inherited ones, along with the
+    /// keys each of them sets
+    pub sources: Vec<(PathBuf, Vec<String>)>,
 }
 
 impl TryConf {
-    fn from_error(error: impl Error + 'static) -> Self {
+    fn from_error(error: impl Error + Send + Sync + 'static) -> Self {
+        Self::from_boxed_error(Box::new(error))
+    }
+
+    fn from_boxed_error(error: Box<dyn Error + Send + Sync>) -> Self {
         Self {
             conf: Conf::default(),
-            errors: vec![Box::new(error)],
+            errors: vec![error],
             warnings: vec![],
+            sources: vec![],
         }
     }
 }
@@ -92,7 +233,7 @@ impl fmt::Display for ConfError {
 
 impl Error for ConfError {}
 
-fn conf_error(s: impl Into<String>) -> Box<dyn Error> {
+fn conf_error(s: impl Into<String>) -> Box<dyn Error + Send + Sync> {
     Box::new(ConfError(s.into()))
 }
 
@@ -100,6 +241,7 @@ macro_rules! define_Conf {
     ($(
         $(#[doc = $doc:literal])+
         $(#[conf_deprecated($dep:literal, $new_conf:ident)])?
+        $(#[per_path $($per_path:tt)*])?
         ($name:ident: $ty:ty = $default:expr),
     )*) => {
         /// Clippy lint configuration
@@ -107,6 +249,22 @@ macro_rules! define_Conf {
             $($(#[doc = $doc])+ pub $name: $ty,)*
         }
 
+        /// An `[[overrides]]` table, which changes the configuration for the files matching `path`.
+        /// It can set the options marked with `#[per_path]`.
+        #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+        #[serde(deny_unknown_fields, rename_all = "kebab-case")]
+        pub struct ConfOverride {
+            /// Globs of the files to apply the override to, relative to the directory of `clippy.toml`
+            pub path: Vec<String>,
+            $($($($per_path)* pub $name: Option<$ty>,)?)*
+            /// Lint levels, keyed by lint or lint group names like `clippy::unwrap_used`
+            #[serde(default)]
+            pub lints: BTreeMap<String, LintLevel>,
+            /// The configuration file the override is written in
+            #[serde(skip)]
+            pub file: PathBuf,
+        }
+
         mod defaults {
             $(pub fn $name() -> $ty { $default })*
         }
@@ -117,6 +275,24 @@ macro_rules! define_Conf {
             }
         }
 
+        impl Conf {
+            /// Returns the name and value of every option that isn't deprecated. The value is
+            /// `None` for unset options.
+            pub fn values(&self) -> Vec<(String, Option<toml::Value>)> {
+                let values = vec![$(
+                    (
+                        stringify!($name).replace('_', "-"),
+                        None::<&str>$(.or(Some($dep)))?.is_none(),
+                        toml::Value::try_from(&self.$name).ok(),
+                    ),
+                )*];
+                values
+                    .into_iter()
+                    .filter_map(|(name, supported, value)| supported.then_some((name, value)))
+                    .collect()
+            }
+        }
+
         impl<'de> Deserialize<'de> for TryConf {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
                 deserializer.deserialize_map(ConfVisitor)
@@ -126,7 +302,7 @@ macro_rules! define_Conf {
         #[derive(Deserialize)]
         #[serde(field_identifier, rename_all = "kebab-case")]
         #[allow(non_camel_case_types)]
-        enum Field { $($name,)* third_party, }
+        enum Field { $($name,)* third_party, inherit, extends, }
 
         struct ConfVisitor;
 
@@ -166,11 +342,13 @@ macro_rules! define_Conf {
                             }
                         })*
                         // white-listed; ignore
-                        Field::third_party => drop(map.next_value::<IgnoredAny>())
+                        Field::third_party => drop(map.next_value::<IgnoredAny>()),
+                        // handled by `read`
+                        Field::inherit | Field::extends => drop(map.next_value::<IgnoredAny>()),
                     }
                 }
                 let conf = Conf { $($name: $name.unwrap_or_else(defaults::$name),)* };
-                Ok(TryConf { conf, errors, warnings })
+                Ok(TryConf { conf, errors, warnings, sources: Vec::new() })
             }
         }
 
@@ -193,7 +371,7 @@ macro_rules! define_Conf {
                                 stringify!($name),
                                 stringify!($ty),
                                 format!("{:?}", super::defaults::$name()),
-                                concat!($($doc, '\n',)*),
+                                concat!($($doc, '\n',)* $($($per_path)* "\n Can also be set per path in `[[overrides]]`.\n",)?),
                                 deprecation_reason,
                             )
                         },
@@ -204,8 +382,11 @@ macro_rules! define_Conf {
     };
 }
 
+// The documentation of an option starts with `Lint: LINT_A, LINT_B.`, naming the lints it affects.
+// Options that apply to all lints, like `lints` and `overrides`, leave that line out. `#[per_path]`
+// marks the options that `[[overrides]]` tables can set.
 define_Conf! {
-    /// Lint: Arithmetic.
+    /// Lint: ARITHMETIC_SIDE_EFFECTS.
     ///
     /// Suppress checking of the passed type names.
     (arithmetic_side_effects_allowed: rustc_data_structures::fx::FxHashSet<String> = <_>::default()),
@@ -250,10 +431,12 @@ define_Conf! {
     /// Lint: TOO_MANY_ARGUMENTS.
     ///
     /// The maximum number of argument a function or method can have
+    #[per_path]
     (too_many_arguments_threshold: u64 = 7),
     /// Lint: TYPE_COMPLEXITY.
     ///
     /// The maximum complexity a type can have
+    #[per_path]
     (type_complexity_threshold: u64 = 250),
     /// Lint: MANY_SINGLE_CHAR_NAMES.
     ///
@@ -290,6 +473,7 @@ define_Conf! {
     /// Lint: TOO_MANY_LINES.
     ///
     /// The maximum number of lines a function or method can have
+    #[per_path]
     (too_many_lines_threshold: u64 = 100),
     /// Lint: LARGE_STACK_ARRAYS, LARGE_CONST_ARRAYS.
     ///
@@ -306,10 +490,12 @@ define_Conf! {
     /// Lint: STRUCT_EXCESSIVE_BOOLS.
     ///
     /// The maximum number of bool fields a struct can have
+    #[per_path]
     (max_struct_bools: u64 = 3),
     /// Lint: FN_PARAMS_EXCESSIVE_BOOLS.
     ///
     /// The maximum number of bool parameters a function can have
+    #[per_path]
     (max_fn_params_bools: u64 = 3),
     /// Lint: WILDCARD_IMPORTS.
     ///
@@ -318,7 +504,15 @@ define_Conf! {
     /// Lint: DISALLOWED_METHODS.
     ///
     /// The list of disallowed methods, written as fully qualified paths.
-    (disallowed_methods: Vec<crate::utils::conf::DisallowedMethod> = Vec::new()),
+    (disallowed_methods: Vec<crate::utils::conf::DisallowedPath> = Vec::new()),
+    /// Lint: DISALLOWED_MACROS.
+    ///
+    /// The list of disallowed macros, written as fully qualified paths.
+    (disallowed_macros: Vec<crate::utils::conf::DisallowedPath> = Vec::new()),
+    /// Lint: DISALLOWED_IMPORTS, DISALLOWED_DEPENDENCIES.
+    ///
+    /// The list of crates and modules that may not be imported, written as paths like `tokio` or `std::net`.
+    (disallowed_imports: Vec<crate::utils::conf::DisallowedPath> = Vec::new()),
     /// Lint: DISALLOWED_TYPES.
     ///
     /// The list of disallowed types, written as fully qualified paths.
@@ -382,20 +576,80 @@ define_Conf! {
     /// Lint: RESULT_LARGE_ERR
     ///
     /// The maximum size of the `Err`-variant in a `Result` returned from a function
+    #[per_path]
     (large_error_threshold: u64 = 128),
+    /// Changes the configuration and lint levels for the files matching a list of path globs. For example:
+    /// ```toml
+    /// [[overrides]]
+    /// path = ["tests/**", "src/generated/**"]
+    /// too-many-lines-threshold = 300
+    /// lints = { "clippy::unwrap_used" = "allow" }
+    /// ```
+    /// The options that can be set per path say so in their documentation. Levels set with attributes in the source
+    /// code still take precedence, and levels forbidden with `-F` are never lowered. The levels don't apply to the
+    /// lints checked before type checking, like `double_neg`.
+    (overrides: Vec<crate::utils::conf::ConfOverride> = Vec::new()),
+    /// Lint levels for all crates using this configuration, keyed by lint or lint group names. Names without a tool
+    /// prefix are Clippy lints. For example:
+    /// ```toml
+    /// [lints]
+    /// pedantic = "warn"
+    /// needless_return = "allow"
+    /// ```
+    /// Lint groups are applied before single lints, and flags on the command line take precedence.
+    (lints: std::collections::BTreeMap<String, crate::utils::conf::LintLevel> = std::collections::BTreeMap::new()),
+}
+
+impl Conf {
+    /// Returns the levels of the `[lints]` table as command line lint options, with lint groups
+    /// first so that the levels of single lints take precedence over them.
+    pub fn lint_opts(&self) -> Vec<(String, Level)> {
+        let mut lint_opts = self
+            .lints
+            .iter()
+            .map(|(name, &level)| (qualified_lint_name(name), level.into()))
+            .collect::<Vec<_>>();
+        lint_opts.sort_by_key(|(name, _)| {
+            !name
+                .strip_prefix("clippy::")
+                .map_or(false, |name| CLIPPY_LINT_GROUPS.contains(&name))
+        });
+        lint_opts
+    }
+
+    /// Combines a configuration value with the values `[[overrides]]` tables set for it.
+    pub fn per_path<T: Copy>(&self, value: T, get: impl Fn(&ConfOverride) -> Option<T>) -> PerPath<T> {
+        self.overrides
+            .iter()
+            .fold(PerPath::new(value), |per_path, conf_override| {
+                match get(conf_override) {
+                    Some(value) => per_path.with_override(conf_override.globs(), value),
+                    None => per_path,
+                }
+            })
+    }
 }
 
 /// Search for the configuration file.
+///
+/// # Errors
+///
+/// Returns any unexpected filesystem error encountered while searching for the config file
 pub fn lookup_conf_file() -> io::Result<Option<PathBuf>> {
-    /// Possible filename to search for.
-    const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
-
     // Start looking for a config file in CLIPPY_CONF_DIR, or failing that, CARGO_MANIFEST_DIR.
     // If neither of those exist, use ".".
-    let mut current = env::var_os("CLIPPY_CONF_DIR")
+    let current = env::var_os("CLIPPY_CONF_DIR")
         .or_else(|| env::var_os("CARGO_MANIFEST_DIR"))
         .map_or_else(|| PathBuf::from("."), PathBuf::from);
 
+    find_conf_file(current)
+}
+
+/// Search for a configuration file in `current` and its parent directories.
+fn find_conf_file(mut current: PathBuf) -> io::Result<Option<PathBuf>> {
+    /// Possible filename to search for.
+    const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
+
     let mut found_config: Option<PathBuf> = None;
 
     loop {
@@ -432,23 +686,175 @@ pub fn lookup_conf_file() -> io::Result<Option<PathBuf>> {
     }
 }
 
-/// Read the `toml` configuration file.
+/// A configuration file, read as part of a chain of files inheriting from each other.
+struct Layer {
+    path: PathBuf,
+    content: String,
+    table: toml::value::Table,
+}
+
+/// Reads the configuration file at `path` and the files it inherits from, with `inherit = true`
+/// or `extends = "path"`. The returned layers start with the file everything inherits from.
+fn read_layers(path: &Path) -> Result<Vec<Layer>, Box<dyn Error + Send + Sync>> {
+    let mut layers = Vec::<Layer>::new();
+    let mut next = Some(path.to_path_buf());
+    while let Some(path) = next {
+        if layers.iter().any(|layer| layer.path == path) {
+            return Err(conf_error(format!(
+                "`{}` inherits from itself through `{}`",
+                path.display(),
+                layers[layers.len() - 1].path.display()
+            )));
+        }
+        let content =
+            fs::read_to_string(&path).map_err(|e| conf_error(format!("failed to read `{}`: {e}", path.display())))?;
+        let table = toml::from_str::<toml::value::Table>(&content)?;
+        let dir = path.parent().unwrap_or_else(|| Path::new("."));
+        next = match (table.get("extends"), table.get("inherit")) {
+            (Some(toml::Value::String(extends)), _) => Some(dir.join(extends).canonicalize().map_err(|e| {
+                conf_error(format!(
+                    "failed to find `{extends}`, extended by `{}`: {e}",
+                    path.display()
+                ))
+            })?),
+            (Some(_), _) => return Err(conf_error("`extends` must be the path of a configuration file")),
+            (None, Some(toml::Value::Boolean(true))) => match dir.parent() {
+                Some(parent) => Some(find_conf_file(parent.to_path_buf())?.ok_or_else(|| {
+                    conf_error(format!(
+                        "`{}` sets `inherit = true`, but there is no configuration file in the parent directories",
+                        path.display()
+                    ))
+                })?),
+                None => None,
+            },
+            (None, Some(toml::Value::Boolean(false)) | None) => None,
+            (None, Some(_)) => return Err(conf_error("`inherit` must be `true` or `false`")),
+        };
+        layers.push(Layer { path, content, table });
+    }
+    layers.reverse();
+    Ok(layers)
+}
+
+/// Merges the values of an inheriting configuration file into the inherited ones. Lists are
+/// concatenated, tables like `lints` are merged, and all other values are replaced.
+fn merge_layer(merged: &mut toml::value::Table, layer: toml::value::Table) {
+    for (key, value) in layer {
+        if key == "inherit" || key == "extends" {
+            continue;
+        }
+        match (merged.get_mut(&key), value) {
+            (Some(toml::Value::Array(merged)), toml::Value::Array(values)) => {
+                for value in values {
+                    // `[[overrides]]` tables are kept as they are, even if they are the same
+                    if value.is_table() || !merged.contains(&value) {
+                        merged.push(value);
+                    }
+                }
+            },
+            (Some(toml::Value::Table(merged)), toml::Value::Table(values)) => merged.extend(values),
+            (_, value) => {
+                merged.insert(key, value);
+            },
+        }
+    }
+}
+
+/// Read the `toml` configuration file, and the files it inherits from.
 ///
 /// In case of error, the function tries to continue as much as possible.
 pub fn read(path: &Path) -> TryConf {
-    let content = match fs::read_to_string(path) {
-        Err(e) => return TryConf::from_error(e),
-        Ok(content) => content,
+    let layers = match read_layers(path) {
+        Ok(layers) => layers,
+        Err(e) => return TryConf::from_boxed_error(e),
     };
-    match toml::from_str::<TryConf>(&content) {
-        Ok(mut conf) => {
-            extend_vec_if_indicator_present(&mut conf.conf.doc_valid_idents, DEFAULT_DOC_VALID_IDENTS);
-            extend_vec_if_indicator_present(&mut conf.conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
+    let mut conf = match &layers[..] {
+        [layer] => match toml::from_str::<TryConf>(&layer.content) {
+            Ok(conf) => conf,
+            Err(e) => return TryConf::from_error(e),
+        },
+        [inherited @ .., layer] => {
+            // The errors are reported for each file, so that they have the right line numbers and
+            // aren't reported twice.
+            let mut errors = Vec::new();
+            let mut warnings = Vec::new();
+            for layer in inherited {
+                let in_layer =
+                    |e: Box<dyn Error + Send + Sync>| conf_error(format!("in `{}`: {e}", layer.path.display()));
+                match toml::from_str::<TryConf>(&layer.content) {
+                    Ok(conf) => {
+                        errors.extend(conf.errors.into_iter().map(in_layer));
+                        warnings.extend(conf.warnings.into_iter().map(in_layer));
+                    },
+                    Err(e) => errors.push(in_layer(Box::new(e))),
+                }
+            }
+            match toml::from_str::<TryConf>(&layer.content) {
+                Ok(conf) => {
+                    errors.extend(conf.errors);
+                    warnings.extend(conf.warnings);
+                },
+                Err(e) => errors.push(Box::new(e)),
+            }
 
-            conf
+            let mut merged = toml::value::Table::new();
+            for layer in &layers {
+                merge_layer(&mut merged, layer.table.clone());
+            }
+            let merged = toml::to_string(&toml::Value::Table(merged))
+                .map_err(Into::into)
+                .and_then(|merged| {
+                    toml::from_str::<TryConf>(&merged)
+                        .map_err(|e| conf_error(format!("failed to merge the configuration files: {e}")))
+                });
+            match merged {
+                Ok(conf) => TryConf {
+                    errors,
+                    warnings,
+                    ..conf
+                },
+                Err(e) => {
+                    errors.push(e);
+                    TryConf {
+                        errors,
+                        warnings,
+                        ..TryConf::default()
+                    }
+                },
+            }
         },
-        Err(e) => TryConf::from_error(e),
+        [] => unreachable!(),
+    };
+
+    extend_vec_if_indicator_present(&mut conf.conf.doc_valid_idents, DEFAULT_DOC_VALID_IDENTS);
+    extend_vec_if_indicator_present(&mut conf.conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
+
+    // The paths of `[[overrides]]` are relative to the file they are written in
+    let override_files = layers.iter().flat_map(|layer| {
+        let count = layer
+            .table
+            .get("overrides")
+            .and_then(toml::Value::as_array)
+            .map_or(0, Vec::len);
+        iter::repeat(&layer.path).take(count)
+    });
+    for (conf_override, file) in conf.conf.overrides.iter_mut().zip(override_files) {
+        conf_override.file = file.clone();
     }
+
+    conf.sources = layers
+        .into_iter()
+        .map(|layer| {
+            let keys = layer
+                .table
+                .into_iter()
+                .map(|(key, _)| key)
+                .filter(|key| key != "inherit" && key != "extends")
+                .collect();
+            (layer.path, keys)
+        })
+        .collect();
+    conf
 }
 
 fn extend_vec_if_indicator_present(vec: &mut Vec<String>, default: &[&str]) {
@@ -461,11 +867,11 @@ const SEPARATOR_WIDTH: usize = 4;
 
 // Check whether the error is "unknown field" and, if so, list the available fields sorted and at
 // least one per line, more if `CLIPPY_TERMINAL_WIDTH` is set and allows it.
-pub fn format_error(error: Box<dyn Error>) -> String {
+pub fn format_error(error: &(dyn Error + 'static)) -> String {
     let s = error.to_string();
 
     if_chain! {
-        if error.downcast::<toml::de::Error>().is_ok();
+        if error.is::<toml::de::Error>();
         if let Some((prefix, mut fields, suffix)) = parse_unknown_field_message(&s);
         then {
             use fmt::Write;
@@ -0,0 +1,249 @@
+//! Resolution of the paths in `disallowed-methods` and `disallowed-types`.
+//!
+//! A path is either the exact path of an item, or a pattern where `*` matches any characters
+//! within a segment and `**` matches any number of segments, like `std::process::*`. A pattern
+//! also matches the items nested in the items it matches, so `std::process::*` matches
+//! `std::process::Command::new` as well. The paths in `allow-in` are module paths, which can use
+//! the same wildcards and start with `crate` to refer to the crate being checked.
+//!
+//! Patterns match both the paths where items are defined and the paths they are exported under,
+//! so `std::sync::*` matches `std::sync::Arc`, which is defined as `alloc::sync::Arc`.
+
+use clippy_utils::def_path_res;
+use clippy_utils::overrides::glob_match_segments;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, DefIdMap};
+use rustc_hir::{HirId, PrimTy};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{AdtDef, DefIdTree, TyCtxt};
+use rustc_span::Symbol;
+
+/// The resolved paths of a `disallowed-*` configuration. Each match is reported as the index of
+/// the entry in the configuration.
+#[derive(Clone, Debug, Default)]
+pub struct DisallowedPaths {
+    def_ids: DefIdMap<usize>,
+    prim_tys: FxHashMap<PrimTy, usize>,
+    patterns: Vec<(Vec<String>, usize)>,
+    /// The items the patterns match through the paths they are exported under
+    exported: DefIdMap<usize>,
+    allow_in: Vec<Vec<Vec<String>>>,
+}
+
+impl DisallowedPaths {
+    /// Resolves the configured `(path, allow_in)` pairs.
+    pub fn new<'a>(cx: &LateContext<'_>, paths: impl IntoIterator<Item = (&'a str, &'a [String])>) -> Self {
+        Self::resolve(cx, paths, false)
+    }
+
+    /// Resolves the configured `(path, allow_in)` pairs, where every path also matches the items
+    /// nested in it, the way a crate or module matches everything inside of it.
+    pub fn with_nested_items<'a>(
+        cx: &LateContext<'_>,
+        paths: impl IntoIterator<Item = (&'a str, &'a [String])>,
+    ) -> Self {
+        Self::resolve(cx, paths, true)
+    }
+
+    fn resolve<'a>(
+        cx: &LateContext<'_>,
+        paths: impl IntoIterator<Item = (&'a str, &'a [String])>,
+        nested_items: bool,
+    ) -> Self {
+        let mut disallowed = Self::default();
+        for (index, (path, allow_in)) in paths.into_iter().enumerate() {
+            let segments = split_path(cx, path);
+            if nested_items || segments.iter().any(|segment| segment.contains('*')) {
+                for def_id in exported_items(cx, &segments) {
+                    disallowed.exported.entry(def_id).or_insert(index);
+                }
+                disallowed.patterns.push((resolve_prefix(cx, segments), index));
+            } else {
+                let segments: Vec<_> = segments.iter().map(String::as_str).collect();
+                match def_path_res(cx, &segments) {
+                    Res::Def(_, id) => {
+                        disallowed.def_ids.insert(id, index);
+                    },
+                    Res::PrimTy(ty) => {
+                        disallowed.prim_tys.insert(ty, index);
+                    },
+                    // Items of the local crate can't be resolved by path, but can be matched as
+                    // a pattern without wildcards.
+                    _ => disallowed
+                        .patterns
+                        .push((segments.iter().map(ToString::to_string).collect(), index)),
+                }
+            }
+            disallowed
+                .allow_in
+                .push(allow_in.iter().map(|module| split_path(cx, module)).collect());
+        }
+        disallowed
+    }
+
+    /// Returns the entry disallowing the item `def_id` used at `hir_id`. Implementations of trait
+    /// methods are disallowed by the paths of the trait methods.
+    pub fn find_def(&self, cx: &LateContext<'_>, def_id: DefId, hir_id: HirId) -> Option<usize> {
+        let trait_item = cx
+            .tcx
+            .opt_associated_item(def_id)
+            .and_then(|item| item.trait_item_def_id);
+        [Some(def_id), trait_item]
+            .into_iter()
+            .flatten()
+            .find_map(|def_id| {
+                self.def_ids
+                    .get(&def_id)
+                    .copied()
+                    .or_else(|| self.match_pattern(cx, def_id))
+            })
+            .filter(|&index| !self.is_allowed_in(cx, index, hir_id))
+    }
+
+    /// Returns the entry disallowing the primitive type `prim` used at `hir_id`.
+    pub fn find_prim(&self, cx: &LateContext<'_>, prim: PrimTy, hir_id: HirId) -> Option<usize> {
+        self.prim_tys
+            .get(&prim)
+            .copied()
+            .filter(|&index| !self.is_allowed_in(cx, index, hir_id))
+    }
+
+    fn match_pattern(&self, cx: &LateContext<'_>, def_id: DefId) -> Option<usize> {
+        if self.patterns.is_empty() {
+            return None;
+        }
+        let path = def_path(cx, def_id);
+        let path: Vec<_> = path.iter().map(String::as_str).collect();
+        self.patterns
+            .iter()
+            .find(|(pattern, _)| {
+                let pattern: Vec<_> = pattern.iter().map(String::as_str).collect();
+                glob_match_segments(&pattern, &path)
+            })
+            .map(|&(_, index)| index)
+            .or_else(|| self.match_exported(cx.tcx, def_id))
+    }
+
+    /// Finds the item or the innermost item containing it among the items the patterns match
+    /// through their exported paths. The items of inherent impls are contained in their type.
+    fn match_exported(&self, tcx: TyCtxt<'_>, def_id: DefId) -> Option<usize> {
+        let mut current = Some(def_id);
+        while let Some(def_id) = current {
+            if let Some(&index) = self.exported.get(&def_id) {
+                return Some(index);
+            }
+            current = match tcx.def_kind(def_id) {
+                DefKind::Impl => tcx
+                    .type_of(def_id)
+                    .ty_adt_def()
+                    .map(AdtDef::did)
+                    .or_else(|| tcx.opt_parent(def_id)),
+                _ => tcx.opt_parent(def_id),
+            };
+        }
+        None
+    }
+
+    fn is_allowed_in(&self, cx: &LateContext<'_>, index: usize, hir_id: HirId) -> bool {
+        let allow_in = &self.allow_in[index];
+        if allow_in.is_empty() {
+            return false;
+        }
+        let module = def_path(cx, cx.tcx.parent_module(hir_id).to_def_id());
+        let module: Vec<_> = module.iter().map(String::as_str).collect();
+        allow_in.iter().any(|allowed| {
+            let allowed: Vec<_> = allowed.iter().map(String::as_str).collect();
+            glob_match_segments(&allowed, &module)
+        })
+    }
+}
+
+/// Splits a configured path into its segments, replacing a leading `crate` with the name of the
+/// local crate.
+fn split_path(cx: &LateContext<'_>, path: &str) -> Vec<String> {
+    let mut segments: Vec<_> = path.split("::").map(ToString::to_string).collect();
+    if segments[0] == "crate" {
+        segments[0] = cx.tcx.crate_name(rustc_hir::def_id::LOCAL_CRATE).to_string();
+    }
+    segments
+}
+
+/// Replaces the part of a pattern before the first wildcard with the path of the item it
+/// resolves to, so that patterns through re-exports like `std::vec::*` match the items where
+/// they are defined.
+fn resolve_prefix(cx: &LateContext<'_>, mut segments: Vec<String>) -> Vec<String> {
+    let prefix_len = segments
+        .iter()
+        .position(|segment| segment.contains('*'))
+        .unwrap_or(segments.len());
+    let prefix: Vec<_> = segments[..prefix_len].iter().map(String::as_str).collect();
+    if let Res::Def(_, id) = def_path_res(cx, &prefix) {
+        segments.splice(..prefix_len, def_path(cx, id));
+    }
+    segments
+}
+
+/// Finds the items a pattern matches by following the names they are exported under from the
+/// item its part before the first wildcard resolves to, like `alloc::sync::Arc` for
+/// `std::sync::*`.
+fn exported_items(cx: &LateContext<'_>, segments: &[String]) -> Vec<DefId> {
+    let prefix_len = segments
+        .iter()
+        .position(|segment| segment.contains('*'))
+        .unwrap_or(segments.len());
+    let prefix: Vec<_> = segments[..prefix_len].iter().map(String::as_str).collect();
+    let Res::Def(_, prefix) = def_path_res(cx, &prefix) else {
+        return Vec::new();
+    };
+    let pattern: Vec<_> = segments[prefix_len..].iter().map(String::as_str).collect();
+    let mut items = Vec::new();
+    collect_exported_items(cx.tcx, prefix, &pattern, &mut FxHashSet::default(), &mut items);
+    items
+}
+
+fn collect_exported_items(
+    tcx: TyCtxt<'_>,
+    def_id: DefId,
+    pattern: &[&str],
+    visited: &mut FxHashSet<(DefId, usize)>,
+    items: &mut Vec<DefId>,
+) {
+    // Modules can re-export each other
+    if !visited.insert((def_id, pattern.len())) {
+        return;
+    }
+    let children = || {
+        // The children of local modules are matched by their paths
+        let has_children =
+            !def_id.is_local() && matches!(tcx.def_kind(def_id), DefKind::Mod | DefKind::Enum | DefKind::Trait);
+        let children = if has_children { tcx.module_children(def_id) } else { &[] };
+        children
+            .iter()
+            .filter(|child| child.vis.is_public())
+            .filter_map(|child| Some((child.ident.name, child.res.opt_def_id()?)))
+    };
+    match pattern.split_first() {
+        None => items.push(def_id),
+        Some((&"**", rest)) => {
+            collect_exported_items(tcx, def_id, rest, visited, items);
+            for (_, child) in children() {
+                collect_exported_items(tcx, child, pattern, visited, items);
+            }
+        },
+        Some((segment, rest)) => {
+            for (name, child) in children() {
+                if glob_match_segments(&[segment], &[name.as_str()]) {
+                    collect_exported_items(tcx, child, rest, visited, items);
+                }
+            }
+        },
+    }
+}
+
+fn def_path(cx: &LateContext<'_>, def_id: DefId) -> Vec<String> {
+    cx.get_def_path(def_id)
+        .into_iter()
+        .map(Symbol::to_ident_string)
+        .collect()
+}
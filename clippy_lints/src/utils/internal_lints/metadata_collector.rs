@@ -36,6 +36,11 @@ use std::process::Command;
 
 /// This is the output file of the lint collector.
 const OUTPUT_FILE: &str = "../util/gh-pages/lints.json";
+/// The file of the applicability list used by `cargo clippy --lint-metadata`, relative to the
+/// project root
+const APPLICABILITY_LIST_FILE: &str = "src/lint_list.rs";
+/// The start of the applicability list in `APPLICABILITY_LIST_FILE`
+const APPLICABILITY_LIST_START: &str = "pub static APPLICABILITIES: &[(&str, &str)] = &[\n";
 /// These lints are excluded from the export.
 const BLACK_LISTED_LINTS: &[&str] = &["lint_author", "dump_hir", "internal_metadata_collector"];
 /// These groups will be ignored by the lint group matcher. This is useful for collections like
@@ -205,7 +210,31 @@ impl Drop for MetadataCollector {
         }
         let mut file = OpenOptions::new().write(true).create(true).open(OUTPUT_FILE).unwrap();
         writeln!(file, "{}", serde_json::to_string_pretty(&lints).unwrap()).unwrap();
+
+        update_applicability_list(&lints, &self.clippy_project_root);
+    }
+}
+
+/// Replaces the applicability list of `APPLICABILITY_LIST_FILE` with the resolved applicability
+/// of each lint. The lints are sorted by name, as the list is searched with a binary search.
+fn update_applicability_list(lints: &[LintMetadata], clippy_project_root: &Path) {
+    let path = clippy_project_root.join(APPLICABILITY_LIST_FILE);
+    let contents = fs::read_to_string(&path).unwrap();
+    let (before, list) = contents
+        .split_once(APPLICABILITY_LIST_START)
+        .expect("failed to find the applicability list");
+    let (_, after) = list
+        .split_once("];\n")
+        .expect("failed to find the end of the applicability list");
+
+    let mut list = String::new();
+    for lint in lints {
+        if let Some(index) = lint.applicability.as_ref().and_then(|info| info.applicability) {
+            let applicability = paths::APPLICABILITY_VALUES[index][APPLICABILITY_NAME_INDEX];
+            let _ = writeln!(list, "    ({:?}, {applicability:?}),", lint.id);
+        }
     }
+    fs::write(path, format!("{before}{APPLICABILITY_LIST_START}{list}];\n{after}")).unwrap();
 }
 
 #[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
@@ -521,10 +550,14 @@ fn collect_configs() -> Vec<ClippyConfiguration> {
 /// ```rust, ignore
 /// Some(["lint_name_1", "lint_name_2"], "Papa penguin, papa penguin")
 /// ```
+///
+/// The options applying to all lints have no `Lint:` line and yield no lints.
 fn parse_config_field_doc(doc_comment: &str) -> Option<(Vec<String>, String)> {
     const DOC_START: &str = " Lint: ";
+    if !doc_comment.starts_with(DOC_START) {
+        return Some((vec![], doc_comment.trim().replace("\n ", "\n    ")));
+    }
     if_chain! {
-        if doc_comment.starts_with(DOC_START);
         if let Some(split_pos) = doc_comment.find('.');
         then {
             let mut doc_comment = doc_comment.to_string();
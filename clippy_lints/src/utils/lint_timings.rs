@@ -0,0 +1,203 @@
+//! Timing of Clippy's lint passes, enabled by the `CLIPPY_LINT_TIMINGS` environment variable.
+//!
+//! Every lint pass Clippy registers is wrapped in a pass that measures the time spent in each of
+//! its `check_*` methods. Once the crate is checked, the totals are written to a file in the
+//! directory given by the variable, with one line per pass and method:
+//!
+//! ```text
+//! <pre-expansion|early|late> <pass> <method> <calls> <nanoseconds>
+//! ```
+//!
+//! with the fields separated by tabs. `cargo lintcheck --timings` aggregates these files.
+
+use rustc_ast as ast;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::sync;
+use rustc_hir as hir;
+use rustc_lint::{
+    early_lint_methods, late_lint_methods, EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintPass, LintStore,
+};
+use rustc_span::symbol::Ident;
+use rustc_span::Span;
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// The timings of the passes that were dropped, which happens once they checked the crate.
+static FINISHED: Mutex<Vec<PassTimings>> = Mutex::new(Vec::new());
+
+struct PassTimings {
+    kind: &'static str,
+    pass: &'static str,
+    /// The number of calls and the total time of each method
+    methods: FxHashMap<&'static str, (u64, Duration)>,
+}
+
+impl PassTimings {
+    fn new(kind: &'static str, pass: &'static str) -> Self {
+        Self {
+            kind,
+            pass,
+            methods: FxHashMap::default(),
+        }
+    }
+
+    fn record(&mut self, method: &'static str, start: Instant) {
+        let (calls, total) = self.methods.entry(method).or_default();
+        *calls += 1;
+        *total += start.elapsed();
+    }
+
+    fn finish(&mut self) {
+        let methods = std::mem::take(&mut self.methods);
+        FINISHED.lock().unwrap().push(Self { methods, ..*self });
+    }
+}
+
+/// The number of passes of each kind in a `LintStore`, used to find the ones Clippy registers.
+#[derive(Clone, Copy, Debug)]
+pub struct RegisteredPasses {
+    pre_expansion: usize,
+    early: usize,
+    late: usize,
+}
+
+impl RegisteredPasses {
+    pub fn count(store: &LintStore) -> Self {
+        Self {
+            pre_expansion: store.pre_expansion_passes.len(),
+            early: store.early_passes.len(),
+            late: store.late_passes.len(),
+        }
+    }
+}
+
+type EarlyLintPassObject = Box<dyn EarlyLintPass + sync::Send + 'static>;
+type LateLintPassObject = Box<dyn for<'tcx> LateLintPass<'tcx> + sync::Send + 'static>;
+type EarlyPassCtor = Box<dyn Fn() -> EarlyLintPassObject + sync::Send + sync::Sync>;
+type LatePassCtor = Box<dyn Fn() -> LateLintPassObject + sync::Send + sync::Sync>;
+
+fn time_early_passes(ctors: &mut Vec<EarlyPassCtor>, from: usize, kind: &'static str) {
+    let timed = ctors.split_off(from).into_iter().map(|ctor| -> EarlyPassCtor {
+        Box::new(move || {
+            let pass = ctor();
+            let timings = PassTimings::new(kind, pass.name());
+            Box::new(TimedEarlyPass { pass, timings })
+        })
+    });
+    ctors.extend(timed);
+}
+
+/// Replaces the passes registered since `before` was counted with passes recording their timings
+pub fn time_passes(store: &mut LintStore, before: RegisteredPasses) {
+    time_early_passes(&mut store.pre_expansion_passes, before.pre_expansion, "pre-expansion");
+    time_early_passes(&mut store.early_passes, before.early, "early");
+
+    let timed = store
+        .late_passes
+        .split_off(before.late)
+        .into_iter()
+        .map(|ctor| -> LatePassCtor {
+            Box::new(move || {
+                let pass = ctor();
+                let timings = PassTimings::new("late", pass.name());
+                Box::new(TimedLatePass { pass, timings })
+            })
+        });
+    store.late_passes.extend(timed);
+}
+
+/// Writes the timings of the passes that checked the crate to a new file in `dir`
+///
+/// # Errors
+///
+/// Returns an error if the directory or the file can't be created.
+///
+/// # Panics
+///
+/// Panics if a pass panicked while recording its timings.
+pub fn write(dir: &Path, crate_name: &str) -> io::Result<()> {
+    let mut out = String::new();
+    for timings in FINISHED.lock().unwrap().iter() {
+        let mut methods: Vec<_> = timings.methods.iter().collect();
+        methods.sort_by_key(|(method, _)| *method);
+        for (method, (calls, total)) in methods {
+            let _ = writeln!(
+                out,
+                "{}\t{}\t{method}\t{calls}\t{}",
+                timings.kind,
+                timings.pass,
+                total.as_nanos()
+            );
+        }
+    }
+    std::fs::create_dir_all(dir)?;
+    // The crate can be compiled several times, as a library, a binary or a test
+    std::fs::write(dir.join(format!("{crate_name}-{}.tsv", std::process::id())), out)
+}
+
+struct TimedEarlyPass {
+    pass: EarlyLintPassObject,
+    timings: PassTimings,
+}
+
+struct TimedLatePass {
+    pass: LateLintPassObject,
+    timings: PassTimings,
+}
+
+impl Drop for TimedEarlyPass {
+    fn drop(&mut self) {
+        self.timings.finish();
+    }
+}
+
+impl Drop for TimedLatePass {
+    fn drop(&mut self) {
+        self.timings.finish();
+    }
+}
+
+// The wrappers report the name of the wrapped pass, which the macros can't do
+#[allow(rustc::lint_pass_impl_without_macro)]
+impl LintPass for TimedEarlyPass {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+}
+
+#[allow(rustc::lint_pass_impl_without_macro)]
+impl LintPass for TimedLatePass {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+}
+
+macro_rules! timed_early_lint_pass_methods {
+    ([], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => (
+        impl EarlyLintPass for TimedEarlyPass {
+            $(fn $name(&mut self, cx: &EarlyContext<'_>, $($param: $arg),*) {
+                let start = Instant::now();
+                self.pass.$name(cx, $($param),*);
+                self.timings.record(stringify!($name), start);
+            })*
+        }
+    )
+}
+
+macro_rules! timed_late_lint_pass_methods {
+    ([], [$hir:tt], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => (
+        impl<$hir> LateLintPass<$hir> for TimedLatePass {
+            $(fn $name(&mut self, cx: &LateContext<$hir>, $($param: $arg),*) {
+                let start = Instant::now();
+                self.pass.$name(cx, $($param),*);
+                self.timings.record(stringify!($name), start);
+            })*
+        }
+    )
+}
+
+early_lint_methods!(timed_early_lint_pass_methods, []);
+late_lint_methods!(timed_late_lint_pass_methods, [], ['tcx]);
@@ -1,5 +1,7 @@
 pub mod author;
 pub mod conf;
+pub mod disallowed_path;
 pub mod dump_hir;
 #[cfg(feature = "internal")]
 pub mod internal_lints;
+pub mod lint_timings;
@@ -0,0 +1,137 @@
+//! Support for `cargo clippy --fix --add-allows`, which adds lint attributes for the warnings of
+//! a crate instead of fixing them, so that a new lint can be enabled without touching the code.
+//!
+//! While the mode is enabled, the suggestions of Clippy's warnings are removed, and the warnings
+//! are recorded. Once the crate was checked, [`suggest_attributes`] emits one warning for each
+//! item containing some of them, with a suggestion adding the attribute that `cargo fix` applies.
+//! The driver removes the suggestions of all other diagnostics, see [`is_attribute_warning`].
+
+use rustc_errors::{Applicability, Diagnostic, Level};
+use rustc_lint::Lint;
+use rustc_middle::ty::TyCtxt;
+use rustc_span::{BytePos, Span};
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::mem;
+use std::sync::OnceLock;
+
+static ATTRIBUTE: OnceLock<AllowAttribute> = OnceLock::new();
+
+thread_local! {
+    /// The lint names and spans of the recorded warnings. Spans are only meaningful on the thread
+    /// running the compiler.
+    static WARNINGS: RefCell<Vec<(String, Span)>> = RefCell::new(Vec::new());
+}
+
+/// The attribute added for the warnings.
+#[derive(Clone, Debug)]
+pub struct AllowAttribute {
+    /// The name of the attribute, `allow` or `expect`
+    pub name: String,
+    /// The `reason` given in the attribute, if any
+    pub reason: Option<String>,
+}
+
+/// Enables the mode for the current process. Only the first call has an effect.
+pub fn enable(attribute: AllowAttribute) {
+    ATTRIBUTE.set(attribute).ok();
+}
+
+/// Whether `message` is the message of a warning emitted by [`suggest_attributes`], whose
+/// suggestion is the only one `cargo fix` may apply while the mode is enabled.
+pub fn is_attribute_warning(message: &str) -> bool {
+    ATTRIBUTE
+        .get()
+        .map_or(false, |attribute| message == attribute_warning(attribute))
+}
+
+fn attribute_warning(attribute: &AllowAttribute) -> String {
+    format!("adding `#[{}]` for the Clippy warnings in this item", attribute.name)
+}
+
+/// Removes the suggestions of a warning for `lint` and records it, if the mode is enabled.
+pub(crate) fn record(diag: &mut Diagnostic, lint: &'static Lint) {
+    if ATTRIBUTE.get().is_none() || matches!(diag.level(), Level::Expect(_)) {
+        return;
+    }
+    diag.disable_suggestions();
+    if let Some(span) = diag.span.primary_span() {
+        WARNINGS.with(|warnings| {
+            warnings.borrow_mut().push((lint.name_lower(), span.source_callsite()));
+        });
+    }
+}
+
+/// Emits a warning with a machine applicable suggestion for every item containing recorded
+/// warnings, which adds an attribute allowing their lints to the innermost item containing them.
+/// Warnings outside of any item are left as they are.
+pub fn suggest_attributes(tcx: TyCtxt<'_>) {
+    let attribute = match ATTRIBUTE.get() {
+        Some(attribute) => attribute,
+        None => return,
+    };
+    let warnings = WARNINGS.with(|warnings| mem::take(&mut *warnings.borrow_mut()));
+    if warnings.is_empty() {
+        return;
+    }
+
+    // Only items can carry the attribute, not closures or the constants of array lengths
+    let hir = tcx.hir();
+    let crate_items = tcx.hir_crate_items(());
+    let items: Vec<Span> = crate_items
+        .items()
+        .map(|id| id.hir_id())
+        .chain(crate_items.impl_items().map(|id| id.hir_id()))
+        .chain(crate_items.trait_items().map(|id| id.hir_id()))
+        .chain(crate_items.foreign_items().map(|id| id.hir_id()))
+        .map(|hir_id| hir.span_with_body(hir_id))
+        .filter(|span| !span.from_expansion())
+        .collect();
+
+    let mut lints_by_item: Vec<(Span, BTreeSet<String>)> = Vec::new();
+    for (lint, span) in warnings {
+        let item = items
+            .iter()
+            .filter(|item| item.contains(span))
+            .min_by_key(|item| item.hi() - item.lo());
+        if let Some(&item) = item {
+            match lints_by_item.iter_mut().find(|(span, _)| *span == item) {
+                Some((_, lints)) => {
+                    lints.insert(lint);
+                },
+                None => lints_by_item.push((item, BTreeSet::from([lint]))),
+            }
+        }
+    }
+
+    let source_map = tcx.sess.source_map();
+    for (item, lints) in lints_by_item {
+        // The attribute goes on its own line, with the indentation of the item
+        let indent = source_map
+            .span_to_prev_source(item)
+            .ok()
+            .and_then(|prev| prev.rsplit('\n').next().map(ToString::to_string))
+            .filter(|prev| prev.chars().all(char::is_whitespace))
+            .unwrap_or_default();
+        let reason = attribute
+            .reason
+            .as_ref()
+            .map(|reason| format!(", reason = {reason:?}"))
+            .unwrap_or_default();
+        let lints: Vec<_> = lints.into_iter().collect();
+        let attr = format!("#[{}({}{reason})]\n{indent}", attribute.name, lints.join(", "));
+        // The start of the first file is a dummy span, which can't carry a suggestion, so the
+        // first character of the item is replaced instead
+        let (span, sugg) = if item.shrink_to_lo().is_dummy() {
+            let span = item.with_hi(item.lo() + BytePos(1));
+            let first = source_map.span_to_snippet(span).unwrap_or_default();
+            (span, format!("{attr}{first}"))
+        } else {
+            (item.shrink_to_lo(), attr)
+        };
+        tcx.sess
+            .struct_span_warn(span, &attribute_warning(attribute))
+            .span_suggestion_verbose(span, "add the attribute", sugg, Applicability::MachineApplicable)
+            .emit();
+    }
+}
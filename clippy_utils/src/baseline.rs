@@ -0,0 +1,58 @@
+//! Support for `cargo clippy --baseline`, which hides the warnings recorded in a baseline file.
+//!
+//! The driver reads the baseline and passes a filter to [`enable`]. Warnings for which the filter
+//! returns `true` are cancelled instead of being emitted, so they neither show up nor make the
+//! compilation fail when their lint is denied.
+
+use rustc_errors::{Diagnostic, Level};
+use rustc_lint::Lint;
+use rustc_session::Session;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+/// Called with the lint name (without the `clippy::` prefix), the file and the source code of
+/// the primary span of a warning. Returns `true` if the warning is hidden.
+type Filter = dyn FnMut(&str, &str, &str) -> bool + Send;
+
+static FILTER: OnceLock<Mutex<Box<Filter>>> = OnceLock::new();
+static HIDDEN: AtomicUsize = AtomicUsize::new(0);
+
+/// Enables the baseline for the current process. Only the first call has an effect.
+pub fn enable(filter: impl FnMut(&str, &str, &str) -> bool + Send + 'static) {
+    FILTER.set(Mutex::new(Box::new(filter))).ok();
+}
+
+/// The number of warnings hidden so far.
+pub fn hidden() -> usize {
+    HIDDEN.load(Ordering::Relaxed)
+}
+
+/// Returns `true` if the baseline contains the warning for `lint`, which must then not be
+/// emitted.
+pub(crate) fn hides(sess: &Session, diag: &Diagnostic, lint: &'static Lint) -> bool {
+    let Some(filter) = FILTER.get() else {
+        return false;
+    };
+    // Hiding an expected lint would make its expectation unfulfilled
+    if matches!(diag.level(), Level::Expect(_)) {
+        return false;
+    }
+
+    let name = lint.name_lower();
+    let name = name.strip_prefix("clippy::").unwrap_or(&name);
+    // The same file name and code as in the JSON messages `cargo clippy --update-baseline` reads
+    let (file, snippet) = diag.span.primary_span().map_or_else(Default::default, |span| {
+        let sm = sess.source_map();
+        let file = sm.lookup_source_file(span.lo());
+        (
+            sm.filename_for_diagnostics(&file.name).to_string(),
+            sm.span_to_snippet(span).unwrap_or_default(),
+        )
+    });
+
+    let hidden = (filter.lock().unwrap())(name, &file, &snippet);
+    if hidden {
+        HIDDEN.fetch_add(1, Ordering::Relaxed);
+    }
+    hidden
+}
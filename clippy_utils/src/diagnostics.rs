@@ -8,12 +8,28 @@
 //! Thank you!
 //! ~The `INTERNAL_METADATA_COLLECTOR` lint
 
-use rustc_errors::{Applicability, Diagnostic, MultiSpan};
+use crate::{add_allows, baseline, overrides};
+use rustc_errors::{Applicability, Diagnostic, DiagnosticBuilder, LintDiagnosticBuilder, MultiSpan};
 use rustc_hir::HirId;
 use rustc_lint::{LateContext, Lint, LintContext};
+use rustc_session::Session;
 use rustc_span::source_map::Span;
 use std::env;
 
+/// Like `LintContext::struct_span_lint`, but warns if the lint is emitted by an early lint pass
+/// in a file where an `[[overrides]]` table of `clippy.toml` sets its level, as the early lint
+/// passes don't use the levels of the overrides.
+fn struct_span_lint<T: LintContext>(
+    cx: &T,
+    lint: &'static Lint,
+    sp: impl Into<MultiSpan>,
+    decorate: impl for<'a> FnOnce(LintDiagnosticBuilder<'a, ()>),
+) {
+    let sp = sp.into();
+    overrides::warn_early_lint(cx.sess(), lint, &sp);
+    cx.struct_span_lint(lint, sp, decorate);
+}
+
 fn docs_link(diag: &mut Diagnostic, lint: &'static Lint) {
     if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
         if let Some(lint) = lint.name_lower().strip_prefix("clippy::") {
@@ -28,6 +44,16 @@ fn docs_link(diag: &mut Diagnostic, lint: &'static Lint) {
     }
 }
 
+/// Emits a Clippy warning, unless it is part of the baseline given to `cargo clippy --baseline`.
+fn emit(sess: &Session, mut diag: DiagnosticBuilder<'_, ()>, lint: &'static Lint) {
+    if baseline::hides(sess, &diag, lint) {
+        diag.cancel();
+    } else {
+        add_allows::record(&mut diag, lint);
+        diag.emit();
+    }
+}
+
 /// Emit a basic lint message with a `msg` and a `span`.
 ///
 /// This is the most primitive of our lint emission methods and can
@@ -46,10 +72,10 @@ fn docs_link(diag: &mut Diagnostic, lint: &'static Lint) {
 ///    |     ^^^^^^^^^^^^^^^^^^^^^^^
 /// ```
 pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<MultiSpan>, msg: &str) {
-    cx.struct_span_lint(lint, sp, |diag| {
+    struct_span_lint(cx, lint, sp, |diag| {
         let mut diag = diag.build(msg);
         docs_link(&mut diag, lint);
-        diag.emit();
+        emit(cx.sess(), diag, lint);
     });
 }
 
@@ -81,7 +107,7 @@ pub fn span_lint_and_help<'a, T: LintContext>(
     help_span: Option<Span>,
     help: &str,
 ) {
-    cx.struct_span_lint(lint, span, |diag| {
+    struct_span_lint(cx, lint, span, |diag| {
         let mut diag = diag.build(msg);
         if let Some(help_span) = help_span {
             diag.span_help(help_span, help);
@@ -89,7 +115,7 @@ pub fn span_lint_and_help<'a, T: LintContext>(
             diag.help(help);
         }
         docs_link(&mut diag, lint);
-        diag.emit();
+        emit(cx.sess(), diag, lint);
     });
 }
 
@@ -124,7 +150,7 @@ pub fn span_lint_and_note<'a, T: LintContext>(
     note_span: Option<Span>,
     note: &str,
 ) {
-    cx.struct_span_lint(lint, span, |diag| {
+    struct_span_lint(cx, lint, span, |diag| {
         let mut diag = diag.build(msg);
         if let Some(note_span) = note_span {
             diag.span_note(note_span, note);
@@ -132,7 +158,7 @@ pub fn span_lint_and_note<'a, T: LintContext>(
             diag.note(note);
         }
         docs_link(&mut diag, lint);
-        diag.emit();
+        emit(cx.sess(), diag, lint);
     });
 }
 
@@ -146,11 +172,11 @@ where
     S: Into<MultiSpan>,
     F: FnOnce(&mut Diagnostic),
 {
-    cx.struct_span_lint(lint, sp, |diag| {
+    struct_span_lint(cx, lint, sp, |diag| {
         let mut diag = diag.build(msg);
         f(&mut diag);
         docs_link(&mut diag, lint);
-        diag.emit();
+        emit(cx.sess(), diag, lint);
     });
 }
 
@@ -158,7 +184,7 @@ pub fn span_lint_hir(cx: &LateContext<'_>, lint: &'static Lint, hir_id: HirId, s
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, |diag| {
         let mut diag = diag.build(msg);
         docs_link(&mut diag, lint);
-        diag.emit();
+        emit(cx.tcx.sess, diag, lint);
     });
 }
 
@@ -174,7 +200,7 @@ pub fn span_lint_hir_and_then(
         let mut diag = diag.build(msg);
         f(&mut diag);
         docs_link(&mut diag, lint);
-        diag.emit();
+        emit(cx.tcx.sess, diag, lint);
     });
 }
 
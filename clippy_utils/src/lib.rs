@@ -38,6 +38,8 @@ extern crate rustc_typeck;
 #[macro_use]
 pub mod sym_helper;
 
+pub mod add_allows;
+pub mod baseline;
 pub mod ast_utils;
 pub mod attrs;
 mod check_proc_macro;
@@ -50,6 +52,7 @@ mod hir_utils;
 pub mod macros;
 pub mod msrvs;
 pub mod numeric_literal;
+pub mod overrides;
 pub mod paths;
 pub mod ptr;
 pub mod qualify_min_const_fn;
@@ -0,0 +1,398 @@
+//! Support for the `[[overrides]]` tables of `clippy.toml`, which change configuration values and
+//! lint levels for the files matching a set of path globs.
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_errors::MultiSpan;
+use rustc_hir::{HirId, CRATE_HIR_ID};
+use rustc_lint::{Level, Lint, LintId};
+use rustc_middle::lint::{LevelAndSource, LintLevelMap, LintLevelSource, LintSet, LintStackIndex, COMMAND_LINE};
+use rustc_middle::ty::query::Providers;
+use rustc_middle::ty::{tls, TyCtxt};
+use rustc_session::Session;
+use rustc_span::{BytePos, FileName, RealFileName, Span, Symbol};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::OnceLock;
+
+/// The lint levels set by the `[[overrides]]` tables, in the order they were written.
+static LINT_LEVELS: OnceLock<Vec<(PathGlobs, FxHashMap<LintId, OverrideLevel>)>> = OnceLock::new();
+
+/// A lint level set by an `[[overrides]]` table, along with the lint or lint group it was set for.
+#[derive(Clone, Copy, Debug)]
+pub struct OverrideLevel {
+    pub level: Level,
+    pub name: Symbol,
+}
+
+impl OverrideLevel {
+    /// The level, shown in diagnostics like the levels of the `[lints]` table, which are passed
+    /// as command line flags
+    fn level_and_source(self) -> LevelAndSource {
+        (self.level, LintLevelSource::CommandLine(self.name, self.level))
+    }
+}
+
+/// A set of path globs, relative to the directory containing `clippy.toml`.
+#[derive(Clone, Debug)]
+pub struct PathGlobs {
+    root: PathBuf,
+    globs: Vec<String>,
+}
+
+impl PathGlobs {
+    pub fn new(root: impl Into<PathBuf>, globs: Vec<String>) -> Self {
+        Self {
+            root: root.into(),
+            globs,
+        }
+    }
+
+    /// Checks if the file containing `span` matches any of the globs. Spans from macro expansions
+    /// belong to the file of the macro call.
+    pub fn matches(&self, sess: &Session, span: Span) -> bool {
+        local_path(sess, span).map_or(false, |path| self.matches_path(&path))
+    }
+
+    fn matches_path(&self, path: &Path) -> bool {
+        path.strip_prefix(&self.root).map_or(false, |relative| {
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            self.globs.iter().any(|glob| glob_match(glob, &relative))
+        })
+    }
+}
+
+/// A configuration value, together with the values `[[overrides]]` tables set for some paths.
+#[derive(Clone, Debug)]
+pub struct PerPath<T> {
+    default: T,
+    overrides: Vec<(PathGlobs, T)>,
+}
+
+impl<T: Copy> PerPath<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            default,
+            overrides: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_override(mut self, globs: PathGlobs, value: T) -> Self {
+        self.overrides.push((globs, value));
+        self
+    }
+
+    /// Returns the value for the file containing `span`. If several overrides match, the last one
+    /// wins.
+    pub fn get(&self, sess: &Session, span: Span) -> T {
+        self.overrides
+            .iter()
+            .rev()
+            .find(|(globs, _)| globs.matches(sess, span))
+            .map_or(self.default, |&(_, value)| value)
+    }
+}
+
+/// Sets the lint levels of the `[[overrides]]` tables, with lint groups expanded to their lints.
+/// Only the first call has an effect.
+#[expect(clippy::implicit_hasher)]
+pub fn set_lint_levels(levels: Vec<(PathGlobs, FxHashMap<LintId, OverrideLevel>)>) {
+    LINT_LEVELS.set(levels).ok();
+}
+
+/// The `lint_levels` query, which adds the levels of the `[[overrides]]` tables to the ones rustc
+/// computes from the command line and the attributes.
+///
+/// The levels of a file are inserted as if it started with attributes setting them, so that the
+/// attributes in the file take precedence. Levels set outside of the file with attributes, with
+/// `forbid` or with `--force-warn` are kept as they are.
+///
+/// This query is where rustc looks up the levels of the late lint passes, `#[expect]` and
+/// functions like `is_lint_allowed`, so replacing it is the only way for the overrides to work the
+/// same way as attributes. The early lint passes compute their levels while walking the syntax
+/// tree instead, and don't see the overrides, see [`warn_early_lint`].
+pub fn lint_levels(tcx: TyCtxt<'_>, (): ()) -> LintLevelMap {
+    let mut providers = Providers::default();
+    rustc_lint::provide(&mut providers);
+    let mut map = (providers.lint_levels)(tcx, ());
+    if let Some(overrides) = LINT_LEVELS.get().filter(|overrides| !overrides.is_empty()) {
+        LevelsWithOverrides::new(tcx, &mut map, overrides).add_overrides();
+    }
+    map
+}
+
+/// Warns if an `[[overrides]]` table sets the level of `lint` for the file of `span` and the lint
+/// is emitted by an early lint pass, which doesn't use the levels of the overrides. The warning is
+/// only shown once for each lint.
+pub(crate) fn warn_early_lint(sess: &Session, lint: &'static Lint, span: &MultiSpan) {
+    let Some(overrides) = LINT_LEVELS.get() else {
+        return;
+    };
+    // The early lint passes run before the type context is created
+    if tls::with_opt(|tcx| tcx.is_some()) {
+        return;
+    }
+    let Some(path) = span.primary_span().and_then(|span| local_path(sess, span)) else {
+        return;
+    };
+    let id = LintId::of(lint);
+    if overrides
+        .iter()
+        .any(|(globs, levels)| levels.contains_key(&id) && globs.matches_path(&path))
+    {
+        sess.warn(&format!(
+            "the `[[overrides]]` of Clippy's configuration file can't set the level of `{}`, as it is checked \
+            before type checking",
+            lint.name_lower()
+        ));
+    }
+}
+
+/// Whether an override can replace a level set outside of the file it applies to
+fn can_override((level, source): LevelAndSource) -> bool {
+    match source {
+        LintLevelSource::Node(..) | LintLevelSource::CommandLine(_, Level::Forbid | Level::ForceWarn(_)) => false,
+        LintLevelSource::CommandLine(..) | LintLevelSource::Default => {
+            !matches!(level, Level::Forbid | Level::ForceWarn(_))
+        },
+    }
+}
+
+/// Adds the levels of the `[[overrides]]` tables to a `LintLevelMap`.
+///
+/// Each lint set of the map belongs to the node whose attributes it holds. For each node in a file
+/// with overrides, the sets of its enclosing nodes in the same file are copied, with a new set
+/// holding the levels of the overrides as their outermost parent. The sets of the map are left as
+/// they are, so the levels of one file don't leak into other files.
+struct LevelsWithOverrides<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    map: &'a mut LintLevelMap,
+    overrides: &'a [(PathGlobs, FxHashMap<LintId, OverrideLevel>)],
+    /// The node each set belongs to
+    owners: FxHashMap<LintStackIndex, HirId>,
+    /// The levels of the overrides of each file, keyed by the position of the file in the source
+    /// map
+    file_levels: FxHashMap<BytePos, Option<Rc<FxHashMap<LintId, OverrideLevel>>>>,
+    /// The copies of the sets for each file
+    copies: FxHashMap<(LintStackIndex, BytePos), LintStackIndex>,
+}
+
+impl<'a, 'tcx> LevelsWithOverrides<'a, 'tcx> {
+    fn new(
+        tcx: TyCtxt<'tcx>,
+        map: &'a mut LintLevelMap,
+        overrides: &'a [(PathGlobs, FxHashMap<LintId, OverrideLevel>)],
+    ) -> Self {
+        #[allow(rustc::potential_query_instability)] // collected into a map
+        let owners = map
+            .id_to_set
+            .iter()
+            .filter(|&(_, &set)| set != COMMAND_LINE)
+            .map(|(&id, &set)| (set, id))
+            .collect();
+        Self {
+            tcx,
+            map,
+            overrides,
+            owners,
+            file_levels: FxHashMap::default(),
+            copies: FxHashMap::default(),
+        }
+    }
+
+    fn add_overrides(mut self) {
+        // Besides the nodes with attributes, the items have to get the levels of their file, as a
+        // module in another file doesn't start with a node with attributes
+        let items = self.tcx.hir_crate_items(());
+        #[allow(rustc::potential_query_instability)] // sorted below
+        let mut nodes: Vec<HirId> = self.map.id_to_set.keys().copied().collect();
+        nodes.push(CRATE_HIR_ID);
+        nodes.extend(items.items().map(|id| id.hir_id()));
+        nodes.extend(items.trait_items().map(|id| id.hir_id()));
+        nodes.extend(items.impl_items().map(|id| id.hir_id()));
+        nodes.extend(items.foreign_items().map(|id| id.hir_id()));
+        // The sets are added in a stable order, as the map is hashed for incremental compilation
+        nodes.sort_unstable();
+        nodes.dedup();
+
+        let sets: Vec<_> = nodes
+            .into_iter()
+            .filter_map(|id| {
+                let file = self.file(id);
+                self.levels(file)?;
+                let set = self.enclosing_set(id);
+                Some((id, self.copy_set(set, file)))
+            })
+            .collect();
+        self.map.id_to_set.extend(sets);
+    }
+
+    /// The file containing the node, identified by its position in the source map
+    fn file(&self, id: HirId) -> BytePos {
+        let span = self.tcx.hir().span(id).source_callsite();
+        self.tcx.sess.source_map().lookup_source_file(span.lo()).start_pos
+    }
+
+    fn levels(&mut self, file: BytePos) -> Option<Rc<FxHashMap<LintId, OverrideLevel>>> {
+        let (sess, overrides) = (self.tcx.sess, self.overrides);
+        self.file_levels
+            .entry(file)
+            .or_insert_with(|| {
+                let path = local_path(sess, Span::with_root_ctxt(file, file))?;
+                let mut levels = FxHashMap::default();
+                for (globs, override_levels) in overrides {
+                    if globs.matches_path(&path) {
+                        #[allow(rustc::potential_query_instability)] // collected into a map
+                        levels.extend(override_levels.iter().map(|(&id, &level)| (id, level)));
+                    }
+                }
+                (!levels.is_empty()).then(|| Rc::new(levels))
+            })
+            .clone()
+    }
+
+    /// The set of the node, or of the closest enclosing node with one
+    fn enclosing_set(&self, mut id: HirId) -> LintStackIndex {
+        loop {
+            if let Some(&set) = self.map.id_to_set.get(&id) {
+                return set;
+            }
+            id = self.tcx.hir().get_parent_node(id);
+        }
+    }
+
+    /// Copies `set` and its parents in `file`, with the levels of the overrides of the file as
+    /// the parent of the outermost one.
+    fn copy_set(&mut self, set: LintStackIndex, file: BytePos) -> LintStackIndex {
+        if let Some(&copy) = self.copies.get(&(set, file)) {
+            return copy;
+        }
+        let copy = match self.owners.get(&set) {
+            Some(&owner) if self.file(owner) == file => {
+                let parent = self.copy_set(self.map.sets.list[set].parent, file);
+                let specs = self.map.sets.list[set].specs.clone();
+                self.map.sets.list.push(LintSet { specs, parent })
+            },
+            _ => {
+                let levels = self.levels(file).unwrap_or_default();
+                let edition = self.tcx.sess.edition();
+                #[allow(rustc::potential_query_instability)] // collected into a map
+                let specs: FxHashMap<_, _> = levels
+                    .iter()
+                    .filter(|&(&id, _)| {
+                        let (level, source) = self.map.sets.get_lint_id_level(id, set, None);
+                        can_override((level.unwrap_or_else(|| id.lint.default_level(edition)), source))
+                    })
+                    .map(|(&id, &level)| (id, level.level_and_source()))
+                    .collect();
+                if specs.is_empty() {
+                    set
+                } else {
+                    self.map.sets.list.push(LintSet { specs, parent: set })
+                }
+            },
+        };
+        self.copies.insert((set, file), copy);
+        copy
+    }
+}
+
+fn local_path(sess: &Session, span: Span) -> Option<PathBuf> {
+    let path = match sess.source_map().span_to_filename(span.source_callsite()) {
+        FileName::Real(
+            RealFileName::LocalPath(path)
+            | RealFileName::Remapped {
+                local_path: Some(path), ..
+            },
+        ) => path,
+        _ => return None,
+    };
+    let path = if path.is_absolute() {
+        path
+    } else {
+        sess.opts.working_dir.local_path_if_available().join(path)
+    };
+    Some(path.canonicalize().unwrap_or(path))
+}
+
+/// Matches a `/` separated path against a glob. `*` and `?` match any characters within a path
+/// component, and `**` matches any number of components. A glob matching a directory matches all
+/// files in it.
+///
+/// ```
+/// # use clippy_utils::overrides::glob_match;
+/// assert!(glob_match("tests/**", "tests/ui/foo.rs"));
+/// assert!(glob_match("src/generated", "src/generated/bindings.rs"));
+/// assert!(glob_match("src/*_test.rs", "src/parser_test.rs"));
+/// assert!(!glob_match("src/*.rs", "src/generated/bindings.rs"));
+/// ```
+#[must_use]
+pub fn glob_match(glob: &str, path: &str) -> bool {
+    glob_match_segments(&components(glob), &components(path))
+}
+
+/// Matches a path split into segments against a glob split the same way, with the rules of
+/// [`glob_match`]. This is also used for item paths like `std::process::*`.
+#[must_use]
+pub fn glob_match_segments(glob: &[&str], path: &[&str]) -> bool {
+    (1..=path.len()).any(|len| match_components(glob, &path[..len]))
+}
+
+fn components(path: &str) -> Vec<&str> {
+    Path::new(path)
+        .iter()
+        .filter_map(OsStr::to_str)
+        .filter(|&component| component != "." && component != "/")
+        .collect()
+}
+
+fn match_components(glob: &[&str], path: &[&str]) -> bool {
+    match glob.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_components(rest, &path[skip..])),
+        Some((first, rest)) => path.split_first().map_or(false, |(name, path)| {
+            match_component(first, name) && match_components(rest, path)
+        }),
+    }
+}
+
+fn match_component(glob: &str, name: &str) -> bool {
+    let mut glob = glob.chars();
+    let mut name_chars = name.chars();
+    match glob.next() {
+        None => name.is_empty(),
+        Some('*') => name
+            .char_indices()
+            .map(|(skip, _)| skip)
+            .chain([name.len()])
+            .any(|skip| match_component(glob.as_str(), &name[skip..])),
+        Some('?') => name_chars.next().is_some() && match_component(glob.as_str(), name_chars.as_str()),
+        Some(c) => name_chars.next() == Some(c) && match_component(glob.as_str(), name_chars.as_str()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::glob_match;
+
+    #[test]
+    fn globs() {
+        assert!(glob_match("tests/**", "tests/foo.rs"));
+        assert!(glob_match("tests/**", "tests/ui/foo.rs"));
+        assert!(glob_match("tests/**/*.rs", "tests/foo.rs"));
+        assert!(glob_match("**/generated/*.rs", "src/generated/foo.rs"));
+        assert!(glob_match("./src/lib.rs", "src/lib.rs"));
+        assert!(glob_match("src/?.rs", "src/a.rs"));
+        assert!(glob_match("src/a?b.rs", "src/aéb.rs"));
+        assert!(glob_match("src/*é.rs", "src/café.rs"));
+        assert!(glob_match("src/generated", "src/generated/foo.rs"));
+        assert!(glob_match("src", "src/generated/foo.rs"));
+
+        assert!(!glob_match("tests/**", "src/tests/foo.rs"));
+        assert!(!glob_match("src/*.rs", "src/generated/foo.rs"));
+        assert!(!glob_match("src/?.rs", "src/ab.rs"));
+        assert!(!glob_match("src/a?b.rs", "src/aéeb.rs"));
+        assert!(!glob_match("src/gen", "src/generated/foo.rs"));
+    }
+}
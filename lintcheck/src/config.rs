@@ -25,7 +25,18 @@ fn get_clap_config() -> ArgMatches {
                 .help("Number of threads to use, 0 automatic choice"),
             Arg::new("fix")
                 .long("fix")
-                .help("Runs cargo clippy --fix and checks if all suggestions apply"),
+                .help("Applies the suggestions of each lint separately and checks that the crates still compile"),
+            Arg::new("recursive")
+                .long("recursive")
+                .conflicts_with("fix")
+                .help("Also lint the dependencies of the crates, including the ones from vendor directories"),
+            Arg::new("timings")
+                .long("timings")
+                .help("Record the time spent by each lint pass and report the passes that got slower"),
+            Arg::new("save-profile")
+                .long("save-profile")
+                .requires("timings")
+                .help("Store the timings of `--timings` as the profile the next runs are compared with"),
             Arg::new("filter")
                 .long("filter")
                 .action(ArgAction::Append)
@@ -35,6 +46,20 @@ fn get_clap_config() -> ArgMatches {
                 .long("markdown")
                 .help("Change the reports table to use markdown links"),
         ])
+        .subcommand(
+            Command::new("diff")
+                .about("Print the warnings added, removed or changed between two structured logs")
+                .args([
+                    Arg::new("old")
+                        .required(true)
+                        .value_parser(clap::value_parser!(PathBuf))
+                        .help("The `.json` log of the previous run"),
+                    Arg::new("new")
+                        .required(true)
+                        .value_parser(clap::value_parser!(PathBuf))
+                        .help("The `.json` log of the new run"),
+                ]),
+        )
         .get_matches()
 }
 
@@ -46,14 +71,28 @@ pub(crate) struct LintcheckConfig {
     pub sources_toml_path: PathBuf,
     /// we save the clippy lint results here
     pub lintcheck_results_path: PathBuf,
+    /// we save the report of `--fix` here
+    pub lintcheck_fix_results_path: PathBuf,
+    /// we save the report of `--timings` here
+    pub lintcheck_timings_report_path: PathBuf,
+    /// we save the timings of `--timings` here, and compare the next run with them
+    pub lintcheck_timings_path: PathBuf,
     /// Check only a specified package
     pub only: Option<String>,
-    /// whether to just run --fix and not collect all the warnings
+    /// whether to check the suggestions of each lint with --fix instead of collecting the warnings
     pub fix: bool,
+    /// whether to lint the dependencies of the crates as well
+    pub recursive: bool,
+    /// whether to record and report the time spent by each lint pass
+    pub timings: bool,
+    /// whether to store the timings as the profile the next `--timings` runs are compared with
+    pub save_profile: bool,
     /// A list of lints that this lintcheck run should focus on
     pub lint_filter: Vec<String>,
     /// Indicate if the output should support markdown syntax
     pub markdown: bool,
+    /// The old and new logs to compare with `lintcheck diff`, instead of running Clippy
+    pub diff: Option<(PathBuf, PathBuf)>,
 }
 
 impl LintcheckConfig {
@@ -82,6 +121,9 @@ impl LintcheckConfig {
             filename.display(),
             if markdown { "md" } else { "txt" }
         ));
+        let lintcheck_fix_results_path = PathBuf::from(format!("lintcheck-logs/{}_fix_logs.md", filename.display()));
+        let lintcheck_timings_report_path = PathBuf::from(format!("lintcheck-logs/{}_timings.md", filename.display()));
+        let lintcheck_timings_path = PathBuf::from(format!("lintcheck-logs/{}_timings.json", filename.display()));
 
         // look at the --threads arg, if 0 is passed, ask rayon rayon how many threads it would spawn and
         // use half of that for the physical core count
@@ -111,14 +153,28 @@ impl LintcheckConfig {
             })
             .unwrap_or_default();
 
+        let diff = clap_config.subcommand_matches("diff").map(|matches| {
+            (
+                matches.get_one::<PathBuf>("old").unwrap().clone(),
+                matches.get_one::<PathBuf>("new").unwrap().clone(),
+            )
+        });
+
         LintcheckConfig {
             max_jobs,
             sources_toml_path,
             lintcheck_results_path,
+            lintcheck_fix_results_path,
+            lintcheck_timings_report_path,
+            lintcheck_timings_path,
             only: clap_config.get_one::<String>("only").map(String::from),
             fix: clap_config.contains_id("fix"),
+            recursive: clap_config.contains_id("recursive"),
+            timings: clap_config.contains_id("timings"),
+            save_profile: clap_config.contains_id("save-profile"),
             lint_filter,
             markdown,
+            diff,
         }
     }
 }
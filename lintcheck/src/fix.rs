@@ -0,0 +1,204 @@
+//! `lintcheck --fix`: applies the suggestions of each lint separately, on a copy of each crate,
+//! and checks that the fixed crate still compiles and that the fixes didn't change the warnings
+//! of other lints.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use cargo_metadata::diagnostic::DiagnosticLevel;
+use cargo_metadata::Message;
+
+use crate::{clippy_project_root, copy_dir, ClippyWarning, Crate};
+
+const LINTCHECK_FIXES: &str = "target/lintcheck/fixes";
+
+/// The outcome of applying the suggestions of one lint to one crate
+#[derive(Debug)]
+pub(crate) struct FixResult {
+    crate_name: String,
+    crate_version: String,
+    lint: String,
+    /// The errors of the fixed crate
+    errors: Vec<String>,
+    /// The lints whose number of warnings changed, with the counts before and after the fixes
+    changed_warnings: Vec<(String, usize, usize)>,
+    /// The changes made by the fixes
+    diff: String,
+}
+
+impl FixResult {
+    fn summary(&self) -> String {
+        if !self.errors.is_empty() {
+            format!("fixes don't compile ({} errors)", self.errors.len())
+        } else if !self.changed_warnings.is_empty() {
+            let changes: Vec<_> = self
+                .changed_warnings
+                .iter()
+                .map(|(lint, before, after)| format!("`{lint}` {before} => {after}"))
+                .collect();
+            format!("warnings changed: {}", changes.join(", "))
+        } else {
+            String::from("ok")
+        }
+    }
+}
+
+fn count_by_lint(warnings: &[ClippyWarning]) -> BTreeMap<&str, usize> {
+    let mut counts = BTreeMap::new();
+    for warning in warnings {
+        *counts.entry(warning.lint_type.as_str()).or_insert(0) += 1;
+    }
+    counts
+}
+
+impl Crate {
+    /// Applies the suggestions of every lint in `warnings` to a separate copy of the crate, then
+    /// checks the copy again
+    pub(crate) fn check_fixes(
+        &self,
+        cargo_clippy_path: &Path,
+        warnings: &[ClippyWarning],
+        lint_filter: &[String],
+    ) -> Vec<FixResult> {
+        let lints: BTreeSet<&str> = warnings
+            .iter()
+            .filter(|warning| warning.fixable && !warning.is_ice)
+            .map(|warning| warning.lint_type.as_str())
+            .collect();
+        let counts_before = count_by_lint(warnings);
+        let target_dir = clippy_project_root()
+            .join("target/lintcheck/shared_target_dir")
+            .join(format!("_fix{}", rayon::current_thread_index().unwrap_or(0)));
+
+        let mut results = Vec::new();
+        for lint in lints {
+            println!("Applying the suggestions of {} to {} {}", lint, self.name, self.version);
+            let scratch_path = PathBuf::from(LINTCHECK_FIXES)
+                .join(format!("{}-{}", self.name, self.version))
+                .join(lint.trim_start_matches("clippy::"));
+            if scratch_path.exists() {
+                std::fs::remove_dir_all(&scratch_path).unwrap();
+            }
+            std::fs::create_dir_all(scratch_path.parent().unwrap()).unwrap();
+            copy_dir(&self.path, &scratch_path);
+
+            // keep the changes even if they don't compile, to show them in the report. Lints are
+            // allowed with `-Awarnings` rather than `--cap-lints`, which `cargo fix` overrides.
+            let force_warn = format!("--force-warn={lint}");
+            self.cargo_clippy(
+                cargo_clippy_path,
+                &scratch_path,
+                &target_dir,
+                &["--fix", "--allow-no-vcs", "--allow-dirty", "--broken-code"],
+                &["-Awarnings", &force_warn],
+                &[],
+            );
+
+            let diff = diff_dirs(&self.path, &scratch_path);
+            if diff.is_empty() {
+                continue;
+            }
+
+            // check the fixed crate with the same lints as the original one
+            let output = self.cargo_clippy(
+                cargo_clippy_path,
+                &scratch_path,
+                &target_dir,
+                &["--message-format=json"],
+                &self.lint_args(lint_filter),
+                &[],
+            );
+            let mut errors = Vec::new();
+            let mut warnings_after = Vec::new();
+            for message in Message::parse_stream(output.stdout.as_slice()) {
+                let message = message.unwrap();
+                if let Message::CompilerMessage(message) = &message {
+                    let diag = &message.message;
+                    if diag.level == DiagnosticLevel::Error {
+                        errors.push(diag.rendered.clone().unwrap_or_else(|| diag.message.clone()));
+                    }
+                }
+                warnings_after.extend(ClippyWarning::new(message, self));
+            }
+
+            let counts_after = count_by_lint(&warnings_after);
+            let changed_warnings = counts_before
+                .keys()
+                .chain(counts_after.keys())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .filter_map(|&changed| {
+                    let before = counts_before.get(changed).copied().unwrap_or(0);
+                    let after = counts_after.get(changed).copied().unwrap_or(0);
+                    // the fixed lint is expected to have fewer warnings
+                    let expected = changed == lint && after <= before;
+                    (before != after && !expected).then(|| (changed.to_string(), before, after))
+                })
+                .collect();
+
+            results.push(FixResult {
+                crate_name: self.name.clone(),
+                crate_version: self.version.clone(),
+                lint: lint.to_string(),
+                errors,
+                changed_warnings,
+                diff,
+            });
+        }
+        results
+    }
+}
+
+/// Returns the differences between the files of the two directories, as a unified diff
+fn diff_dirs(old: &Path, new: &Path) -> String {
+    let output = Command::new("git")
+        .args(["diff", "--no-index", "--no-color", "--"])
+        .arg(old)
+        .arg(new)
+        .output()
+        .expect("failed to run `git diff`");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+/// Prints a table of the fixed lints and writes it into the report at `path`, along with the
+/// errors and changes of the fixes that broke something
+pub(crate) fn write_report(results: &[FixResult], path: &Path) {
+    let mut table = String::from("| lint | crate | result |\n| --- | --- | --- |\n");
+    for result in results {
+        let _ = writeln!(
+            table,
+            "| `{}` | {} {} | {} |",
+            result.lint,
+            result.crate_name,
+            result.crate_version,
+            result.summary()
+        );
+    }
+    println!("\n{table}");
+
+    let mut text = String::from("### Fixes\n\n");
+    text.push_str(&table);
+    for result in results {
+        if result.errors.is_empty() && result.changed_warnings.is_empty() {
+            continue;
+        }
+        let _ = writeln!(
+            text,
+            "\n### `{}` in {} {}\n\n{}\n",
+            result.lint,
+            result.crate_name,
+            result.crate_version,
+            result.summary()
+        );
+        for error in &result.errors {
+            let _ = writeln!(text, "```\n{}```\n", error);
+        }
+        let _ = writeln!(text, "```diff\n{}```", result.diff);
+    }
+
+    println!("Writing the report of the fixes to {}", path.display());
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, text).unwrap();
+}
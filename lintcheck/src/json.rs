@@ -0,0 +1,212 @@
+//! The structured log of a lintcheck run, and the comparison of two of them with
+//! `lintcheck diff old.json new.json`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::ClippyWarning;
+
+/// Serializes all the warnings of a run, sorted so that the logs of two runs can be compared with
+/// a textual diff as well.
+pub(crate) fn output(clippy_warnings: &[ClippyWarning]) -> String {
+    let mut warnings: Vec<&ClippyWarning> = clippy_warnings.iter().collect();
+    warnings.sort_by(|a, b| a.key().cmp(&b.key()).then_with(|| a.message.cmp(&b.message)));
+    serde_json::to_string_pretty(&warnings).unwrap()
+}
+
+fn load_warnings(path: &Path) -> Vec<ClippyWarning> {
+    let file = std::fs::read(path).unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+    serde_json::from_slice(&file).unwrap_or_else(|e| panic!("failed to parse {}: {e}", path.display()))
+}
+
+/// The differences in the warnings of a lint for one crate
+#[derive(Debug, Default)]
+struct Changes<'a> {
+    added: Vec<&'a ClippyWarning>,
+    removed: Vec<&'a ClippyWarning>,
+    /// Warnings at the same position with a different message or snippet, as `(old, new)`
+    changed: Vec<(&'a ClippyWarning, &'a ClippyWarning)>,
+}
+
+/// Compares the warnings of two runs, grouped by lint and crate. Warnings are matched by their
+/// crate, lint and position. A position can have several warnings, e.g. from macro expansions, so
+/// the warnings at each position are compared as multisets.
+fn diff_warnings<'a>(
+    old: &'a [ClippyWarning],
+    new: &'a [ClippyWarning],
+) -> BTreeMap<&'a str, BTreeMap<String, Changes<'a>>> {
+    let mut by_key: BTreeMap<_, (Vec<&ClippyWarning>, Vec<&ClippyWarning>)> = BTreeMap::new();
+    for warning in old {
+        by_key.entry(warning.key()).or_default().0.push(warning);
+    }
+    for warning in new {
+        by_key.entry(warning.key()).or_default().1.push(warning);
+    }
+
+    let mut lints = BTreeMap::new();
+    for (mut old_warnings, mut new_warnings) in by_key.into_values() {
+        // The warnings found in both runs are unchanged
+        old_warnings.retain(|&old_warning| {
+            let same = new_warnings.iter().position(|new_warning| {
+                old_warning.message == new_warning.message && old_warning.snippet == new_warning.snippet
+            });
+            same.map(|index| new_warnings.remove(index)).is_none()
+        });
+
+        let changed = old_warnings.len().min(new_warnings.len());
+        for (&old_warning, &new_warning) in old_warnings.iter().zip(&new_warnings) {
+            changes_of(&mut lints, new_warning)
+                .changed
+                .push((old_warning, new_warning));
+        }
+        for &warning in &new_warnings[changed..] {
+            changes_of(&mut lints, warning).added.push(warning);
+        }
+        for &warning in &old_warnings[changed..] {
+            changes_of(&mut lints, warning).removed.push(warning);
+        }
+    }
+
+    lints
+}
+
+fn changes_of<'a, 'b>(
+    lints: &'b mut BTreeMap<&'a str, BTreeMap<String, Changes<'a>>>,
+    warning: &'a ClippyWarning,
+) -> &'b mut Changes<'a> {
+    lints
+        .entry(&warning.lint_type)
+        .or_default()
+        .entry(format!("{} {}", warning.crate_name, warning.crate_version))
+        .or_default()
+}
+
+fn print_warning(output: &mut String, prefix: char, warning: &ClippyWarning) {
+    let _ = writeln!(
+        output,
+        "{prefix} {}:{}:{} \"{}\"",
+        warning.file, warning.line, warning.column, warning.message
+    );
+    for line in warning.snippet.lines() {
+        let _ = writeln!(output, "    | {line}");
+    }
+}
+
+/// Prints the warnings that were added, removed or changed between the two logs
+pub(crate) fn diff(old_path: &Path, new_path: &Path) {
+    let old = load_warnings(old_path);
+    let new = load_warnings(new_path);
+    let lints = diff_warnings(&old, &new);
+
+    if lints.is_empty() {
+        println!(
+            "No differences between {} and {}",
+            old_path.display(),
+            new_path.display()
+        );
+        return;
+    }
+
+    let mut output = String::new();
+    for (lint, crates) in &lints {
+        let _ = writeln!(output, "## `{lint}`\n");
+        for (krate, changes) in crates {
+            let _ = writeln!(
+                output,
+                "### {krate}: {} added, {} removed, {} changed\n",
+                changes.added.len(),
+                changes.removed.len(),
+                changes.changed.len()
+            );
+            for warning in &changes.added {
+                print_warning(&mut output, '+', warning);
+            }
+            for warning in &changes.removed {
+                print_warning(&mut output, '-', warning);
+            }
+            for (old_warning, new_warning) in &changes.changed {
+                print_warning(&mut output, '-', old_warning);
+                print_warning(&mut output, '+', new_warning);
+            }
+            output.push('\n');
+        }
+    }
+    print!("{output}");
+}
+
+#[test]
+fn diff_groups_by_lint_and_crate() {
+    fn warning(lint: &str, line: usize, message: &str) -> ClippyWarning {
+        ClippyWarning {
+            crate_name: "krate".into(),
+            crate_version: "1.0.0".into(),
+            file: "src/lib.rs".into(),
+            line,
+            column: 5,
+            lint_type: lint.into(),
+            message: message.into(),
+            snippet: String::new(),
+            fixable: false,
+            is_ice: false,
+        }
+    }
+
+    let old = [
+        warning("clippy::a", 1, "kept"),
+        warning("clippy::a", 2, "removed"),
+        warning("clippy::b", 3, "old message"),
+    ];
+    let new = [
+        warning("clippy::a", 1, "kept"),
+        warning("clippy::a", 4, "added"),
+        warning("clippy::b", 3, "new message"),
+    ];
+    let lints = diff_warnings(&old, &new);
+
+    let a = &lints["clippy::a"]["krate 1.0.0"];
+    assert_eq!(a.added.iter().map(|w| w.line).collect::<Vec<_>>(), [4]);
+    assert_eq!(a.removed.iter().map(|w| w.line).collect::<Vec<_>>(), [2]);
+    assert!(a.changed.is_empty());
+
+    let b = &lints["clippy::b"]["krate 1.0.0"];
+    assert!(b.added.is_empty() && b.removed.is_empty());
+    assert_eq!(b.changed[0].1.message, "new message");
+}
+
+#[test]
+fn diff_counts_duplicate_warnings() {
+    fn warning(message: &str) -> ClippyWarning {
+        ClippyWarning {
+            crate_name: "krate".into(),
+            crate_version: "1.0.0".into(),
+            file: "src/lib.rs".into(),
+            line: 1,
+            column: 5,
+            lint_type: "clippy::a".into(),
+            message: message.into(),
+            snippet: String::new(),
+            fixable: false,
+            is_ice: false,
+        }
+    }
+
+    // Three warnings at the same position become two, and one of them changes
+    let old = [warning("same"), warning("same"), warning("old")];
+    let new = [warning("new"), warning("same")];
+    let lints = diff_warnings(&old, &new);
+
+    let a = &lints["clippy::a"]["krate 1.0.0"];
+    assert!(a.added.is_empty());
+    assert_eq!(a.removed.iter().map(|w| &*w.message).collect::<Vec<_>>(), ["old"]);
+    assert_eq!(a.changed.len(), 1);
+    assert_eq!((&*a.changed[0].0.message, &*a.changed[0].1.message), ("same", "new"));
+
+    // Duplicates that appear in the new run are added
+    let lints = diff_warnings(&new, &old);
+    let a = &lints["clippy::a"]["krate 1.0.0"];
+    assert_eq!(a.added.len(), 1);
+    assert!(a.removed.is_empty());
+
+    assert!(diff_warnings(&old, &old).is_empty());
+}
@@ -8,21 +8,26 @@
 #![allow(clippy::collapsible_else_if)]
 
 mod config;
+mod fix;
+mod json;
+mod recursive;
+mod timings;
 
 use config::LintcheckConfig;
 
 use std::collections::HashMap;
 use std::env;
+use std::ffi::OsString;
 use std::fmt::Write as _;
 use std::fs::write;
 use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, Output};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::thread;
 use std::time::Duration;
 
-use cargo_metadata::diagnostic::DiagnosticLevel;
+use cargo_metadata::diagnostic::{Applicability, DiagnosticLevel};
 use cargo_metadata::Message;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -45,6 +50,10 @@ const LINTCHECK_SOURCES: &str = "target/lintcheck/sources";
 #[derive(Debug, Serialize, Deserialize)]
 struct SourceList {
     crates: HashMap<String, TomlCrate>,
+    /// Resolve the `versions` of all crates from this `cargo vendor` directory
+    vendor_dir: Option<String>,
+    /// Resolve the `versions` of all crates from this local registry
+    registry_dir: Option<String>,
 }
 
 /// A crate source stored inside the .toml
@@ -56,6 +65,8 @@ struct TomlCrate {
     git_url: Option<String>,
     git_hash: Option<String>,
     path: Option<String>,
+    vendor_dir: Option<String>,
+    registry_dir: Option<String>,
     options: Option<Vec<String>>,
 }
 
@@ -79,6 +90,20 @@ enum CrateSource {
         path: PathBuf,
         options: Option<Vec<String>>,
     },
+    /// A crate in a directory created by `cargo vendor`
+    Vendored {
+        name: String,
+        version: String,
+        vendor_dir: PathBuf,
+        options: Option<Vec<String>>,
+    },
+    /// A `.crate` archive in a local registry, like the ones created by `cargo local-registry`
+    LocalRegistry {
+        name: String,
+        version: String,
+        registry_dir: PathBuf,
+        options: Option<Vec<String>>,
+    },
 }
 
 /// Represents the actual source code of a crate that we ran "cargo clippy" on
@@ -89,25 +114,42 @@ struct Crate {
     // path to the extracted sources that clippy can check
     path: PathBuf,
     options: Option<Vec<String>>,
+    /// Arguments passed to cargo, like the source replacement of offline sources
+    cargo_args: Vec<String>,
+}
+
+/// Returns the name and version of a package from a registry, like a vendor directory or
+/// crates.io, given its id in the format `<name> <version> (<source>)`. Packages given by their
+/// path, like the checked crates, return `None`.
+fn dependency_name_and_version(package_id: &str) -> Option<(&str, &str)> {
+    let mut parts = package_id.splitn(3, ' ');
+    let (name, version, source) = (parts.next()?, parts.next()?, parts.next()?);
+    (!source.starts_with("(path+")).then_some((name, version))
 }
 
 /// A single warning that clippy issued while checking a `Crate`
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 struct ClippyWarning {
     crate_name: String,
+    crate_version: String,
     file: String,
     line: usize,
     column: usize,
     lint_type: String,
     message: String,
+    /// The source lines of the primary span
+    snippet: String,
+    /// Whether the warning has a suggestion `--fix` applies
+    #[serde(default)]
+    fixable: bool,
     is_ice: bool,
 }
 
 #[allow(unused)]
 impl ClippyWarning {
     fn new(cargo_message: Message, krate: &Crate) -> Option<Self> {
-        let diag = match cargo_message {
-            Message::CompilerMessage(message) => message.message,
+        let (package_id, diag) = match cargo_message {
+            Message::CompilerMessage(message) => (message.package_id, message.message),
             _ => return None,
         };
 
@@ -118,27 +160,63 @@ impl ClippyWarning {
             return None;
         }
 
+        let fixable = diag
+            .children
+            .iter()
+            .flat_map(|child| &child.spans)
+            .chain(&diag.spans)
+            .any(|span| span.suggestion_applicability == Some(Applicability::MachineApplicable));
         let span = diag.spans.into_iter().find(|span| span.is_primary)?;
+        let snippet = span
+            .text
+            .iter()
+            .map(|line| line.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
 
         let file = match Path::new(&span.file_name).strip_prefix(env!("CARGO_HOME")) {
             Ok(stripped) => format!("$CARGO_HOME/{}", stripped.display()),
+            // the sources of dependencies from a vendor directory or a local registry
+            Err(_) if Path::new(&span.file_name).is_absolute() => span.file_name.clone(),
             Err(_) => format!(
                 "target/lintcheck/sources/{}-{}/{}",
                 krate.name, krate.version, span.file_name
             ),
         };
 
+        // warnings of the dependencies, in --recursive mode or with --filter, belong to the
+        // dependency rather than to the checked crate
+        let (crate_name, crate_version) = match dependency_name_and_version(&package_id.repr) {
+            Some((name, version)) => (name.to_string(), version.to_string()),
+            None => (krate.name.clone(), krate.version.clone()),
+        };
+
         Some(Self {
-            crate_name: krate.name.clone(),
+            crate_name,
+            crate_version,
             file,
             line: span.line_start,
             column: span.column_start,
             lint_type,
             message: diag.message,
+            snippet,
+            fixable,
             is_ice: diag.level == DiagnosticLevel::Ice,
         })
     }
 
+    /// Identifies a warning across two runs of lintcheck
+    fn key(&self) -> (&str, &str, &str, &str, usize, usize) {
+        (
+            &self.lint_type,
+            &self.crate_name,
+            &self.crate_version,
+            &self.file,
+            self.line,
+            self.column,
+        )
+    }
+
     fn to_output(&self, markdown: bool) -> String {
         let file_with_pos = format!("{}:{}:{}", &self.file, &self.line, &self.column);
         if markdown {
@@ -200,11 +278,7 @@ impl CrateSource {
                     // copy the crate into the file
                     std::io::copy(&mut krate_req, &mut krate_dest).unwrap();
 
-                    // unzip the tarball
-                    let ungz_tar = flate2::read::GzDecoder::new(std::fs::File::open(&krate_file_path).unwrap());
-                    // extract the tar archive
-                    let mut archive = tar::Archive::new(ungz_tar);
-                    archive.unpack(&extract_dir).expect("Failed to extract!");
+                    extract_crate(&krate_file_path, &extract_dir);
                 }
                 // crate is extracted, return a new Krate object which contains the path to the extracted
                 // sources that clippy can check
@@ -213,6 +287,7 @@ impl CrateSource {
                     name: name.clone(),
                     path: extract_dir.join(format!("{}-{}/", name, version)),
                     options: options.clone(),
+                    cargo_args: Vec::new(),
                 }
             },
             CrateSource::Git {
@@ -258,6 +333,7 @@ impl CrateSource {
                     name: name.clone(),
                     path: repo_path,
                     options: options.clone(),
+                    cargo_args: Vec::new(),
                 }
             },
             CrateSource::Path { name, path, options } => {
@@ -271,38 +347,132 @@ impl CrateSource {
                 }
 
                 println!("Copying {:?} to {:?}", path, dest_crate_root);
+                copy_dir(path, &dest_crate_root);
 
-                fn is_cache_dir(entry: &DirEntry) -> bool {
-                    std::fs::read(entry.path().join("CACHEDIR.TAG"))
-                        .map(|x| x.starts_with(b"Signature: 8a477f597d28d172789f06886806bc55"))
-                        .unwrap_or(false)
+                Crate {
+                    version: String::from("local"),
+                    name: name.clone(),
+                    path: dest_crate_root,
+                    options: options.clone(),
+                    cargo_args: Vec::new(),
                 }
+            },
+            CrateSource::Vendored {
+                name,
+                version,
+                vendor_dir,
+                options,
+            } => {
+                let vendored_path = find_vendored_crate(vendor_dir, name, version).unwrap_or_else(|| {
+                    panic!("{} {} not found in {}", name, version, vendor_dir.display());
+                });
+                // copy the sources, so that neither `cargo` nor `--fix` modify the vendor dir
+                let dest_crate_root = PathBuf::from(LINTCHECK_SOURCES).join(format!("{}-{}", name, version));
+                if dest_crate_root.exists() {
+                    std::fs::remove_dir_all(&dest_crate_root).unwrap();
+                }
+                println!("Copying {:?} to {:?}", vendored_path, dest_crate_root);
+                std::fs::create_dir_all(LINTCHECK_SOURCES).unwrap();
+                copy_dir(&vendored_path, &dest_crate_root);
 
-                for entry in WalkDir::new(path).into_iter().filter_entry(|e| !is_cache_dir(e)) {
-                    let entry = entry.unwrap();
-                    let entry_path = entry.path();
-                    let relative_entry_path = entry_path.strip_prefix(path).unwrap();
-                    let dest_path = dest_crate_root.join(relative_entry_path);
-                    let metadata = entry_path.symlink_metadata().unwrap();
-
-                    if metadata.is_dir() {
-                        std::fs::create_dir(dest_path).unwrap();
-                    } else if metadata.is_file() {
-                        std::fs::copy(entry_path, dest_path).unwrap();
-                    }
+                let vendor_dir = vendor_dir.canonicalize().unwrap();
+                Crate {
+                    version: version.clone(),
+                    name: name.clone(),
+                    path: dest_crate_root,
+                    options: options.clone(),
+                    cargo_args: offline_cargo_args("directory", &vendor_dir),
+                }
+            },
+            CrateSource::LocalRegistry {
+                name,
+                version,
+                registry_dir,
+                options,
+            } => {
+                let extract_dir = PathBuf::from(LINTCHECK_SOURCES);
+                let krate_file_path = registry_dir.join(format!("{}-{}.crate", name, version));
+                let dest_crate_root = extract_dir.join(format!("{}-{}/", name, version));
+                if !dest_crate_root.is_dir() {
+                    println!("Extracting {}", krate_file_path.display());
+                    std::fs::create_dir_all(&extract_dir).unwrap();
+                    extract_crate(&krate_file_path, &extract_dir);
                 }
 
+                let registry_dir = registry_dir.canonicalize().unwrap();
                 Crate {
-                    version: String::from("local"),
+                    version: version.clone(),
                     name: name.clone(),
                     path: dest_crate_root,
                     options: options.clone(),
+                    cargo_args: offline_cargo_args("local-registry", &registry_dir),
                 }
             },
         }
     }
 }
 
+/// Extracts the `.crate` archive at `krate_file_path` into `extract_dir`
+fn extract_crate(krate_file_path: &Path, extract_dir: &Path) {
+    // unzip the tarball
+    let ungz_tar = flate2::read::GzDecoder::new(std::fs::File::open(krate_file_path).unwrap());
+    // extract the tar archive
+    let mut archive = tar::Archive::new(ungz_tar);
+    archive.unpack(extract_dir).expect("Failed to extract!");
+}
+
+/// Copies the directory `path` to `dest`, but skips directories that contain a CACHEDIR.TAG file.
+/// The target/ directory contains a CACHEDIR.TAG file so it is the most commonly skipped directory
+/// as a result of this filter.
+fn copy_dir(path: &Path, dest: &Path) {
+    fn is_cache_dir(entry: &DirEntry) -> bool {
+        std::fs::read(entry.path().join("CACHEDIR.TAG"))
+            .map(|x| x.starts_with(b"Signature: 8a477f597d28d172789f06886806bc55"))
+            .unwrap_or(false)
+    }
+
+    for entry in WalkDir::new(path).into_iter().filter_entry(|e| !is_cache_dir(e)) {
+        let entry = entry.unwrap();
+        let entry_path = entry.path();
+        let relative_entry_path = entry_path.strip_prefix(path).unwrap();
+        let dest_path = dest.join(relative_entry_path);
+        let metadata = entry_path.symlink_metadata().unwrap();
+
+        if metadata.is_dir() {
+            std::fs::create_dir_all(dest_path).unwrap();
+        } else if metadata.is_file() {
+            std::fs::copy(entry_path, dest_path).unwrap();
+        }
+    }
+}
+
+/// Finds `name` `version` in a `cargo vendor` directory. Crates are in `<name>-<version>` when
+/// several versions of them are vendored, or with `--versioned-dirs`, and in `<name>` otherwise.
+fn find_vendored_crate(vendor_dir: &Path, name: &str, version: &str) -> Option<PathBuf> {
+    let versioned = vendor_dir.join(format!("{}-{}", name, version));
+    if versioned.is_dir() {
+        return Some(versioned);
+    }
+
+    let unversioned = vendor_dir.join(name);
+    let manifest = std::fs::read_to_string(unversioned.join("Cargo.toml")).ok()?;
+    let manifest: toml::Value = toml::from_str(&manifest).ok()?;
+    let found_version = manifest.get("package")?.get("version")?.as_str()?;
+    (found_version == version).then_some(unversioned)
+}
+
+/// The arguments making cargo resolve all dependencies from the `kind` source at `path` without
+/// network access, where `kind` is `directory` or `local-registry`
+fn offline_cargo_args(kind: &str, path: &Path) -> Vec<String> {
+    vec![
+        "--offline".into(),
+        "--config".into(),
+        r#"source.crates-io.replace-with="lintcheck-offline""#.into(),
+        "--config".into(),
+        format!("source.lintcheck-offline.{}={:?}", kind, path.display().to_string()),
+    ]
+}
+
 impl Crate {
     /// Run `cargo clippy` on the `Crate` and collect and return all the lint warnings that clippy
     /// issued
@@ -313,6 +483,7 @@ impl Crate {
         total_crates_to_lint: usize,
         config: &LintcheckConfig,
         lint_filter: &Vec<String>,
+        timings_dir: Option<&Path>,
     ) -> Vec<ClippyWarning> {
         // advance the atomic index by one
         let index = target_dir_index.fetch_add(1, Ordering::SeqCst);
@@ -332,19 +503,55 @@ impl Crate {
             );
         }
 
-        let cargo_clippy_path = std::fs::canonicalize(cargo_clippy_path).unwrap();
-
-        let shared_target_dir = clippy_project_root().join("target/lintcheck/shared_target_dir");
-
-        let mut args = if config.fix {
-            vec!["--fix", "--"]
+        let target_dir = if config.recursive {
+            recursive::target_dir(thread_index)
         } else {
-            vec!["--", "--message-format=json", "--"]
+            clippy_project_root()
+                .join("target/lintcheck/shared_target_dir")
+                // use the looping index to create individual target dirs
+                .join(format!("_{:?}", thread_index))
         };
 
+        let mut envs = Vec::new();
+        if let Some(run_dir) = timings_dir {
+            envs.push(("CLIPPY_LINT_TIMINGS", self.timings_dir(run_dir).into_os_string()));
+        }
+        if config.recursive {
+            envs.extend(recursive::wrapper_env());
+        }
+
+        let all_output = self.cargo_clippy(
+            cargo_clippy_path,
+            &self.path,
+            &target_dir,
+            &["--message-format=json"],
+            &self.lint_args(lint_filter),
+            &envs,
+        );
+        let stdout = String::from_utf8_lossy(&all_output.stdout);
+        let status = &all_output.status;
+
+        if !status.success() {
+            eprintln!(
+                "\nWARNING: bad exit status after checking {} {} \n",
+                self.name, self.version
+            );
+        }
+
+        // get all clippy warnings and ICEs
+        let warnings: Vec<ClippyWarning> = Message::parse_stream(stdout.as_bytes())
+            .filter_map(|msg| ClippyWarning::new(msg.unwrap(), &self))
+            .collect();
+
+        warnings
+    }
+
+    /// The arguments passed to `clippy-driver` to check the lints of this crate
+    fn lint_args<'a>(&'a self, lint_filter: &'a [String]) -> Vec<&'a str> {
+        let mut args = Vec::new();
         if let Some(options) = &self.options {
             for opt in options {
-                args.push(opt);
+                args.push(opt.as_str());
             }
         } else {
             args.extend(&["-Wclippy::pedantic", "-Wclippy::cargo"])
@@ -356,58 +563,45 @@ impl Crate {
             args.push("--cap-lints=allow");
             args.extend(lint_filter.iter().map(|filter| filter.as_str()))
         }
+        args
+    }
 
-        let all_output = std::process::Command::new(&cargo_clippy_path)
-            // use the looping index to create individual target dirs
-            .env(
-                "CARGO_TARGET_DIR",
-                shared_target_dir.join(format!("_{:?}", thread_index)),
-            )
+    /// Runs `cargo clippy` on the sources of the crate at `path`, which is either `self.path` or a
+    /// copy of it, with the additional environment variables `envs`
+    fn cargo_clippy(
+        &self,
+        cargo_clippy_path: &Path,
+        path: &Path,
+        target_dir: &Path,
+        cargo_args: &[&str],
+        clippy_args: &[&str],
+        envs: &[(&str, OsString)],
+    ) -> Output {
+        let cargo_clippy_path = std::fs::canonicalize(cargo_clippy_path).unwrap();
+
+        // the first argument takes the place of `clippy` in `cargo clippy`
+        let mut args = vec!["--"];
+        args.extend(cargo_args);
+        args.extend(self.cargo_args.iter().map(String::as_str));
+        args.push("--");
+        args.extend(clippy_args);
+
+        std::process::Command::new(&cargo_clippy_path)
+            .env("CARGO_TARGET_DIR", target_dir)
+            .envs(envs.iter().map(|(key, value)| (key, value)))
             // lint warnings will look like this:
             // src/cargo/ops/cargo_compile.rs:127:35: warning: usage of `FromIterator::from_iter`
             .args(&args)
-            .current_dir(&self.path)
+            .current_dir(path)
             .output()
             .unwrap_or_else(|error| {
                 panic!(
                     "Encountered error:\n{:?}\ncargo_clippy_path: {}\ncrate path:{}\n",
                     error,
                     &cargo_clippy_path.display(),
-                    &self.path.display()
+                    &path.display()
                 );
-            });
-        let stdout = String::from_utf8_lossy(&all_output.stdout);
-        let stderr = String::from_utf8_lossy(&all_output.stderr);
-        let status = &all_output.status;
-
-        if !status.success() {
-            eprintln!(
-                "\nWARNING: bad exit status after checking {} {} \n",
-                self.name, self.version
-            );
-        }
-
-        if config.fix {
-            if let Some(stderr) = stderr
-                .lines()
-                .find(|line| line.contains("failed to automatically apply fixes suggested by rustc to crate"))
-            {
-                let subcrate = &stderr[63..];
-                println!(
-                    "ERROR: failed to apply some suggetion to {} / to (sub)crate {}",
-                    self.name, subcrate
-                );
-            }
-            // fast path, we don't need the warnings anyway
-            return Vec::new();
-        }
-
-        // get all clippy warnings and ICEs
-        let warnings: Vec<ClippyWarning> = Message::parse_stream(stdout.as_bytes())
-            .filter_map(|msg| ClippyWarning::new(msg.unwrap(), &self))
-            .collect();
-
-        warnings
+            })
     }
 }
 
@@ -429,6 +623,8 @@ fn read_crates(toml_path: &Path) -> Vec<CrateSource> {
         std::fs::read_to_string(&toml_path).unwrap_or_else(|_| panic!("Failed to read {}", toml_path.display()));
     let crate_list: SourceList =
         toml::from_str(&toml_content).unwrap_or_else(|e| panic!("Failed to parse {}: \n{}", toml_path.display(), e));
+    let vendor_dir = crate_list.vendor_dir;
+    let registry_dir = crate_list.registry_dir;
     // parse the hashmap of the toml file into a list of crates
     let tomlcrates: Vec<TomlCrate> = crate_list
         .crates
@@ -447,12 +643,39 @@ fn read_crates(toml_path: &Path) -> Vec<CrateSource> {
                 options: tk.options.clone(),
             });
         } else if let Some(ref versions) = tk.versions {
+            // the source of a crate takes precedence over the one of the whole list
+            let (vendor_dir, registry_dir) = if tk.vendor_dir.is_some() || tk.registry_dir.is_some() {
+                (tk.vendor_dir.as_ref(), tk.registry_dir.as_ref())
+            } else {
+                (vendor_dir.as_ref(), registry_dir.as_ref())
+            };
+            if vendor_dir.is_some() && registry_dir.is_some() {
+                panic!(
+                    "Error: TomlCrate {} can only have one of 'vendor_dir' or 'registry_dir'",
+                    tk.name
+                );
+            }
             // if we have multiple versions, save each one
             versions.iter().for_each(|ver| {
-                crate_sources.push(CrateSource::CratesIo {
-                    name: tk.name.clone(),
-                    version: ver.to_string(),
-                    options: tk.options.clone(),
+                let name = tk.name.clone();
+                let version = ver.to_string();
+                let options = tk.options.clone();
+                crate_sources.push(if let Some(vendor_dir) = vendor_dir {
+                    CrateSource::Vendored {
+                        name,
+                        version,
+                        vendor_dir: PathBuf::from(vendor_dir),
+                        options,
+                    }
+                } else if let Some(registry_dir) = registry_dir {
+                    CrateSource::LocalRegistry {
+                        name,
+                        version,
+                        registry_dir: PathBuf::from(registry_dir),
+                        options,
+                    }
+                } else {
+                    CrateSource::CratesIo { name, version, options }
                 });
             })
         } else if tk.git_url.is_some() && tk.git_hash.is_some() {
@@ -543,14 +766,24 @@ fn lintcheck_needs_rerun(lintcheck_logs_path: &Path) -> bool {
 }
 
 fn main() {
+    // in --recursive mode, cargo runs lintcheck itself instead of rustc
+    if let Some(markers) = recursive::wrapper_markers() {
+        recursive::run_wrapper(&markers);
+    }
+
+    let config = LintcheckConfig::new();
+
+    if let Some((old, new)) = &config.diff {
+        json::diff(old, new);
+        return;
+    }
+
     // assert that we launch lintcheck from the repo root (via cargo lintcheck)
     if std::fs::metadata("lintcheck/Cargo.toml").is_err() {
         eprintln!("lintcheck needs to be run from clippy's repo root!\nUse `cargo lintcheck` alternatively.");
         std::process::exit(3);
     }
 
-    let config = LintcheckConfig::new();
-
     println!("Compiling clippy...");
     build_clippy();
     println!("Done compiling");
@@ -610,7 +843,9 @@ fn main() {
                 let name = match krate {
                     CrateSource::CratesIo { name, .. }
                     | CrateSource::Git { name, .. }
-                    | CrateSource::Path { name, .. } => name,
+                    | CrateSource::Path { name, .. }
+                    | CrateSource::Vendored { name, .. }
+                    | CrateSource::LocalRegistry { name, .. } => name,
                 };
 
                 name == only_one_crate
@@ -639,14 +874,47 @@ fn main() {
         .build_global()
         .unwrap();
 
+    // in --fix mode, only write the report of the fixes and don't change the log files
+    if config.fix {
+        let results: Vec<fix::FixResult> = crates
+            .par_iter()
+            .flat_map(|krate| {
+                let warnings =
+                    krate.run_clippy_lints(&cargo_clippy_path, &counter, crates.len(), &config, &lint_filter, None);
+                krate.check_fixes(&cargo_clippy_path, &warnings, &lint_filter)
+            })
+            .collect();
+        fix::write_report(&results, &config.lintcheck_fix_results_path);
+        return;
+    }
+
+    if config.recursive {
+        recursive::prepare();
+    }
+
+    let timings_dir = config.timings.then(timings::run_dir);
     let clippy_warnings: Vec<ClippyWarning> = crates
         .par_iter()
-        .flat_map(|krate| krate.run_clippy_lints(&cargo_clippy_path, &counter, crates.len(), &config, &lint_filter))
+        .flat_map(|krate| {
+            krate.run_clippy_lints(
+                &cargo_clippy_path,
+                &counter,
+                crates.len(),
+                &config,
+                &lint_filter,
+                timings_dir.as_deref(),
+            )
+        })
         .collect();
 
-    // if we are in --fix mode, don't change the log files, terminate here
-    if config.fix {
-        return;
+    if let Some(timings_dir) = &timings_dir {
+        let profile = timings::Profile::collect(timings_dir, &crates);
+        timings::write_report(
+            &profile,
+            &config.lintcheck_timings_report_path,
+            &config.lintcheck_timings_path,
+            config.save_profile,
+        );
     }
 
     // generate some stats
@@ -684,6 +952,11 @@ fn main() {
     std::fs::create_dir_all(config.lintcheck_results_path.parent().unwrap()).unwrap();
     write(&config.lintcheck_results_path, text).unwrap();
 
+    // save every warning into lintcheck-logs/logs.json, for `lintcheck diff`
+    let json_path = config.lintcheck_results_path.with_extension("json");
+    println!("Writing structured logs to {}", json_path.display());
+    write(&json_path, json::output(&clippy_warnings)).unwrap();
+
     print_stats(old_stats, new_stats, &config.lint_filter);
 }
 
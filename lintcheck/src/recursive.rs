@@ -0,0 +1,102 @@
+//! `lintcheck --recursive`: lints the dependencies of the listed crates as well as the crates.
+//!
+//! Cargo runs lintcheck itself as the `RUSTC_WRAPPER` of every crate it compiles. For the
+//! dependencies, which cargo compiles with `--cap-lints allow`, the wrapper removes that argument
+//! so that `clippy-driver` lints them like the listed crates. A dependency shared between several
+//! crates is only linted the first time it is compiled, which the wrapper remembers with a marker
+//! file for each of them.
+
+use std::ffi::OsString;
+use std::fs::OpenOptions;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::{clippy_project_root, CLIPPY_DRIVER_PATH};
+
+const LINTCHECK_RECURSIVE: &str = "target/lintcheck/recursive";
+
+/// Set to the directory of the marker files when lintcheck runs as the `RUSTC_WRAPPER`
+const MARKERS_ENV: &str = "LINTCHECK_RECURSIVE_MARKERS";
+/// The path of the `clippy-driver` the wrapper runs
+const DRIVER_ENV: &str = "LINTCHECK_CLIPPY_DRIVER";
+
+/// Returns the directory of the marker files if lintcheck was started as the `RUSTC_WRAPPER`
+pub(crate) fn wrapper_markers() -> Option<PathBuf> {
+    std::env::var_os(MARKERS_ENV).map(PathBuf::from)
+}
+
+/// Clears the markers and the target directories of the previous run. The dependencies must be
+/// compiled again to get their warnings, so they can't share the target directories of the
+/// other modes.
+pub(crate) fn prepare() {
+    let recursive_dir = clippy_project_root().join(LINTCHECK_RECURSIVE);
+    if recursive_dir.exists() {
+        std::fs::remove_dir_all(&recursive_dir).unwrap();
+    }
+    std::fs::create_dir_all(recursive_dir.join("markers")).unwrap();
+}
+
+/// The target directory of the `thread_index`th thread
+pub(crate) fn target_dir(thread_index: usize) -> PathBuf {
+    clippy_project_root()
+        .join(LINTCHECK_RECURSIVE)
+        .join(format!("target/_{thread_index}"))
+}
+
+/// The environment making cargo run lintcheck as the `RUSTC_WRAPPER`
+pub(crate) fn wrapper_env() -> Vec<(&'static str, OsString)> {
+    let clippy_driver_path = std::fs::canonicalize(CLIPPY_DRIVER_PATH).expect("clippy-driver binary not found");
+    vec![
+        ("RUSTC_WRAPPER", std::env::current_exe().unwrap().into_os_string()),
+        (
+            MARKERS_ENV,
+            clippy_project_root().join(LINTCHECK_RECURSIVE).join("markers").into(),
+        ),
+        (DRIVER_ENV, clippy_driver_path.into()),
+    ]
+}
+
+/// Runs `clippy-driver` with the arguments cargo passed to the wrapper, and exits with its status
+pub(crate) fn run_wrapper(markers: &Path) -> ! {
+    let driver = std::env::var_os(DRIVER_ENV).expect("LINTCHECK_CLIPPY_DRIVER is not set");
+    let mut args: Vec<OsString> = std::env::args_os().skip(1).collect();
+
+    // workspace members are run through `RUSTC_WORKSPACE_WRAPPER`, which is already the driver
+    let is_member = args.first().and_then(|arg| Path::new(arg).file_stem()) == Some("clippy-driver".as_ref());
+    if is_member {
+        args.remove(0);
+    } else if let Some(cap_lints) = args
+        .windows(2)
+        .position(|pair| pair[0] == "--cap-lints" && pair[1] == "allow")
+    {
+        if first_compilation(markers, &args) {
+            args.drain(cap_lints..cap_lints + 2);
+        }
+    }
+
+    let status = Command::new(driver)
+        .args(&args)
+        .status()
+        .expect("failed to run clippy-driver");
+    std::process::exit(status.code().unwrap_or(1));
+}
+
+/// Whether the crate compiled with `args` wasn't compiled before during this run, in which case
+/// it's marked as compiled
+fn first_compilation(markers: &Path, args: &[OsString]) -> bool {
+    let crate_name = args
+        .windows(2)
+        .find(|pair| pair[0] == "--crate-name")
+        .map_or_else(OsString::new, |pair| pair[1].clone());
+    let marker = format!(
+        "{}-{}-{}",
+        std::env::var("CARGO_PKG_NAME").unwrap_or_default(),
+        std::env::var("CARGO_PKG_VERSION").unwrap_or_default(),
+        crate_name.to_string_lossy()
+    );
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(markers.join(marker))
+        .is_ok()
+}
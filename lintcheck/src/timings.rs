@@ -0,0 +1,338 @@
+//! `lintcheck --timings`: runs Clippy with `CLIPPY_LINT_TIMINGS`, which makes it record the time
+//! each lint pass spends in its `check_*` methods, then reports the slowest passes of every crate
+//! and the ones that got slower since the profile stored by a previous run with `--save-profile`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use walkdir::WalkDir;
+
+use crate::{clippy_project_root, Crate};
+
+const LINTCHECK_TIMINGS: &str = "target/lintcheck/timings";
+
+/// A pass is a regression if it got this many times slower than in the stored profile...
+const REGRESSION_FACTOR: f64 = 1.5;
+/// ...and at least this much slower, to ignore the noise of the fast passes
+const REGRESSION_MIN: Duration = Duration::from_millis(10);
+/// The number of passes listed in the report, overall and for each crate
+const SLOWEST_PASSES: usize = 15;
+
+/// The time a lint pass spent checking a crate
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PassTimings {
+    nanos: u64,
+    /// The number of calls and the nanoseconds spent in each `check_*` method
+    methods: BTreeMap<String, (u64, u64)>,
+}
+
+impl PassTimings {
+    fn add(&mut self, other: &PassTimings) {
+        self.nanos += other.nanos;
+        for (method, (calls, nanos)) in &other.methods {
+            let total = self.methods.entry(method.clone()).or_default();
+            total.0 += calls;
+            total.1 += nanos;
+        }
+    }
+
+    fn slowest_method(&self) -> &str {
+        self.methods
+            .iter()
+            .max_by_key(|(_, (_, nanos))| nanos)
+            .map_or("", |(method, _)| method)
+    }
+}
+
+/// The timings of the lint passes, by crate (`<name> <version>`) and by pass (`<pass> (<kind>)`)
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct Profile {
+    crates: BTreeMap<String, BTreeMap<String, PassTimings>>,
+}
+
+impl Profile {
+    /// Loads a stored profile, or an empty one if there is none
+    pub(crate) fn load(path: &Path) -> Self {
+        match std::fs::read(path) {
+            Ok(file) => {
+                serde_json::from_slice(&file).unwrap_or_else(|e| panic!("failed to parse {}: {e}", path.display()))
+            },
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Reads the timings Clippy wrote while checking `crates` into `run_dir`
+    pub(crate) fn collect(run_dir: &Path, crates: &[Crate]) -> Self {
+        let mut profile = Self::default();
+        for krate in crates {
+            let passes = profile
+                .crates
+                .entry(format!("{} {}", krate.name, krate.version))
+                .or_default();
+            // every compiled target of the crate writes its own file
+            let files = std::fs::read_dir(krate.timings_dir(run_dir))
+                .into_iter()
+                .flatten()
+                .map(|entry| entry.unwrap().path());
+            for file in files {
+                let text = std::fs::read_to_string(&file).unwrap();
+                for line in text.lines() {
+                    let fields: Vec<&str> = line.split('\t').collect();
+                    if let [kind, pass, method, calls, nanos] = fields[..] {
+                        let (calls, nanos): (u64, u64) = (calls.parse().unwrap(), nanos.parse().unwrap());
+                        let timings = passes.entry(format!("{pass} ({kind})")).or_default();
+                        timings.nanos += nanos;
+                        let total = timings.methods.entry(method.to_string()).or_default();
+                        total.0 += calls;
+                        total.1 += nanos;
+                    }
+                }
+            }
+        }
+        profile
+    }
+
+    /// The timings of each pass, summed over all crates
+    fn totals(&self) -> BTreeMap<&str, PassTimings> {
+        let mut totals: BTreeMap<&str, PassTimings> = BTreeMap::new();
+        for passes in self.crates.values() {
+            for (pass, timings) in passes {
+                totals.entry(pass).or_default().add(timings);
+            }
+        }
+        totals
+    }
+}
+
+/// Returns a new directory for the timings of this run. The directory is part of the environment
+/// of Clippy, so a new one also makes cargo check the crates again.
+pub(crate) fn run_dir() -> PathBuf {
+    let timings_dir = clippy_project_root().join(LINTCHECK_TIMINGS);
+    if timings_dir.exists() {
+        std::fs::remove_dir_all(&timings_dir).unwrap();
+    }
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    timings_dir.join(now.as_millis().to_string())
+}
+
+impl Crate {
+    /// The directory where Clippy writes the timings of this crate
+    pub(crate) fn timings_dir(&self, run_dir: &Path) -> PathBuf {
+        run_dir.join(format!("{}-{}", self.name, self.version))
+    }
+}
+
+fn format_nanos(nanos: u64) -> String {
+    format!("{:.1}ms", Duration::from_nanos(nanos).as_secs_f64() * 1000.0)
+}
+
+fn is_regression(old: u64, new: u64) -> bool {
+    Duration::from_nanos(new.saturating_sub(old)) >= REGRESSION_MIN
+        && Duration::from_nanos(new).as_secs_f64() > Duration::from_nanos(old).as_secs_f64() * REGRESSION_FACTOR
+}
+
+/// Appends a table of the slowest passes of `passes` to `text`, and returns them
+fn write_slowest<'a>(text: &mut String, passes: impl Iterator<Item = (&'a str, &'a PassTimings)>) -> Vec<&'a str> {
+    let mut passes: Vec<_> = passes.collect();
+    passes.sort_by_key(|(_, timings)| std::cmp::Reverse(timings.nanos));
+    passes.truncate(SLOWEST_PASSES);
+    text.push_str("| pass | time | slowest method |\n| --- | --- | --- |\n");
+    for (pass, timings) in &passes {
+        let _ = writeln!(
+            text,
+            "| `{pass}` | {} | `{}` |",
+            format_nanos(timings.nanos),
+            timings.slowest_method()
+        );
+    }
+    text.push('\n');
+    passes.into_iter().map(|(pass, _)| pass).collect()
+}
+
+/// The lints of each pass of `clippy_lints`, from the `declare_lint_pass!` and `impl_lint_pass!`
+/// invocations that implement `get_lints` for them
+fn lints_of_passes() -> BTreeMap<String, Vec<String>> {
+    let mut passes = BTreeMap::new();
+    let files = WalkDir::new(clippy_project_root().join("clippy_lints/src"))
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().extension().map_or(false, |ext| ext == "rs"));
+    for file in files {
+        let Ok(text) = std::fs::read_to_string(file.path()) else {
+            continue;
+        };
+        passes.extend(parse_lint_passes(&text));
+    }
+    passes
+}
+
+/// Finds the passes and their lint lists in `declare_lint_pass!(Pass => [LINT, ..])` and
+/// `impl_lint_pass!(Pass => [LINT, ..])`
+fn parse_lint_passes(text: &str) -> Vec<(String, Vec<String>)> {
+    let mut passes = Vec::new();
+    for (start, _) in text.match_indices("_lint_pass!(") {
+        let args = &text[start + "_lint_pass!(".len()..];
+        let Some((pass, rest)) = args.split_once("=>") else {
+            continue;
+        };
+        let Some(lints) = rest.trim_start().strip_prefix('[').and_then(|rest| rest.split_once(']')) else {
+            continue;
+        };
+        let lints = lints
+            .0
+            .split(',')
+            .map(str::trim)
+            .filter(|lint| !lint.is_empty())
+            .map(|lint| format!("clippy::{}", lint.to_ascii_lowercase()))
+            .collect();
+        passes.push((pass.trim().to_string(), lints));
+    }
+    passes
+}
+
+/// Appends the lints of each of `passes`, named `<pass> (<kind>)`, to `text`
+fn write_lints_of_passes<'a>(text: &mut String, passes: impl IntoIterator<Item = &'a str>) {
+    let lints = lints_of_passes();
+    let mut passes: Vec<_> = passes
+        .into_iter()
+        .map(|pass| pass.rsplit_once(" (").map_or(pass, |(name, _)| name))
+        .collect();
+    passes.sort_unstable();
+    passes.dedup();
+
+    text.push_str("### Lints of the passes\n\n");
+    for pass in passes {
+        let lints = lints.get(pass).map_or_else(
+            || "unknown".to_string(),
+            |lints| {
+                lints
+                    .iter()
+                    .map(|lint| format!("`{lint}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            },
+        );
+        let _ = writeln!(text, "- `{pass}`: {lints}");
+    }
+    text.push('\n');
+}
+
+/// The passes that got slower than in `old`, as `(crate, pass, old nanos, new nanos)`. The totals
+/// over all crates are listed with the crate `all crates`.
+fn regressions<'a>(old: &'a Profile, new: &'a Profile) -> Vec<(&'a str, &'a str, u64, u64)> {
+    let mut regressions = Vec::new();
+    let old_totals = old.totals();
+    for (pass, timings) in new.totals() {
+        if let Some(old_timings) = old_totals.get(pass) {
+            if is_regression(old_timings.nanos, timings.nanos) {
+                regressions.push(("all crates", pass, old_timings.nanos, timings.nanos));
+            }
+        }
+    }
+    for (krate, passes) in &new.crates {
+        for (pass, timings) in passes {
+            let old_nanos = old.crates.get(krate).and_then(|passes| passes.get(pass));
+            if let Some(old_timings) = old_nanos {
+                if is_regression(old_timings.nanos, timings.nanos) {
+                    regressions.push((krate.as_str(), pass.as_str(), old_timings.nanos, timings.nanos));
+                }
+            }
+        }
+    }
+    regressions
+}
+
+/// Writes the report of the timings to `report_path`, flagging the regressions since the profile
+/// stored at `profile_path`. With `save_profile`, the new profile then replaces that profile.
+pub(crate) fn write_report(profile: &Profile, report_path: &Path, profile_path: &Path, save_profile: bool) {
+    let old = Profile::load(profile_path);
+
+    let mut text = String::from("### Regressions\n\n");
+    let regressions = regressions(&old, profile);
+    if regressions.is_empty() {
+        text.push_str("No lint pass got slower.\n\n");
+    } else {
+        text.push_str("| crate | pass | before | after |\n| --- | --- | --- | --- |\n");
+        for (krate, pass, old_nanos, new_nanos) in &regressions {
+            let _ = writeln!(
+                text,
+                "| {krate} | `{pass}` | {} | {} |",
+                format_nanos(*old_nanos),
+                format_nanos(*new_nanos)
+            );
+        }
+        text.push('\n');
+    }
+    print!("\n{text}");
+
+    text.push_str("### All crates\n\n");
+    let totals = profile.totals();
+    let mut listed = write_slowest(&mut text, totals.iter().map(|(pass, timings)| (*pass, timings)));
+    for (krate, passes) in &profile.crates {
+        let _ = writeln!(text, "### {krate}\n");
+        listed.extend(write_slowest(
+            &mut text,
+            passes.iter().map(|(pass, timings)| (pass.as_str(), timings)),
+        ));
+    }
+    write_lints_of_passes(&mut text, listed);
+
+    println!("Writing the report of the timings to {}", report_path.display());
+    std::fs::create_dir_all(report_path.parent().unwrap()).unwrap();
+    std::fs::write(report_path, text).unwrap();
+    if save_profile {
+        println!("Writing the timings profile to {}", profile_path.display());
+        std::fs::write(profile_path, serde_json::to_string_pretty(profile).unwrap()).unwrap();
+    }
+}
+
+#[test]
+fn regressions_need_a_large_and_relative_slowdown() {
+    fn profile(nanos: &[(&str, u64)]) -> Profile {
+        let passes = nanos
+            .iter()
+            .map(|&(pass, nanos)| {
+                let timings = PassTimings {
+                    nanos,
+                    methods: BTreeMap::new(),
+                };
+                (pass.to_string(), timings)
+            })
+            .collect();
+        Profile {
+            crates: BTreeMap::from([("krate 1.0.0".to_string(), passes)]),
+        }
+    }
+
+    let old = profile(&[("slower", 100_000_000), ("noise", 1_000_000), ("same", 100_000_000)]);
+    let new = profile(&[
+        ("slower", 200_000_000),
+        ("noise", 5_000_000),
+        ("same", 110_000_000),
+        ("new", 500_000_000),
+    ]);
+    let regressions: Vec<_> = regressions(&old, &new)
+        .into_iter()
+        .map(|(krate, pass, _, _)| (krate, pass))
+        .collect();
+    assert_eq!(regressions, [("all crates", "slower"), ("krate 1.0.0", "slower")]);
+}
+
+#[test]
+fn lint_passes_are_parsed() {
+    let text = "declare_lint_pass!(Single => [SINGLE_LINT]);\n\
+        impl_lint_pass!(Several => [\n    FIRST,\n    SECOND,\n]);";
+    assert_eq!(
+        parse_lint_passes(text),
+        [
+            ("Single".to_string(), vec!["clippy::single_lint".to_string()]),
+            (
+                "Several".to_string(),
+                vec!["clippy::first".to_string(), "clippy::second".to_string()]
+            ),
+        ]
+    );
+}
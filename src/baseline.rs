@@ -0,0 +1,162 @@
+//! Baseline files, used to hide the warnings a project already had when it started using a lint.
+//!
+//! A warning is identified by the lint, the file and a fingerprint of the source code the warning
+//! points at. Line numbers are not part of it, so editing unrelated code doesn't invalidate the
+//! baseline.
+//!
+//! `cargo-clippy` records the baselines, and `clippy-driver` hides the warnings they contain, so
+//! each of them only uses a part of this module.
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Default, Deserialize, Serialize)]
+pub struct Baseline {
+    version: u32,
+    warnings: Vec<BaselineEntry>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+struct BaselineEntry {
+    lint: String,
+    file: String,
+    fingerprint: String,
+    /// How many warnings with the same lint, file and fingerprint there are
+    count: usize,
+}
+
+impl Baseline {
+    /// Creates a baseline containing all of the given warnings, which are given as their lint
+    /// name, file and the source code they point at.
+    pub fn new<'a>(warnings: impl IntoIterator<Item = (&'a str, &'a str, &'a str)>) -> Self {
+        let mut counts = BTreeMap::<_, usize>::new();
+        for (lint, file, snippet) in warnings {
+            *counts
+                .entry((lint.to_string(), file.to_string(), fingerprint(snippet)))
+                .or_default() += 1;
+        }
+
+        Self {
+            version: BASELINE_VERSION,
+            warnings: counts
+                .into_iter()
+                .map(|((lint, file, fingerprint), count)| BaselineEntry {
+                    lint,
+                    file,
+                    fingerprint,
+                    count,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|e| format!("failed to read `{}`: {e}", path.display()))?;
+        let baseline: Self =
+            serde_json::from_str(&contents).map_err(|e| format!("failed to parse `{}`: {e}", path.display()))?;
+        if baseline.version != BASELINE_VERSION {
+            return Err(format!(
+                "`{}` has version {}, but only version {BASELINE_VERSION} is supported. \
+                Regenerate it with `--update-baseline`",
+                path.display(),
+                baseline.version
+            ));
+        }
+        Ok(baseline)
+    }
+
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let mut contents = serde_json::to_string_pretty(self)?;
+        contents.push('\n');
+        fs::write(path, contents)
+    }
+
+    /// Returns `true` if the warning is part of the baseline. Every entry in the baseline
+    /// suppresses as many warnings as it had when it was recorded.
+    pub fn suppress(&mut self, lint: &str, file: &str, snippet: &str) -> bool {
+        let fingerprint = fingerprint(snippet);
+        match self.warnings.iter_mut().find(|entry| {
+            entry.count > 0 && entry.lint == lint && entry.file == file && entry.fingerprint == fingerprint
+        }) {
+            Some(entry) => {
+                entry.count -= 1;
+                true
+            },
+            None => false,
+        }
+    }
+}
+
+/// Hashes the code a diagnostic points at with whitespace normalized, using FNV-1a so the
+/// result is stable across Rust versions and platforms.
+fn fingerprint(snippet: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for word in snippet.split_whitespace() {
+        for byte in word.bytes().chain([b' ']) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fingerprint, Baseline, BASELINE_VERSION};
+    use std::fs;
+
+    const FILE: &str = "src/lib.rs";
+
+    #[test]
+    fn test_fingerprint() {
+        assert_eq!(fingerprint("return x;"), fingerprint("return   x;"));
+        assert_eq!(fingerprint("if a {\n    b\n}"), fingerprint("if a {\n\t\tb\n        }"));
+        assert_ne!(fingerprint("return x;"), fingerprint("return y;"));
+        assert_ne!(fingerprint("ab"), fingerprint("a b"));
+    }
+
+    #[test]
+    fn test_survives_line_shifts() {
+        let mut baseline = Baseline::new([("needless_return", FILE, "return x;")]);
+        assert!(baseline.suppress("needless_return", FILE, "return\n    x;"));
+        assert!(!baseline.suppress("needless_return", FILE, "return y;"));
+        assert!(!baseline.suppress("needless_return", "src/main.rs", "return x;"));
+    }
+
+    #[test]
+    fn test_counts() {
+        let warning = ("needless_return", FILE, "return x;");
+        let mut baseline = Baseline::new([warning, warning]);
+        assert_eq!(baseline.warnings.len(), 1);
+        assert_eq!(baseline.warnings[0].count, 2);
+
+        assert!(baseline.suppress("needless_return", FILE, "return x;"));
+        assert!(baseline.suppress("needless_return", FILE, "return x;"));
+        assert!(!baseline.suppress("needless_return", FILE, "return x;"));
+        assert!(!baseline.suppress("let_and_return", FILE, "return x;"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let path = std::env::temp_dir().join(format!("clippy-baseline-{}.json", std::process::id()));
+        Baseline::new([("needless_return", FILE, "return x;")])
+            .write(&path)
+            .unwrap();
+        let mut read = Baseline::read(&path);
+
+        fs::write(&path, r#"{ "version": 0, "warnings": [] }"#).unwrap();
+        let old = Baseline::read(&path);
+        fs::remove_file(&path).unwrap();
+
+        let read = read.as_mut().unwrap();
+        assert_eq!(read.version, BASELINE_VERSION);
+        assert!(read.suppress("needless_return", FILE, "return x;"));
+        assert!(old.err().unwrap().contains("has version 0"));
+    }
+}
@@ -8,13 +8,14 @@ macro_rules! include_lint {
 
 macro_rules! docs {
     ($($lint_name: expr,)*) => {
-        pub fn explain(lint: &str) {
-            println!("{}", match lint {
+        /// Returns the markdown documentation of the lint with the given name
+        pub fn lint_docs(lint: &str) -> Option<&'static str> {
+            match lint {
                 $(
-                    $lint_name => include_lint!(concat!("docs/", concat!($lint_name, ".txt"))),
+                    $lint_name => Some(include_lint!(concat!("docs/", concat!($lint_name, ".txt")))),
                 )*
-                _ => "unknown lint",
-            })
+                _ => None,
+            }
         }
     }
 }
@@ -105,6 +106,9 @@ docs! {
     "derive_hash_xor_eq",
     "derive_ord_xor_partial_ord",
     "derive_partial_eq_without_eq",
+    "disallowed_dependencies",
+    "disallowed_imports",
+    "disallowed_macros",
     "disallowed_methods",
     "disallowed_names",
     "disallowed_script_idents",
@@ -11,22 +11,33 @@
 extern crate rustc_driver;
 extern crate rustc_errors;
 extern crate rustc_interface;
+extern crate rustc_lint;
 extern crate rustc_session;
 extern crate rustc_span;
 
-use rustc_interface::interface;
+use baseline::Baseline;
+use clippy_lints::add_allows::AllowAttribute;
+use clippy_lints::lint_timings::{self, RegisteredPasses};
+use rustc_driver::Compilation;
+use rustc_interface::{interface, Queries};
+use rustc_session::config::ErrorOutputType;
 use rustc_session::parse::ParseSess;
+use rustc_session::DiagnosticOutput;
+use rustc_span::def_id::LOCAL_CRATE;
 use rustc_span::symbol::Symbol;
 use rustc_tools_util::VersionInfo;
 
 use std::borrow::Cow;
 use std::env;
+use std::io::{self, Write};
 use std::ops::Deref;
 use std::panic;
 use std::path::{Path, PathBuf};
 use std::process::{exit, Command};
 use std::sync::LazyLock;
 
+mod baseline;
+
 /// If a command-line option matches `find_arg`, then apply the predicate `pred` on its value. If
 /// true, then return it. The parameter is assumed to be either `--arg=value` or `--arg value`.
 fn arg_value<'a, T: Deref<Target = str>>(
@@ -65,10 +76,14 @@ fn test_arg_value() {
 }
 
 fn track_clippy_args(parse_sess: &mut ParseSess, args_env_var: &Option<String>) {
-    parse_sess.env_depinfo.get_mut().insert((
-        Symbol::intern("CLIPPY_ARGS"),
-        args_env_var.as_deref().map(Symbol::intern),
-    ));
+    track_env_var(parse_sess, "CLIPPY_ARGS", args_env_var);
+}
+
+fn track_env_var(parse_sess: &mut ParseSess, name: &str, value: &Option<String>) {
+    parse_sess
+        .env_depinfo
+        .get_mut()
+        .insert((Symbol::intern(name), value.as_deref().map(Symbol::intern)));
 }
 
 struct DefaultCallbacks;
@@ -89,8 +104,69 @@ impl rustc_driver::Callbacks for RustcCallbacks {
     }
 }
 
+/// Writes the JSON diagnostics to stderr, without the suggestions of the diagnostics other than
+/// the warnings adding the attributes of `cargo clippy --fix --add-allows`.
+#[derive(Default)]
+struct AttributeSuggestionsOnly {
+    /// The start of a diagnostic that wasn't completely written yet
+    line: Vec<u8>,
+}
+
+impl Write for AttributeSuggestionsOnly {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.line.extend_from_slice(buf);
+        while let Some(end) = self.line.iter().position(|&byte| byte == b'\n') {
+            let line: Vec<u8> = self.line.drain(..=end).collect();
+            match serde_json::from_slice::<serde_json::Value>(&line) {
+                // Diagnostics, as opposed to the notifications about the emitted files
+                Ok(mut diag) if diag.get("spans").is_some() => {
+                    if !diag["message"]
+                        .as_str()
+                        .map_or(false, clippy_lints::add_allows::is_attribute_warning)
+                    {
+                        remove_suggestions(&mut diag);
+                    }
+                    writeln!(io::stderr(), "{diag}")?;
+                },
+                _ => io::stderr().write_all(&line)?,
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stderr().flush()
+    }
+}
+
+fn remove_suggestions(diag: &mut serde_json::Value) {
+    if let Some(spans) = diag["spans"].as_array_mut() {
+        for span in spans {
+            span["suggested_replacement"] = serde_json::Value::Null;
+            span["suggestion_applicability"] = serde_json::Value::Null;
+        }
+    }
+    if let Some(children) = diag["children"].as_array_mut() {
+        children.iter_mut().for_each(remove_suggestions);
+    }
+}
+
 struct ClippyCallbacks {
     clippy_args_var: Option<String>,
+    /// The value of `CLIPPY_PRINT_CONFIG`, which `cargo clippy --print-config` sets to a different
+    /// value on every run so that cargo doesn't skip crates it already checked.
+    print_config_var: Option<String>,
+    /// The attribute `cargo clippy --fix --add-allows` adds for the warnings, passed as
+    /// `--add-allows=allow|expect` and `--allow-reason=REASON` in `CLIPPY_ARGS`.
+    add_allows: Option<AllowAttribute>,
+    /// The value of `CLIPPY_LINT_TIMINGS`, the directory where the time spent in each lint pass is
+    /// written to
+    lint_timings_var: Option<String>,
+    /// The baseline file whose warnings are hidden, passed as `--baseline=PATH` in `CLIPPY_ARGS`
+    baseline: Option<PathBuf>,
+    /// Whether `cargo clippy --update-baseline` records the warnings, passed as `--update-baseline`
+    /// in `CLIPPY_ARGS`
+    update_baseline: bool,
 }
 
 impl rustc_driver::Callbacks for ClippyCallbacks {
@@ -99,9 +175,53 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
     fn config(&mut self, config: &mut interface::Config) {
         let previous = config.register_lints.take();
         let clippy_args_var = self.clippy_args_var.take();
+        let print_config_var = self.print_config_var.take();
+        let print_config = print_config_var.is_some();
+        let lint_timings_var = self.lint_timings_var.clone();
+        let lint_timings = lint_timings_var.is_some();
+        let conf_file = clippy_lints::lookup_conf_file();
+        // The levels from `clippy.toml` go first, so that the flags on the command line take
+        // precedence over them.
+        let conf = clippy_lints::read_conf(&conf_file);
+        config.opts.lint_opts.splice(0..0, conf.conf.lint_opts());
+        if let Some(attribute) = self.add_allows.take() {
+            clippy_lints::add_allows::enable(attribute);
+            // Denied lints must not stop the compilation before the attributes are suggested
+            config.opts.lint_cap = Some(rustc_lint::Level::Warn);
+            // `cargo fix` reads the JSON diagnostics, and must only apply the attributes
+            if let ErrorOutputType::Json { .. } = config.opts.error_format {
+                config.diagnostic_output = DiagnosticOutput::Raw(Box::<AttributeSuggestionsOnly>::default());
+            }
+        }
+        if self.update_baseline {
+            // Denied lints must not stop the compilation before all warnings are recorded
+            config.opts.lint_cap = Some(rustc_lint::Level::Warn);
+        }
+        if print_config {
+            // Only the configuration is shown, not the warnings
+            config.opts.lint_cap = Some(rustc_lint::Level::Allow);
+        }
+        let baseline_path = self.baseline.take();
+        let baseline_error = baseline_path.as_deref().and_then(|path| match Baseline::read(path) {
+            Ok(mut baseline) => {
+                clippy_lints::baseline::enable(move |lint, file, snippet| baseline.suppress(lint, file, snippet));
+                None
+            },
+            Err(e) => Some(e),
+        });
         config.parse_sess_created = Some(Box::new(move |parse_sess| {
             track_clippy_args(parse_sess, &clippy_args_var);
+            track_env_var(parse_sess, "CLIPPY_PRINT_CONFIG", &print_config_var);
+            track_env_var(parse_sess, "CLIPPY_LINT_TIMINGS", &lint_timings_var);
+            // Cargo checks the crate again when the baseline changes
+            if let Some(path) = &baseline_path {
+                parse_sess
+                    .file_depinfo
+                    .get_mut()
+                    .insert(Symbol::intern(&path.to_string_lossy()));
+            }
         }));
+        config.override_queries = Some(|_, providers, _| clippy_lints::override_queries(providers));
         config.register_lints = Some(Box::new(move |sess, lint_store| {
             // technically we're ~guaranteed that this is none but might as well call anything that
             // is there already. Certainly it can't hurt.
@@ -109,10 +229,20 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
                 (previous)(sess, lint_store);
             }
 
-            let conf = clippy_lints::read_conf(sess);
-            clippy_lints::register_plugins(lint_store, sess, &conf);
-            clippy_lints::register_pre_expansion_lints(lint_store, sess, &conf);
+            clippy_lints::report_conf_errors(sess, &conf_file, &conf);
+            if let Some(e) = &baseline_error {
+                sess.err(e);
+            }
+            if print_config {
+                clippy_lints::print_conf(sess, &conf);
+            }
+            let registered = RegisteredPasses::count(lint_store);
+            clippy_lints::register_plugins(lint_store, sess, &conf.conf);
+            clippy_lints::register_pre_expansion_lints(lint_store, sess, &conf.conf);
             clippy_lints::register_renamed(lint_store);
+            if lint_timings {
+                lint_timings::time_passes(lint_store, registered);
+            }
         }));
 
         // FIXME: #4825; This is required, because Clippy lints that are based on MIR have to be
@@ -121,6 +251,26 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
         // use for Clippy.
         config.opts.unstable_opts.mir_opt_level = Some(0);
     }
+
+    fn after_analysis<'tcx>(&mut self, _: &interface::Compiler, queries: &'tcx Queries<'tcx>) -> Compilation {
+        queries.global_ctxt().unwrap().peek_mut().enter(|tcx| {
+            clippy_lints::add_allows::suggest_attributes(tcx);
+            let crate_name = tcx.crate_name(LOCAL_CRATE);
+            let hidden = clippy_lints::baseline::hidden();
+            if hidden > 0 {
+                tcx.sess.note_without_error(&format!(
+                    "Clippy warnings of `{crate_name}` hidden by the baseline: {hidden}"
+                ));
+            }
+            if let Some(dir) = &self.lint_timings_var {
+                if let Err(e) = lint_timings::write(Path::new(dir), crate_name.as_str()) {
+                    tcx.sess
+                        .warn(&format!("failed to write the lint timings to `{dir}`: {e}"));
+                }
+            }
+        });
+        Compilation::Continue
+    }
 }
 
 fn display_help() {
@@ -288,6 +438,23 @@ pub fn main() {
             return rustc_driver::RunCompiler::new(&args, &mut DefaultCallbacks).run();
         }
 
+        // `cargo clippy --explain` shows the configuration of the project in the current directory,
+        // without checking a crate
+        if orig_args.iter().any(|a| a == "--print-clippy-conf") {
+            let conf_file = clippy_lints::lookup_conf_file();
+            let conf = clippy_lints::read_conf(&conf_file);
+            if let Err(e) = &conf_file {
+                eprintln!("error: error finding Clippy's configuration file: {e}");
+                exit(1);
+            }
+            if let Some(e) = conf.errors.first() {
+                eprintln!("error: error reading Clippy's configuration file: {e}");
+                exit(1);
+            }
+            print!("{}", clippy_lints::format_conf(&conf));
+            exit(0);
+        }
+
         if orig_args.iter().any(|a| a == "--version" || a == "-V") {
             let version_info = rustc_tools_util::get_version_info!();
             println!("{version_info}");
@@ -317,18 +484,40 @@ pub fn main() {
         };
 
         let mut no_deps = false;
+        let mut add_allows = None;
+        let mut allow_reason = None;
+        let mut baseline = None;
+        let mut update_baseline = false;
         let clippy_args_var = env::var("CLIPPY_ARGS").ok();
         let clippy_args = clippy_args_var
             .as_deref()
             .unwrap_or_default()
             .split("__CLIPPY_HACKERY__")
-            .filter_map(|s| match s {
-                "" => None,
-                "--no-deps" => {
-                    no_deps = true;
-                    None
-                },
-                _ => Some(s.to_string()),
+            .filter_map(|s| {
+                if let Some(name) = s.strip_prefix("--add-allows=") {
+                    add_allows = Some(name.to_string());
+                    return None;
+                }
+                if let Some(reason) = s.strip_prefix("--allow-reason=") {
+                    allow_reason = Some(reason.to_string());
+                    return None;
+                }
+                if let Some(path) = s.strip_prefix("--baseline=") {
+                    baseline = Some(PathBuf::from(path));
+                    return None;
+                }
+                match s {
+                    "" => None,
+                    "--no-deps" => {
+                        no_deps = true;
+                        None
+                    },
+                    "--update-baseline" => {
+                        update_baseline = true;
+                        None
+                    },
+                    _ => Some(s.to_string()),
+                }
             })
             .chain(vec!["--cfg".into(), r#"feature="cargo-clippy""#.into()])
             .collect::<Vec<String>>();
@@ -345,7 +534,23 @@ pub fn main() {
         let clippy_enabled = !cap_lints_allow && (!no_deps || in_primary_package);
         if clippy_enabled {
             args.extend(clippy_args);
-            rustc_driver::RunCompiler::new(&args, &mut ClippyCallbacks { clippy_args_var }).run()
+            let print_config_var = env::var("CLIPPY_PRINT_CONFIG").ok();
+            let lint_timings_var = env::var("CLIPPY_LINT_TIMINGS").ok();
+            rustc_driver::RunCompiler::new(
+                &args,
+                &mut ClippyCallbacks {
+                    clippy_args_var,
+                    print_config_var,
+                    lint_timings_var,
+                    baseline,
+                    update_baseline,
+                    add_allows: add_allows.map(|name| AllowAttribute {
+                        name,
+                        reason: allow_reason,
+                    }),
+                },
+            )
+            .run()
         } else {
             rustc_driver::RunCompiler::new(&args, &mut RustcCallbacks { clippy_args_var }).run()
         }
@@ -0,0 +1,245 @@
+//! `cargo clippy --explain`: prints the documentation of a lint rendered for the terminal, followed
+//! by the configuration options affecting the lint and their values for the current project.
+
+use crate::docs;
+use crate::lint_list::{self, ConfigInfo, LintInfo};
+use crate::markdown::{self, Style};
+use crate::metadata::GROUPS;
+use crate::ClippyCmd;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::process::Command;
+
+/// The groups enabled by `clippy::all`
+const ALL: [&str; 5] = ["correctness", "suspicious", "style", "complexity", "perf"];
+
+/// Prints the documentation of the lint or lint group with the given name (without the `clippy::`
+/// prefix). The renamed lints are resolved to their new name.
+pub fn explain(name: &str) {
+    let style = Style::detect();
+    if let Some(docs) = docs::lint_docs(name) {
+        print!("{}", markdown::render(docs, style));
+        let configs: Vec<_> = lint_list::configs_of(name).collect();
+        if !configs.is_empty() {
+            print!("{}", configuration(&configs, &ProjectConf::read(), style));
+        }
+    } else if name == "all" || GROUPS.contains(&name) {
+        print!("{}", group_summary(name, style));
+    } else if let Some(renamed) = lint_list::find_renamed(name) {
+        let renamed_to = format!("`clippy::{name}` has been renamed to `{}`", renamed.new_name);
+        if let Some(new_name) = renamed.new_name.strip_prefix("clippy::") {
+            println!("{}\n", markdown::render_inline(&renamed_to, style));
+            explain(new_name);
+        } else {
+            let renamed_to = format!("{renamed_to}, a rustc lint listed by `rustc -W help`");
+            println!("{}", markdown::render_inline(&renamed_to, style));
+        }
+    } else if let Some(deprecated) = lint_list::find_deprecated(name) {
+        let deprecated = format!("`clippy::{name}` has been deprecated: {}", deprecated.reason);
+        println!("{}", markdown::render_inline(&deprecated, style));
+    } else {
+        println!("unknown lint");
+    }
+}
+
+/// A table of the lints of a group, or of all the groups of `clippy::all`
+fn group_summary(group: &str, style: Style) -> String {
+    let lints: Vec<&LintInfo> = lint_list::LINTS
+        .iter()
+        .filter(|lint| lint.group == group || group == "all" && ALL.contains(&lint.group))
+        .collect();
+
+    let mut out = if group == "all" {
+        format!(
+            "`clippy::all` has the {} lints of the {} groups\n\n",
+            lints.len(),
+            ALL.map(|group| format!("`{group}`")).join(", ")
+        )
+    } else {
+        let level = lints.first().map_or("allow", |lint| lint.level);
+        format!(
+            "`clippy::{group}` has {} lints, set to `{level}` by default\n\n",
+            lints.len()
+        )
+    };
+    out = markdown::render_inline(&out, style);
+
+    let name_width = lints.iter().map(|lint| lint.name.len()).max().unwrap_or(0);
+    let group_width = lints.iter().map(|lint| lint.group.len()).max().unwrap_or(0);
+    let _ = writeln!(
+        out,
+        "{}",
+        style.heading(&format!(
+            "{:name_width$}  {:group_width$}  {:5}  description",
+            "name", "group", "level"
+        ))
+    );
+    for lint in lints {
+        let _ = writeln!(
+            out,
+            "{:name_width$}  {:group_width$}  {:5}  {}",
+            lint.name, lint.group, lint.level, lint.desc
+        );
+    }
+    out
+}
+
+fn configuration(configs: &[&ConfigInfo], project: &Result<ProjectConf, String>, style: Style) -> String {
+    let mut out = format!("\n{}\n", style.heading("Configuration"));
+    for config in configs {
+        let _ = writeln!(out, "\n{}: {}", style.code(config.name), config.ty);
+        for line in markdown::render(config.doc, style).lines() {
+            let _ = writeln!(out, "    {line}");
+        }
+        let _ = writeln!(out, "    default: {}", config.default);
+        let project = match project {
+            Ok(project) => project,
+            Err(_) => continue,
+        };
+        match project.values.get(config.name) {
+            Some((value, files)) => {
+                let _ = writeln!(out, "    current: {value} (set in {files})");
+            },
+            None if project.files.is_empty() => {
+                let _ = writeln!(out, "    current: default (no `clippy.toml` found)");
+            },
+            None => {
+                let _ = writeln!(out, "    current: default (not set in {})", project.files.join(", "));
+            },
+        }
+        for (paths, value) in &project.overrides {
+            if let Some(value) = value.get(config.name) {
+                let _ = writeln!(out, "    overridden to {value} for the files matching {paths}");
+            }
+        }
+    }
+    if let Err(error) = project {
+        let _ = writeln!(out, "\nwarning: failed to read the configuration: {error}");
+    }
+    out
+}
+
+/// The configuration Clippy uses for the project in the current directory, as printed by
+/// `clippy-driver --print-clippy-conf`.
+#[derive(Debug, Default, PartialEq)]
+struct ProjectConf {
+    /// The configuration files that were read, in backticks
+    files: Vec<String>,
+    /// The values set by the configuration files, with the files that set them
+    values: BTreeMap<String, (toml::Value, String)>,
+    /// The `path` and the values of each `[[overrides]]` table
+    overrides: Vec<(toml::Value, toml::value::Table)>,
+}
+
+impl ProjectConf {
+    /// Runs `clippy-driver --print-clippy-conf`, which looks up the configuration file from the
+    /// current directory and reads it along with the files it inherits from, without checking a
+    /// crate
+    fn read() -> Result<Self, String> {
+        let output = Command::new(ClippyCmd::path())
+            .arg("--print-clippy-conf")
+            .output()
+            .map_err(|e| format!("failed to run `clippy-driver`: {e}"))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(stderr
+                .lines()
+                .find_map(|line| line.strip_prefix("error: "))
+                .unwrap_or("`clippy-driver --print-clippy-conf` failed")
+                .to_string());
+        }
+        Self::parse(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Parses the output of `clippy-driver --print-clippy-conf`
+    fn parse(conf: &str) -> Result<Self, String> {
+        let mut project = Self::default();
+        let mut table: toml::value::Table = toml::from_str(conf).map_err(|e| e.to_string())?;
+        // Each value follows a comment with the files that set it, or `# default`
+        let mut files = None;
+        for line in conf.lines() {
+            if let Some(file) = line.strip_prefix("# Read ") {
+                project.files.push(file.to_string());
+            } else if let Some(from) = line.strip_prefix("# from ") {
+                files = Some(from);
+            } else if line == "# default" {
+                files = None;
+            } else if line.is_empty() || line.starts_with('#') {
+                continue;
+            } else if let Some(files) = files.take() {
+                let key = line
+                    .trim_start_matches('[')
+                    .split([' ', '.', ']'])
+                    .next()
+                    .unwrap_or_default();
+                if let Some(value) = table.remove(key) {
+                    project.values.insert(key.to_string(), (value, files.to_string()));
+                }
+            }
+        }
+
+        if let Some((toml::Value::Array(overrides), _)) = project.values.get("overrides") {
+            project.overrides = overrides
+                .iter()
+                .filter_map(|value| match value {
+                    toml::Value::Table(table) => {
+                        let mut table = table.clone();
+                        Some((table.remove("path")?, table))
+                    },
+                    _ => None,
+                })
+                .collect();
+        }
+        Ok(project)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_conf() {
+        let output = r#"# Read `/demo/clippy.toml`
+# Read `/clippy.toml`
+
+# from `/demo/clippy.toml`
+too-many-arguments-threshold = 4
+
+# default
+too-many-lines-threshold = 100
+
+# default
+# msrv is not set
+
+# from `/clippy.toml`, `/demo/clippy.toml`
+disallowed-names = ["toto", "tata"]
+
+# from `/demo/clippy.toml`
+[[overrides]]
+path = "tests/**"
+too-many-lines-threshold = 200
+
+[overrides.lints]
+unwrap_used = "allow"
+"#;
+        let project = ProjectConf::parse(output).unwrap();
+        assert_eq!(project.files, ["`/demo/clippy.toml`", "`/clippy.toml`"]);
+        assert_eq!(
+            project.values.keys().collect::<Vec<_>>(),
+            ["disallowed-names", "overrides", "too-many-arguments-threshold"]
+        );
+        let (value, files) = &project.values["disallowed-names"];
+        assert_eq!(value.to_string(), r#"["toto", "tata"]"#);
+        assert_eq!(files, "`/clippy.toml`, `/demo/clippy.toml`");
+        let (value, files) = &project.values["too-many-arguments-threshold"];
+        assert_eq!(value.to_string(), "4");
+        assert_eq!(files, "`/demo/clippy.toml`");
+        assert_eq!(project.overrides.len(), 1);
+        assert_eq!(project.overrides[0].0, toml::Value::from("tests/**"));
+        assert_eq!(
+            project.overrides[0].1.get("too-many-lines-threshold"),
+            Some(&toml::Value::from(200))
+        );
+    }
+}
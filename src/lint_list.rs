@@ -0,0 +1,1065 @@
+// autogenerated. Please look at /clippy_dev/src/update_lints.rs
+
+/// Static information about a single Clippy lint.
+pub struct LintInfo {
+    pub name: &'static str,
+    pub group: &'static str,
+    /// The level of the lint unless it's configured, `allow`, `warn` or `deny`
+    pub level: &'static str,
+    /// The version of Clippy that added the lint
+    pub version: &'static str,
+    /// The Rust version required by the lint, if it's affected by the `msrv` option
+    pub msrv: Option<&'static str>,
+    /// The short description of the lint, as in `declare_clippy_lint!`
+    pub desc: &'static str,
+}
+
+/// Static information about a `clippy.toml` option.
+pub struct ConfigInfo {
+    /// The name of the option, in `kebab-case`
+    pub name: &'static str,
+    pub ty: &'static str,
+    /// The default value, as the Rust expression in `define_Conf!`
+    pub default: &'static str,
+    pub doc: &'static str,
+    /// The lints affected by the option
+    pub lints: &'static [&'static str],
+}
+
+/// A lint removed from Clippy, from `clippy_lints/src/deprecated_lints.rs`.
+pub struct DeprecatedInfo {
+    pub name: &'static str,
+    pub reason: &'static str,
+}
+
+/// A lint that was renamed, from `clippy_lints/src/renamed_lints.rs`.
+pub struct RenamedInfo {
+    /// The old name, without the `clippy::` prefix
+    pub old_name: &'static str,
+    /// The new name, with the `clippy::` prefix unless the lint was moved to rustc
+    pub new_name: &'static str,
+}
+
+/// Returns the lint with the given name (without the `clippy::` prefix).
+pub fn find(name: &str) -> Option<&'static LintInfo> {
+    LINTS
+        .binary_search_by_key(&name, |lint| lint.name)
+        .ok()
+        .map(|index| &LINTS[index])
+}
+
+/// Returns the deprecated lint with the given name (without the `clippy::` prefix).
+pub fn find_deprecated(name: &str) -> Option<&'static DeprecatedInfo> {
+    DEPRECATED
+        .binary_search_by_key(&name, |lint| lint.name)
+        .ok()
+        .map(|index| &DEPRECATED[index])
+}
+
+/// Returns the renamed lint with the given old name (without the `clippy::` prefix).
+pub fn find_renamed(name: &str) -> Option<&'static RenamedInfo> {
+    RENAMED
+        .binary_search_by_key(&name, |lint| lint.old_name)
+        .ok()
+        .map(|index| &RENAMED[index])
+}
+
+/// Returns the highest applicability of the suggestions of the lint with the given name, like
+/// `MachineApplicable`, if it has suggestions.
+pub fn applicability(name: &str) -> Option<&'static str> {
+    APPLICABILITIES
+        .binary_search_by_key(&name, |&(lint, _)| lint)
+        .ok()
+        .map(|index| APPLICABILITIES[index].1)
+}
+
+/// Returns the configuration options affecting the lint with the given name.
+pub fn configs_of(name: &str) -> impl Iterator<Item = &'static ConfigInfo> + '_ {
+    CONFIGS.iter().filter(move |config| config.lints.contains(&name))
+}
+
+#[rustfmt::skip]
+pub static LINTS: &[LintInfo] = &[
+    LintInfo { name: "absurd_extreme_comparisons", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "a comparison with a maximum or minimum value that is always true or false" },
+    LintInfo { name: "alloc_instead_of_core", group: "restriction", level: "allow", version: "1.64.0", msrv: None, desc: "type is imported from alloc when available in core" },
+    LintInfo { name: "allow_attributes_without_reason", group: "restriction", level: "allow", version: "1.61.0", msrv: None, desc: "ensures that all `allow` and `expect` attributes have a reason" },
+    LintInfo { name: "almost_complete_letter_range", group: "suspicious", level: "warn", version: "1.63.0", msrv: None, desc: "almost complete letter range" },
+    LintInfo { name: "almost_swapped", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "`foo = bar; bar = foo` sequence" },
+    LintInfo { name: "approx_constant", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: Some("1.47.0"), desc: "the approximate of a known float constant (in `std::fXX::consts`)" },
+    LintInfo { name: "arithmetic_side_effects", group: "restriction", level: "allow", version: "1.64.0", msrv: None, desc: "any arithmetic expression that can cause side effects like overflows or panics" },
+    LintInfo { name: "as_conversions", group: "restriction", level: "allow", version: "1.41.0", msrv: None, desc: "using a potentially dangerous silent `as` conversion" },
+    LintInfo { name: "as_underscore", group: "restriction", level: "allow", version: "1.63.0", msrv: None, desc: "detects `as _` conversion" },
+    LintInfo { name: "assertions_on_constants", group: "style", level: "warn", version: "1.34.0", msrv: None, desc: "`assert!(true)` / `assert!(false)` will be optimized out by the compiler, and should probably be replaced by a `panic!()` or `unreachable!()`" },
+    LintInfo { name: "assertions_on_result_states", group: "restriction", level: "allow", version: "1.64.0", msrv: None, desc: "`assert!(r.is_ok())`/`assert!(r.is_err())` gives worse error message than directly calling `r.unwrap()`/`r.unwrap_err()`" },
+    LintInfo { name: "assign_op_pattern", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "assigning the result of an operation on a variable to that same variable" },
+    LintInfo { name: "async_yields_async", group: "correctness", level: "deny", version: "1.48.0", msrv: None, desc: "async blocks that return a type that can be awaited" },
+    LintInfo { name: "await_holding_invalid_type", group: "suspicious", level: "warn", version: "1.62.0", msrv: None, desc: "holding a type across an await point which is not allowed to be held as per the configuration" },
+    LintInfo { name: "await_holding_lock", group: "suspicious", level: "warn", version: "1.45.0", msrv: None, desc: "inside an async function, holding a `MutexGuard` while calling `await`" },
+    LintInfo { name: "await_holding_refcell_ref", group: "suspicious", level: "warn", version: "1.49.0", msrv: None, desc: "inside an async function, holding a `RefCell` ref while calling `await`" },
+    LintInfo { name: "bad_bit_mask", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "expressions of the form `_ & mask == select` that will only ever return `true` or `false`" },
+    LintInfo { name: "bind_instead_of_map", group: "complexity", level: "warn", version: "1.45.0", msrv: None, desc: "using `Option.and_then(|x| Some(y))`, which is more succinctly expressed as `map(|x| y)`" },
+    LintInfo { name: "blanket_clippy_restriction_lints", group: "suspicious", level: "warn", version: "1.47.0", msrv: None, desc: "enabling the complete restriction group" },
+    LintInfo { name: "blocks_in_if_conditions", group: "style", level: "warn", version: "1.45.0", msrv: None, desc: "useless or complex blocks that can be eliminated in conditions" },
+    LintInfo { name: "bool_assert_comparison", group: "style", level: "warn", version: "1.53.0", msrv: None, desc: "Using a boolean as comparison value in an assert_* macro when there is no need" },
+    LintInfo { name: "bool_comparison", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "comparing a variable to a boolean, e.g., `if x == true` or `if x != true`" },
+    LintInfo { name: "bool_to_int_with_if", group: "style", level: "warn", version: "1.65.0", msrv: None, desc: "using if to convert bool to int" },
+    LintInfo { name: "borrow_as_ptr", group: "pedantic", level: "allow", version: "1.60.0", msrv: Some("1.51.0"), desc: "borrowing just to cast to a raw pointer" },
+    LintInfo { name: "borrow_deref_ref", group: "complexity", level: "warn", version: "1.63.0", msrv: None, desc: "deref on an immutable reference returns the same type as itself" },
+    LintInfo { name: "borrow_interior_mutable_const", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "referencing `const` with interior mutability" },
+    LintInfo { name: "borrowed_box", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "a borrow of a boxed type" },
+    LintInfo { name: "box_collection", group: "perf", level: "warn", version: "1.57.0", msrv: None, desc: "usage of `Box<Vec<T>>`, vector elements are already on the heap" },
+    LintInfo { name: "boxed_local", group: "perf", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `Box<T>` where unnecessary" },
+    LintInfo { name: "branches_sharing_code", group: "nursery", level: "allow", version: "1.53.0", msrv: None, desc: "`if` statement with shared code in all blocks" },
+    LintInfo { name: "builtin_type_shadow", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "shadowing a builtin type" },
+    LintInfo { name: "bytes_count_to_len", group: "complexity", level: "warn", version: "1.62.0", msrv: None, desc: "Using `bytes().count()` when `len()` performs the same functionality" },
+    LintInfo { name: "bytes_nth", group: "style", level: "warn", version: "1.52.0", msrv: None, desc: "replace `.bytes().nth()` with `.as_bytes().get()`" },
+    LintInfo { name: "cargo_common_metadata", group: "cargo", level: "allow", version: "1.32.0", msrv: None, desc: "common metadata is defined in `Cargo.toml`" },
+    LintInfo { name: "case_sensitive_file_extension_comparisons", group: "pedantic", level: "allow", version: "1.51.0", msrv: None, desc: "Checks for calls to ends_with with case-sensitive file extensions" },
+    LintInfo { name: "cast_abs_to_unsigned", group: "suspicious", level: "warn", version: "1.62.0", msrv: Some("1.51.0"), desc: "casting the result of `abs()` to an unsigned integer can panic" },
+    LintInfo { name: "cast_enum_constructor", group: "suspicious", level: "warn", version: "1.61.0", msrv: None, desc: "casts from an enum tuple constructor to an integer" },
+    LintInfo { name: "cast_enum_truncation", group: "suspicious", level: "warn", version: "1.61.0", msrv: None, desc: "casts from an enum type to an integral type which will truncate the value" },
+    LintInfo { name: "cast_lossless", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "casts using `as` that are known to be lossless, e.g., `x as u64` where `x: u8`" },
+    LintInfo { name: "cast_possible_truncation", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "casts that may cause truncation of the value, e.g., `x as u8` where `x: u32`, or `x as i32` where `x: f32`" },
+    LintInfo { name: "cast_possible_wrap", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "casts that may cause wrapping around the value, e.g., `x as i32` where `x: u32` and `x > i32::MAX`" },
+    LintInfo { name: "cast_precision_loss", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "casts that cause loss of precision, e.g., `x as f32` where `x: u64`" },
+    LintInfo { name: "cast_ptr_alignment", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "cast from a pointer to a more-strictly-aligned pointer" },
+    LintInfo { name: "cast_ref_to_mut", group: "correctness", level: "deny", version: "1.33.0", msrv: None, desc: "a cast of reference to a mutable pointer" },
+    LintInfo { name: "cast_sign_loss", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "casts from signed types to unsigned types, e.g., `x as u32` where `x: i32`" },
+    LintInfo { name: "cast_slice_different_sizes", group: "correctness", level: "deny", version: "1.61.0", msrv: None, desc: "casting using `as` between raw pointers to slices of types with different sizes" },
+    LintInfo { name: "cast_slice_from_raw_parts", group: "suspicious", level: "warn", version: "1.64.0", msrv: None, desc: "casting a slice created from a pointer and length to a slice pointer" },
+    LintInfo { name: "char_lit_as_u8", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "casting a character literal to `u8` truncates" },
+    LintInfo { name: "chars_last_cmp", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `.chars().last()` or `.chars().next_back()` to check if a string ends with a char" },
+    LintInfo { name: "chars_next_cmp", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `.chars().next()` to check if a string starts with a char" },
+    LintInfo { name: "checked_conversions", group: "pedantic", level: "allow", version: "1.37.0", msrv: Some("1.34.0"), desc: "`try_from` could replace manual bounds checking when casting" },
+    LintInfo { name: "clone_double_ref", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "using `clone` on `&&T`" },
+    LintInfo { name: "clone_on_copy", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `clone` on a `Copy` type" },
+    LintInfo { name: "clone_on_ref_ptr", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "using 'clone' on a ref-counted pointer" },
+    LintInfo { name: "cloned_instead_of_copied", group: "pedantic", level: "allow", version: "1.53.0", msrv: Some("1.36.0"), desc: "used `cloned` where `copied` could be used instead" },
+    LintInfo { name: "cmp_nan", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "comparisons to `NAN`, which will always return false, probably not intended" },
+    LintInfo { name: "cmp_null", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "comparing a pointer to a null pointer, suggesting to use `.is_null()` instead" },
+    LintInfo { name: "cmp_owned", group: "perf", level: "warn", version: "pre 1.29.0", msrv: None, desc: "creating owned instances for comparing with others, e.g., `x == \\\"foo\\\".to_string()`" },
+    LintInfo { name: "cognitive_complexity", group: "nursery", level: "allow", version: "1.35.0", msrv: None, desc: "functions that should be split up into multiple functions" },
+    LintInfo { name: "collapsible_else_if", group: "style", level: "warn", version: "1.51.0", msrv: None, desc: "nested `else`-`if` expressions that can be collapsed (e.g., `else { if x { ... } }`)" },
+    LintInfo { name: "collapsible_if", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "nested `if`s that can be collapsed (e.g., `if x { if y { ... } }`" },
+    LintInfo { name: "collapsible_match", group: "style", level: "warn", version: "1.50.0", msrv: None, desc: "Nested `match` or `if let` expressions where the patterns may be \\\"collapsed\\\" together." },
+    LintInfo { name: "collapsible_str_replace", group: "perf", level: "warn", version: "1.64.0", msrv: None, desc: "collapse consecutive calls to str::replace (2 or more) into a single call" },
+    LintInfo { name: "comparison_chain", group: "style", level: "warn", version: "1.40.0", msrv: None, desc: "`if`s that can be rewritten with `match` and `cmp`" },
+    LintInfo { name: "comparison_to_empty", group: "style", level: "warn", version: "1.49.0", msrv: None, desc: "checking `x == \\\"\\\"` or `x == []` (or similar) when `.is_empty()` could be used instead" },
+    LintInfo { name: "copy_iterator", group: "pedantic", level: "allow", version: "1.30.0", msrv: None, desc: "implementing `Iterator` on a `Copy` type" },
+    LintInfo { name: "crate_in_macro_def", group: "suspicious", level: "warn", version: "1.62.0", msrv: None, desc: "using `crate` in a macro definition" },
+    LintInfo { name: "create_dir", group: "restriction", level: "allow", version: "1.48.0", msrv: None, desc: "calling `std::fs::create_dir` instead of `std::fs::create_dir_all`" },
+    LintInfo { name: "crosspointer_transmute", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "transmutes that have to or from types that are a pointer to the other" },
+    LintInfo { name: "dbg_macro", group: "restriction", level: "allow", version: "1.34.0", msrv: None, desc: "`dbg!` macro is intended as a debugging tool" },
+    LintInfo { name: "debug_assert_with_mut_call", group: "nursery", level: "allow", version: "1.40.0", msrv: None, desc: "mutable arguments in `debug_assert{,_ne,_eq}!`" },
+    LintInfo { name: "decimal_literal_representation", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "using decimal representation when hexadecimal would be better" },
+    LintInfo { name: "declare_interior_mutable_const", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "declaring `const` with interior mutability" },
+    LintInfo { name: "default_instead_of_iter_empty", group: "style", level: "warn", version: "1.64.0", msrv: None, desc: "check `std::iter::Empty::default()` and replace with `std::iter::empty()`" },
+    LintInfo { name: "default_numeric_fallback", group: "restriction", level: "allow", version: "1.52.0", msrv: None, desc: "usage of unconstrained numeric literals which may cause default numeric fallback." },
+    LintInfo { name: "default_trait_access", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "checks for literal calls to `Default::default()`" },
+    LintInfo { name: "default_union_representation", group: "restriction", level: "allow", version: "1.60.0", msrv: None, desc: "unions without a `#[repr(C)]` attribute" },
+    LintInfo { name: "deprecated_cfg_attr", group: "complexity", level: "warn", version: "1.32.0", msrv: Some("1.30.0"), desc: "usage of `cfg_attr(rustfmt)` instead of tool attributes" },
+    LintInfo { name: "deprecated_semver", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "use of `#[deprecated(since = \\\"x\\\")]` where x is not semver" },
+    LintInfo { name: "deref_addrof", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "use of `*&` or `*&mut` in an expression" },
+    LintInfo { name: "deref_by_slicing", group: "restriction", level: "allow", version: "1.61.0", msrv: None, desc: "slicing instead of dereferencing" },
+    LintInfo { name: "derivable_impls", group: "complexity", level: "warn", version: "1.57.0", msrv: None, desc: "manual implementation of the `Default` trait which is equal to a derive" },
+    LintInfo { name: "derive_hash_xor_eq", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "deriving `Hash` but implementing `PartialEq` explicitly" },
+    LintInfo { name: "derive_ord_xor_partial_ord", group: "correctness", level: "deny", version: "1.47.0", msrv: None, desc: "deriving `Ord` but implementing `PartialOrd` explicitly" },
+    LintInfo { name: "derive_partial_eq_without_eq", group: "nursery", level: "allow", version: "1.63.0", msrv: None, desc: "deriving `PartialEq` on a type that can implement `Eq`, without implementing `Eq`" },
+    LintInfo { name: "disallowed_dependencies", group: "style", level: "warn", version: "1.65.0", msrv: None, desc: "dependencies on disallowed crates" },
+    LintInfo { name: "disallowed_imports", group: "style", level: "warn", version: "1.65.0", msrv: None, desc: "use of a disallowed crate or module" },
+    LintInfo { name: "disallowed_macros", group: "style", level: "warn", version: "1.65.0", msrv: None, desc: "use of a disallowed macro" },
+    LintInfo { name: "disallowed_methods", group: "style", level: "warn", version: "1.49.0", msrv: None, desc: "use of a disallowed method call" },
+    LintInfo { name: "disallowed_names", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "usage of a disallowed/placeholder name" },
+    LintInfo { name: "disallowed_script_idents", group: "restriction", level: "allow", version: "1.55.0", msrv: None, desc: "usage of non-allowed Unicode scripts" },
+    LintInfo { name: "disallowed_types", group: "style", level: "warn", version: "1.55.0", msrv: None, desc: "use of disallowed types" },
+    LintInfo { name: "diverging_sub_expression", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "whether an expression contains a diverging sub expression" },
+    LintInfo { name: "doc_link_with_quotes", group: "pedantic", level: "allow", version: "1.63.0", msrv: None, desc: "possible typo for an intra-doc link" },
+    LintInfo { name: "doc_markdown", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "presence of `_`, `::` or camel-case outside backticks in documentation" },
+    LintInfo { name: "double_comparisons", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "unnecessary double comparisons that can be simplified" },
+    LintInfo { name: "double_must_use", group: "style", level: "warn", version: "1.40.0", msrv: None, desc: "`#[must_use]` attribute on a `#[must_use]`-returning function / method" },
+    LintInfo { name: "double_neg", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "`--x`, which is a double negation of `x` and not a pre-decrement as in C/C++" },
+    LintInfo { name: "double_parens", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "Warn on unnecessary double parentheses" },
+    LintInfo { name: "drop_copy", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "calls to `std::mem::drop` with a value that implements Copy" },
+    LintInfo { name: "drop_non_drop", group: "suspicious", level: "warn", version: "1.62.0", msrv: None, desc: "call to `std::mem::drop` with a value which does not implement `Drop`" },
+    LintInfo { name: "drop_ref", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "calls to `std::mem::drop` with a reference instead of an owned value" },
+    LintInfo { name: "duplicate_mod", group: "suspicious", level: "warn", version: "1.63.0", msrv: None, desc: "file loaded as module multiple times" },
+    LintInfo { name: "duplicate_underscore_argument", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "function arguments having names which only differ by an underscore" },
+    LintInfo { name: "duration_subsec", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "checks for calculation of subsecond microseconds or milliseconds" },
+    LintInfo { name: "else_if_without_else", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "`if` expression with an `else if`, but without a final `else` branch" },
+    LintInfo { name: "empty_drop", group: "restriction", level: "allow", version: "1.62.0", msrv: None, desc: "empty `Drop` implementations" },
+    LintInfo { name: "empty_enum", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "enum with no variants" },
+    LintInfo { name: "empty_line_after_outer_attr", group: "nursery", level: "allow", version: "pre 1.29.0", msrv: None, desc: "empty line after outer attribute" },
+    LintInfo { name: "empty_loop", group: "suspicious", level: "warn", version: "pre 1.29.0", msrv: None, desc: "empty `loop {}`, which should block or sleep" },
+    LintInfo { name: "empty_structs_with_brackets", group: "restriction", level: "allow", version: "1.62.0", msrv: None, desc: "finds struct declarations with empty brackets" },
+    LintInfo { name: "enum_clike_unportable_variant", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "C-like enums that are `repr(isize/usize)` and have values that don't fit into an `i32`" },
+    LintInfo { name: "enum_glob_use", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "use items that import all variants of an enum" },
+    LintInfo { name: "enum_variant_names", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "enums where all variants share a prefix/postfix" },
+    LintInfo { name: "eq_op", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "equal operands on both sides of a comparison or bitwise combination (e.g., `x == x`)" },
+    LintInfo { name: "equatable_if_let", group: "nursery", level: "allow", version: "1.57.0", msrv: None, desc: "using pattern matching instead of equality" },
+    LintInfo { name: "erasing_op", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "using erasing operations, e.g., `x * 0` or `y & 0`" },
+    LintInfo { name: "err_expect", group: "style", level: "warn", version: "1.62.0", msrv: Some("1.17.0"), desc: "using `.err().expect()` when `.expect_err()` can be used" },
+    LintInfo { name: "excessive_precision", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "excessive precision for float literal" },
+    LintInfo { name: "exhaustive_enums", group: "restriction", level: "allow", version: "1.51.0", msrv: None, desc: "detects exported enums that have not been marked #[non_exhaustive]" },
+    LintInfo { name: "exhaustive_structs", group: "restriction", level: "allow", version: "1.51.0", msrv: None, desc: "detects exported structs that have not been marked #[non_exhaustive]" },
+    LintInfo { name: "exit", group: "restriction", level: "allow", version: "1.41.0", msrv: None, desc: "`std::process::exit` is called, terminating the program" },
+    LintInfo { name: "expect_fun_call", group: "perf", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using any `expect` method with a function call" },
+    LintInfo { name: "expect_used", group: "restriction", level: "allow", version: "1.45.0", msrv: None, desc: "using `.expect()` on `Result` or `Option`, which might be better handled" },
+    LintInfo { name: "expl_impl_clone_on_copy", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "implementing `Clone` explicitly on `Copy` types" },
+    LintInfo { name: "explicit_auto_deref", group: "complexity", level: "warn", version: "1.64.0", msrv: None, desc: "dereferencing when the compiler would automatically dereference" },
+    LintInfo { name: "explicit_counter_loop", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "for-looping with an explicit counter when `_.enumerate()` would do" },
+    LintInfo { name: "explicit_deref_methods", group: "pedantic", level: "allow", version: "1.44.0", msrv: None, desc: "Explicit use of deref or deref_mut method while not in a method chain." },
+    LintInfo { name: "explicit_into_iter_loop", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "for-looping over `_.into_iter()` when `_` would do" },
+    LintInfo { name: "explicit_iter_loop", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "for-looping over `_.iter()` or `_.iter_mut()` when `&_` or `&mut _` would do" },
+    LintInfo { name: "explicit_write", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using the `write!()` family of functions instead of the `print!()` family of functions, when using the latter would work" },
+    LintInfo { name: "extend_with_drain", group: "perf", level: "warn", version: "1.55.0", msrv: None, desc: "using vec.append(&mut vec) to move the full range of a vector to another" },
+    LintInfo { name: "extra_unused_lifetimes", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "unused lifetimes in function definitions" },
+    LintInfo { name: "fallible_impl_from", group: "nursery", level: "allow", version: "pre 1.29.0", msrv: None, desc: "Warn on impls of `From<..>` that contain `panic!()` or `unwrap()`" },
+    LintInfo { name: "field_reassign_with_default", group: "style", level: "warn", version: "1.49.0", msrv: None, desc: "binding initialized with Default should have its fields set in the initializer" },
+    LintInfo { name: "filetype_is_file", group: "restriction", level: "allow", version: "1.42.0", msrv: None, desc: "`FileType::is_file` is not recommended to test for readable file type" },
+    LintInfo { name: "filter_map_identity", group: "complexity", level: "warn", version: "1.52.0", msrv: None, desc: "call to `filter_map` where `flatten` is sufficient" },
+    LintInfo { name: "filter_map_next", group: "pedantic", level: "allow", version: "1.36.0", msrv: Some("1.30.0"), desc: "using combination of `filter_map` and `next` which can usually be written as a single method call" },
+    LintInfo { name: "filter_next", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `filter(p).next()`, which is more succinctly expressed as `.find(p)`" },
+    LintInfo { name: "flat_map_identity", group: "complexity", level: "warn", version: "1.39.0", msrv: None, desc: "call to `flat_map` where `flatten` is sufficient" },
+    LintInfo { name: "flat_map_option", group: "pedantic", level: "allow", version: "1.53.0", msrv: None, desc: "used `flat_map` where `filter_map` could be used instead" },
+    LintInfo { name: "float_arithmetic", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "any floating-point arithmetic statement" },
+    LintInfo { name: "float_cmp", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "using `==` or `!=` on float values instead of comparing difference with an epsilon" },
+    LintInfo { name: "float_cmp_const", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "using `==` or `!=` on float constants instead of comparing difference with an epsilon" },
+    LintInfo { name: "float_equality_without_abs", group: "suspicious", level: "warn", version: "1.48.0", msrv: None, desc: "float equality check without `.abs()`" },
+    LintInfo { name: "fn_address_comparisons", group: "correctness", level: "deny", version: "1.44.0", msrv: None, desc: "comparison with an address of a function item" },
+    LintInfo { name: "fn_params_excessive_bools", group: "pedantic", level: "allow", version: "1.43.0", msrv: None, desc: "using too many bools in function parameters" },
+    LintInfo { name: "fn_to_numeric_cast", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "casting a function pointer to a numeric type other than usize" },
+    LintInfo { name: "fn_to_numeric_cast_any", group: "restriction", level: "allow", version: "1.58.0", msrv: None, desc: "casting a function pointer to any integer type" },
+    LintInfo { name: "fn_to_numeric_cast_with_truncation", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "casting a function pointer to a numeric type not wide enough to store the address" },
+    LintInfo { name: "for_kv_map", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "looping on a map using `iter` when `keys` or `values` would do" },
+    LintInfo { name: "for_loops_over_fallibles", group: "suspicious", level: "warn", version: "1.45.0", msrv: None, desc: "for-looping over an `Option` or a `Result`, which is more clearly expressed as an `if let`" },
+    LintInfo { name: "forget_copy", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "calls to `std::mem::forget` with a value that implements Copy" },
+    LintInfo { name: "forget_non_drop", group: "suspicious", level: "warn", version: "1.62.0", msrv: None, desc: "call to `std::mem::forget` with a value which does not implement `Drop`" },
+    LintInfo { name: "forget_ref", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "calls to `std::mem::forget` with a reference instead of an owned value" },
+    LintInfo { name: "format_in_format_args", group: "perf", level: "warn", version: "1.58.0", msrv: None, desc: "`format!` used in a macro that does formatting" },
+    LintInfo { name: "format_push_string", group: "restriction", level: "allow", version: "1.62.0", msrv: None, desc: "`format!(..)` appended to existing `String`" },
+    LintInfo { name: "from_iter_instead_of_collect", group: "pedantic", level: "allow", version: "1.49.0", msrv: None, desc: "use `.collect()` instead of `::from_iter()`" },
+    LintInfo { name: "from_over_into", group: "style", level: "warn", version: "1.51.0", msrv: Some("1.41.0"), desc: "Warns on implementations of `Into<..>` to use `From<..>`" },
+    LintInfo { name: "from_str_radix_10", group: "style", level: "warn", version: "1.52.0", msrv: None, desc: "from_str_radix with radix 10" },
+    LintInfo { name: "future_not_send", group: "nursery", level: "allow", version: "1.44.0", msrv: None, desc: "public Futures must be Send" },
+    LintInfo { name: "get_first", group: "style", level: "warn", version: "1.63.0", msrv: None, desc: "Using `x.get(0)` when `x.first()` is simpler" },
+    LintInfo { name: "get_last_with_len", group: "complexity", level: "warn", version: "1.37.0", msrv: None, desc: "Using `x.get(x.len() - 1)` when `x.last()` is correct and simpler" },
+    LintInfo { name: "get_unwrap", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "using `.get().unwrap()` or `.get_mut().unwrap()` when using `[]` would work instead" },
+    LintInfo { name: "identity_op", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using identity operations, e.g., `x + 0` or `y / 1`" },
+    LintInfo { name: "if_let_mutex", group: "correctness", level: "deny", version: "1.45.0", msrv: None, desc: "locking a `Mutex` in an `if let` block can cause deadlocks" },
+    LintInfo { name: "if_not_else", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "`if` branches that could be swapped so no negation operation is necessary on the condition" },
+    LintInfo { name: "if_same_then_else", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "`if` with the same `then` and `else` blocks" },
+    LintInfo { name: "if_then_some_else_none", group: "restriction", level: "allow", version: "1.53.0", msrv: Some("1.62.0"), desc: "Finds if-else that could be written using either `bool::then` or `bool::then_some`" },
+    LintInfo { name: "ifs_same_cond", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "consecutive `if`s with the same condition" },
+    LintInfo { name: "implicit_clone", group: "pedantic", level: "allow", version: "1.52.0", msrv: None, desc: "implicitly cloning a value by invoking a function on its dereferenced type" },
+    LintInfo { name: "implicit_hasher", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "missing generalization over different hashers" },
+    LintInfo { name: "implicit_return", group: "restriction", level: "allow", version: "1.33.0", msrv: None, desc: "use a return statement like `return expr` instead of an expression" },
+    LintInfo { name: "implicit_saturating_sub", group: "pedantic", level: "allow", version: "1.44.0", msrv: None, desc: "Perform saturating subtraction instead of implicitly checking lower bound of data type" },
+    LintInfo { name: "imprecise_flops", group: "nursery", level: "allow", version: "1.43.0", msrv: None, desc: "usage of imprecise floating point operations" },
+    LintInfo { name: "inconsistent_digit_grouping", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "integer literals with digits grouped inconsistently" },
+    LintInfo { name: "inconsistent_struct_constructor", group: "pedantic", level: "allow", version: "1.52.0", msrv: None, desc: "the order of the field init shorthand is inconsistent with the order in the struct definition" },
+    LintInfo { name: "index_refutable_slice", group: "nursery", level: "allow", version: "1.59.0", msrv: Some("1.42.0"), desc: "avoid indexing on slices which could be destructed" },
+    LintInfo { name: "indexing_slicing", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "indexing/slicing usage" },
+    LintInfo { name: "ineffective_bit_mask", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "expressions where a bit mask will be rendered useless by a comparison, e.g., `(x | 1) > 2`" },
+    LintInfo { name: "inefficient_to_string", group: "pedantic", level: "allow", version: "1.40.0", msrv: None, desc: "using `to_string` on `&&T` where `T: ToString`" },
+    LintInfo { name: "infallible_destructuring_match", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "a `match` statement with a single infallible arm instead of a `let`" },
+    LintInfo { name: "infinite_iter", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "infinite iteration" },
+    LintInfo { name: "inherent_to_string", group: "style", level: "warn", version: "1.38.0", msrv: None, desc: "type implements inherent method `to_string()`, but should instead implement the `Display` trait" },
+    LintInfo { name: "inherent_to_string_shadow_display", group: "correctness", level: "deny", version: "1.38.0", msrv: None, desc: "type implements inherent method `to_string()`, which gets shadowed by the implementation of the `Display` trait" },
+    LintInfo { name: "init_numbered_fields", group: "style", level: "warn", version: "1.59.0", msrv: None, desc: "numbered fields in tuple struct initializer" },
+    LintInfo { name: "inline_always", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "use of `#[inline(always)]`" },
+    LintInfo { name: "inline_asm_x86_att_syntax", group: "restriction", level: "allow", version: "1.49.0", msrv: None, desc: "prefer Intel x86 assembly syntax" },
+    LintInfo { name: "inline_asm_x86_intel_syntax", group: "restriction", level: "allow", version: "1.49.0", msrv: None, desc: "prefer AT&T x86 assembly syntax" },
+    LintInfo { name: "inline_fn_without_body", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "use of `#[inline]` on trait methods without bodies" },
+    LintInfo { name: "inspect_for_each", group: "complexity", level: "warn", version: "1.51.0", msrv: None, desc: "using `.inspect().for_each()`, which can be replaced with `.for_each()`" },
+    LintInfo { name: "int_plus_one", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "instead of using `x >= y + 1`, use `x > y`" },
+    LintInfo { name: "integer_arithmetic", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "any integer arithmetic expression which could overflow or panic" },
+    LintInfo { name: "integer_division", group: "restriction", level: "allow", version: "1.37.0", msrv: None, desc: "integer division may cause loss of precision" },
+    LintInfo { name: "into_iter_on_ref", group: "style", level: "warn", version: "1.32.0", msrv: None, desc: "using `.into_iter()` on a reference" },
+    LintInfo { name: "invalid_null_ptr_usage", group: "correctness", level: "deny", version: "1.53.0", msrv: None, desc: "invalid usage of a null pointer, suggesting `NonNull::dangling()` instead" },
+    LintInfo { name: "invalid_regex", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "invalid regular expressions" },
+    LintInfo { name: "invalid_upcast_comparisons", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "a comparison involving an upcast which is always true or false" },
+    LintInfo { name: "invalid_utf8_in_unchecked", group: "correctness", level: "deny", version: "1.64.0", msrv: None, desc: "using a non UTF-8 literal in `std::std::from_utf8_unchecked`" },
+    LintInfo { name: "invisible_characters", group: "correctness", level: "deny", version: "1.49.0", msrv: None, desc: "using an invisible character in a string literal, which is confusing" },
+    LintInfo { name: "is_digit_ascii_radix", group: "style", level: "warn", version: "1.62.0", msrv: None, desc: "use of `char::is_digit(..)` with literal radix of 10 or 16" },
+    LintInfo { name: "items_after_statements", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "blocks where an item comes after a statement" },
+    LintInfo { name: "iter_cloned_collect", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `.cloned().collect()` on slice to create a `Vec`" },
+    LintInfo { name: "iter_count", group: "complexity", level: "warn", version: "1.52.0", msrv: None, desc: "replace `.iter().count()` with `.len()`" },
+    LintInfo { name: "iter_kv_map", group: "complexity", level: "warn", version: "1.65.0", msrv: None, desc: "iterating on map using `iter` when `keys` or `values` would do" },
+    LintInfo { name: "iter_next_loop", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "for-looping over `_.next()` which is probably not intended" },
+    LintInfo { name: "iter_next_slice", group: "style", level: "warn", version: "1.46.0", msrv: None, desc: "using `.iter().next()` on a sliced array, which can be shortened to just `.get()`" },
+    LintInfo { name: "iter_not_returning_iterator", group: "pedantic", level: "allow", version: "1.57.0", msrv: None, desc: "methods named `iter` or `iter_mut` that do not return an `Iterator`" },
+    LintInfo { name: "iter_nth", group: "perf", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `.iter().nth()` on a standard library type with O(1) element access" },
+    LintInfo { name: "iter_nth_zero", group: "style", level: "warn", version: "1.42.0", msrv: None, desc: "replace `iter.nth(0)` with `iter.next()`" },
+    LintInfo { name: "iter_on_empty_collections", group: "nursery", level: "allow", version: "1.64.0", msrv: None, desc: "Iterator for empty array" },
+    LintInfo { name: "iter_on_single_items", group: "nursery", level: "allow", version: "1.64.0", msrv: None, desc: "Iterator for array of length 1" },
+    LintInfo { name: "iter_overeager_cloned", group: "perf", level: "warn", version: "1.60.0", msrv: None, desc: "using `cloned()` early with `Iterator::iter()` can lead to some performance inefficiencies" },
+    LintInfo { name: "iter_skip_next", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `.skip(x).next()` on an iterator" },
+    LintInfo { name: "iter_with_drain", group: "nursery", level: "allow", version: "1.61.0", msrv: None, desc: "replace `.drain(..)` with `.into_iter()`" },
+    LintInfo { name: "iterator_step_by_zero", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "using `Iterator::step_by(0)`, which will panic at runtime" },
+    LintInfo { name: "just_underscores_and_digits", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "unclear name" },
+    LintInfo { name: "large_const_arrays", group: "perf", level: "warn", version: "1.44.0", msrv: None, desc: "large non-scalar const array may cause performance overhead" },
+    LintInfo { name: "large_digit_groups", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "grouping digits into groups that are too large" },
+    LintInfo { name: "large_enum_variant", group: "perf", level: "warn", version: "pre 1.29.0", msrv: None, desc: "large size difference between variants on an enum" },
+    LintInfo { name: "large_include_file", group: "restriction", level: "allow", version: "1.62.0", msrv: None, desc: "including a large file" },
+    LintInfo { name: "large_stack_arrays", group: "pedantic", level: "allow", version: "1.41.0", msrv: None, desc: "allocating large arrays on stack may cause stack overflow" },
+    LintInfo { name: "large_types_passed_by_value", group: "pedantic", level: "allow", version: "1.49.0", msrv: None, desc: "functions taking large arguments by value" },
+    LintInfo { name: "len_without_is_empty", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "traits or impls with a public `len` method but no corresponding `is_empty` method" },
+    LintInfo { name: "len_zero", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "checking `.len() == 0` or `.len() > 0` (or similar) when `.is_empty()` could be used instead" },
+    LintInfo { name: "let_and_return", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "creating a let-binding and then immediately returning it like `let x = expr; x` at the end of a block" },
+    LintInfo { name: "let_underscore_drop", group: "pedantic", level: "allow", version: "1.50.0", msrv: None, desc: "non-binding let on a type that implements `Drop`" },
+    LintInfo { name: "let_underscore_lock", group: "correctness", level: "deny", version: "1.43.0", msrv: None, desc: "non-binding let on a synchronization lock" },
+    LintInfo { name: "let_underscore_must_use", group: "restriction", level: "allow", version: "1.42.0", msrv: None, desc: "non-binding let on a `#[must_use]` expression" },
+    LintInfo { name: "let_unit_value", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "creating a `let` binding to a value of unit type, which usually can't be used afterwards" },
+    LintInfo { name: "linkedlist", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "usage of LinkedList, usually a vector is faster, or a more specialized data structure like a `VecDeque`" },
+    LintInfo { name: "lossy_float_literal", group: "restriction", level: "allow", version: "1.43.0", msrv: None, desc: "lossy whole number float literals" },
+    LintInfo { name: "macro_use_imports", group: "pedantic", level: "allow", version: "1.44.0", msrv: None, desc: "#[macro_use] is no longer needed" },
+    LintInfo { name: "main_recursion", group: "style", level: "warn", version: "1.38.0", msrv: None, desc: "recursion using the entrypoint" },
+    LintInfo { name: "manual_assert", group: "pedantic", level: "allow", version: "1.57.0", msrv: None, desc: "`panic!` and only a `panic!` in `if`-then statement" },
+    LintInfo { name: "manual_async_fn", group: "style", level: "warn", version: "1.45.0", msrv: None, desc: "manual implementations of `async` functions can be simplified using the dedicated syntax" },
+    LintInfo { name: "manual_bits", group: "style", level: "warn", version: "1.60.0", msrv: Some("1.53.0"), desc: "manual implementation of `size_of::<T>() * 8` can be simplified with `T::BITS`" },
+    LintInfo { name: "manual_filter_map", group: "complexity", level: "warn", version: "1.51.0", msrv: None, desc: "using `_.filter(_).map(_)` in a way that can be written more simply as `filter_map(_)`" },
+    LintInfo { name: "manual_find", group: "complexity", level: "warn", version: "1.64.0", msrv: None, desc: "manual implementation of `Iterator::find`" },
+    LintInfo { name: "manual_find_map", group: "complexity", level: "warn", version: "1.51.0", msrv: None, desc: "using `_.find(_).map(_)` in a way that can be written more simply as `find_map(_)`" },
+    LintInfo { name: "manual_flatten", group: "complexity", level: "warn", version: "1.52.0", msrv: None, desc: "for loops over `Option`s or `Result`s with a single expression can be simplified" },
+    LintInfo { name: "manual_instant_elapsed", group: "pedantic", level: "allow", version: "1.64.0", msrv: None, desc: "subtraction between `Instant::now()` and previous `Instant`" },
+    LintInfo { name: "manual_map", group: "style", level: "warn", version: "1.52.0", msrv: None, desc: "reimplementation of `map`" },
+    LintInfo { name: "manual_memcpy", group: "perf", level: "warn", version: "pre 1.29.0", msrv: None, desc: "manually copying items between slices" },
+    LintInfo { name: "manual_non_exhaustive", group: "style", level: "warn", version: "1.45.0", msrv: Some("1.40.0"), desc: "manual implementations of the non-exhaustive pattern can be simplified using #[non_exhaustive]" },
+    LintInfo { name: "manual_ok_or", group: "pedantic", level: "allow", version: "1.49.0", msrv: None, desc: "finds patterns that can be encoded more concisely with `Option::ok_or`" },
+    LintInfo { name: "manual_range_contains", group: "style", level: "warn", version: "1.49.0", msrv: Some("1.35.0"), desc: "manually reimplementing {`Range`, `RangeInclusive`}`::contains`" },
+    LintInfo { name: "manual_rem_euclid", group: "complexity", level: "warn", version: "1.64.0", msrv: None, desc: "manually reimplementing `rem_euclid`" },
+    LintInfo { name: "manual_retain", group: "perf", level: "warn", version: "1.64.0", msrv: None, desc: "`retain()` is simpler and the same functionalitys" },
+    LintInfo { name: "manual_saturating_arithmetic", group: "style", level: "warn", version: "1.39.0", msrv: None, desc: "`.checked_add/sub(x).unwrap_or(MAX/MIN)`" },
+    LintInfo { name: "manual_split_once", group: "complexity", level: "warn", version: "1.57.0", msrv: Some("1.52.0"), desc: "replace `.splitn(2, pat)` with `.split_once(pat)`" },
+    LintInfo { name: "manual_str_repeat", group: "perf", level: "warn", version: "1.54.0", msrv: Some("1.16.0"), desc: "manual implementation of `str::repeat`" },
+    LintInfo { name: "manual_string_new", group: "pedantic", level: "allow", version: "1.65.0", msrv: None, desc: "empty String is being created manually" },
+    LintInfo { name: "manual_strip", group: "complexity", level: "warn", version: "1.48.0", msrv: Some("1.45.0"), desc: "suggests using `strip_{prefix,suffix}` over `str::{starts,ends}_with` and slicing" },
+    LintInfo { name: "manual_swap", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "manual swap of two variables" },
+    LintInfo { name: "manual_unwrap_or", group: "complexity", level: "warn", version: "1.49.0", msrv: None, desc: "finds patterns that can be encoded more concisely with `Option::unwrap_or` or `Result::unwrap_or`" },
+    LintInfo { name: "many_single_char_names", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "too many single character bindings" },
+    LintInfo { name: "map_clone", group: "style", level: "warn", version: "pre 1.29.0", msrv: Some("1.36.0"), desc: "using `iterator.map(|x| x.clone())`, or dereferencing closures for `Copy` types" },
+    LintInfo { name: "map_collect_result_unit", group: "style", level: "warn", version: "1.49.0", msrv: None, desc: "using `.map(_).collect::<Result<(),_>()`, which can be replaced with `try_for_each`" },
+    LintInfo { name: "map_entry", group: "perf", level: "warn", version: "pre 1.29.0", msrv: None, desc: "use of `contains_key` followed by `insert` on a `HashMap` or `BTreeMap`" },
+    LintInfo { name: "map_err_ignore", group: "restriction", level: "allow", version: "1.48.0", msrv: None, desc: "`map_err` should not ignore the original error" },
+    LintInfo { name: "map_flatten", group: "complexity", level: "warn", version: "1.31.0", msrv: None, desc: "using combinations of `flatten` and `map` which can usually be written as a single method call" },
+    LintInfo { name: "map_identity", group: "complexity", level: "warn", version: "1.47.0", msrv: None, desc: "using iterator.map(|x| x)" },
+    LintInfo { name: "map_unwrap_or", group: "pedantic", level: "allow", version: "1.45.0", msrv: Some("1.41.0"), desc: "using `.map(f).unwrap_or(a)` or `.map(f).unwrap_or_else(func)`, which are more succinctly expressed as `map_or(a, f)` or `map_or_else(a, f)`" },
+    LintInfo { name: "match_as_ref", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "a `match` on an Option value instead of using `as_ref()` or `as_mut`" },
+    LintInfo { name: "match_bool", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "a `match` on a boolean expression instead of an `if..else` block" },
+    LintInfo { name: "match_like_matches_macro", group: "style", level: "warn", version: "1.47.0", msrv: Some("1.42.0"), desc: "a match that could be written with the matches! macro" },
+    LintInfo { name: "match_on_vec_items", group: "pedantic", level: "allow", version: "1.45.0", msrv: None, desc: "matching on vector elements can panic" },
+    LintInfo { name: "match_overlapping_arm", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "a `match` with overlapping arms" },
+    LintInfo { name: "match_ref_pats", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "a `match` or `if let` with all arms prefixed with `&` instead of deref-ing the match expression" },
+    LintInfo { name: "match_result_ok", group: "style", level: "warn", version: "1.57.0", msrv: None, desc: "usage of `ok()` in `let Some(pat)` statements is unnecessary, match on `Ok(pat)` instead" },
+    LintInfo { name: "match_same_arms", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "`match` with identical arm bodies" },
+    LintInfo { name: "match_single_binding", group: "complexity", level: "warn", version: "1.43.0", msrv: None, desc: "a match with a single binding instead of using `let` statement" },
+    LintInfo { name: "match_str_case_mismatch", group: "correctness", level: "deny", version: "1.58.0", msrv: None, desc: "creation of a case altering match expression with non-compliant arms" },
+    LintInfo { name: "match_wild_err_arm", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "a `match` with `Err(_)` arm and take drastic actions" },
+    LintInfo { name: "match_wildcard_for_single_variants", group: "pedantic", level: "allow", version: "1.45.0", msrv: None, desc: "a wildcard enum match for a single variant" },
+    LintInfo { name: "maybe_infinite_iter", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "possible infinite iteration" },
+    LintInfo { name: "mem_forget", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "`mem::forget` usage on `Drop` types, likely to cause memory leaks" },
+    LintInfo { name: "mem_replace_option_with_none", group: "style", level: "warn", version: "1.31.0", msrv: None, desc: "replacing an `Option` with `None` instead of `take()`" },
+    LintInfo { name: "mem_replace_with_default", group: "style", level: "warn", version: "1.42.0", msrv: Some("1.40.0"), desc: "replacing a value of type `T` with `T::default()` instead of using `std::mem::take`" },
+    LintInfo { name: "mem_replace_with_uninit", group: "correctness", level: "deny", version: "1.39.0", msrv: None, desc: "`mem::replace(&mut _, mem::uninitialized())` or `mem::replace(&mut _, mem::zeroed())`" },
+    LintInfo { name: "min_max", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "`min(_, max(_, _))` (or vice versa) with bounds clamping the result to a constant" },
+    LintInfo { name: "mismatched_target_os", group: "correctness", level: "deny", version: "1.45.0", msrv: None, desc: "usage of `cfg(operating_system)` instead of `cfg(target_os = \\\"operating_system\\\")`" },
+    LintInfo { name: "mismatching_type_param_order", group: "pedantic", level: "allow", version: "1.63.0", msrv: None, desc: "type parameter positioned inconsistently between type def and impl block" },
+    LintInfo { name: "misrefactored_assign_op", group: "suspicious", level: "warn", version: "pre 1.29.0", msrv: None, desc: "having a variable on both sides of an assign op" },
+    LintInfo { name: "missing_const_for_fn", group: "nursery", level: "allow", version: "1.34.0", msrv: Some("1.46.0"), desc: "Lint functions definitions that could be made `const fn`" },
+    LintInfo { name: "missing_docs_in_private_items", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "detects missing documentation for public and private members" },
+    LintInfo { name: "missing_enforced_import_renames", group: "restriction", level: "allow", version: "1.55.0", msrv: None, desc: "enforce import renames" },
+    LintInfo { name: "missing_errors_doc", group: "pedantic", level: "allow", version: "1.41.0", msrv: None, desc: "`pub fn` returns `Result` without `# Errors` in doc comment" },
+    LintInfo { name: "missing_inline_in_public_items", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "detects missing `#[inline]` attribute for public callables (functions, trait methods, methods...)" },
+    LintInfo { name: "missing_panics_doc", group: "pedantic", level: "allow", version: "1.51.0", msrv: None, desc: "`pub fn` may panic without `# Panics` in doc comment" },
+    LintInfo { name: "missing_safety_doc", group: "style", level: "warn", version: "1.39.0", msrv: None, desc: "`pub unsafe fn` without `# Safety` docs" },
+    LintInfo { name: "missing_spin_loop", group: "perf", level: "warn", version: "1.61.0", msrv: None, desc: "An empty busy waiting loop" },
+    LintInfo { name: "mistyped_literal_suffixes", group: "correctness", level: "deny", version: "1.30.0", msrv: None, desc: "mistyped literal suffix" },
+    LintInfo { name: "mixed_case_hex_literals", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "hex literals whose letter digits are not consistently upper- or lowercased" },
+    LintInfo { name: "mixed_read_write_in_expression", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "whether a variable read occurs before a write depends on sub-expression evaluation order" },
+    LintInfo { name: "mod_module_files", group: "restriction", level: "allow", version: "1.57.0", msrv: None, desc: "checks that module layout is consistent" },
+    LintInfo { name: "module_inception", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "modules that have the same name as their parent module" },
+    LintInfo { name: "module_name_repetitions", group: "pedantic", level: "allow", version: "1.33.0", msrv: None, desc: "type names prefixed/postfixed with their containing module's name" },
+    LintInfo { name: "modulo_arithmetic", group: "restriction", level: "allow", version: "1.42.0", msrv: None, desc: "any modulo arithmetic statement" },
+    LintInfo { name: "modulo_one", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "taking a number modulo +/-1, which can either panic/overflow or always returns 0" },
+    LintInfo { name: "multi_assignments", group: "suspicious", level: "warn", version: "1.65.0", msrv: None, desc: "instead of using `a = b = c;` use `a = c; b = c;`" },
+    LintInfo { name: "multiple_crate_versions", group: "cargo", level: "allow", version: "pre 1.29.0", msrv: None, desc: "multiple versions of the same crate being used" },
+    LintInfo { name: "multiple_inherent_impl", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "Multiple inherent impl that could be grouped" },
+    LintInfo { name: "must_use_candidate", group: "pedantic", level: "allow", version: "1.40.0", msrv: None, desc: "function or method that could take a `#[must_use]` attribute" },
+    LintInfo { name: "must_use_unit", group: "style", level: "warn", version: "1.40.0", msrv: None, desc: "`#[must_use]` attribute on a unit-returning function / method" },
+    LintInfo { name: "mut_from_ref", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "fns that create mutable refs from immutable ref args" },
+    LintInfo { name: "mut_mut", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "usage of double-mut refs, e.g., `&mut &mut ...`" },
+    LintInfo { name: "mut_mutex_lock", group: "style", level: "warn", version: "1.49.0", msrv: None, desc: "`&mut Mutex::lock` does unnecessary locking" },
+    LintInfo { name: "mut_range_bound", group: "suspicious", level: "warn", version: "pre 1.29.0", msrv: None, desc: "for loop over a range where one of the bounds is a mutable variable" },
+    LintInfo { name: "mutable_key_type", group: "suspicious", level: "warn", version: "1.42.0", msrv: None, desc: "Check for mutable `Map`/`Set` key type" },
+    LintInfo { name: "mutex_atomic", group: "nursery", level: "allow", version: "pre 1.29.0", msrv: None, desc: "using a mutex where an atomic value could be used instead" },
+    LintInfo { name: "mutex_integer", group: "nursery", level: "allow", version: "pre 1.29.0", msrv: None, desc: "using a mutex for an integer type" },
+    LintInfo { name: "naive_bytecount", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "use of naive `<slice>.filter(|&x| x == y).count()` to count byte values" },
+    LintInfo { name: "needless_arbitrary_self_type", group: "complexity", level: "warn", version: "1.47.0", msrv: None, desc: "type of `self` parameter is already by default `Self`" },
+    LintInfo { name: "needless_bitwise_bool", group: "pedantic", level: "allow", version: "1.54.0", msrv: None, desc: "Boolean expressions that use bitwise rather than lazy operators" },
+    LintInfo { name: "needless_bool", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "if-statements with plain booleans in the then- and else-clause, e.g., `if p { true } else { false }`" },
+    LintInfo { name: "needless_borrow", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "taking a reference that is going to be automatically dereferenced" },
+    LintInfo { name: "needless_borrowed_reference", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "destructuring a reference and borrowing the inner value" },
+    LintInfo { name: "needless_collect", group: "perf", level: "warn", version: "1.30.0", msrv: None, desc: "collecting an iterator when collect is not needed" },
+    LintInfo { name: "needless_continue", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "`continue` statements that can be replaced by a rearrangement of code" },
+    LintInfo { name: "needless_doctest_main", group: "style", level: "warn", version: "1.40.0", msrv: None, desc: "presence of `fn main() {` in code examples" },
+    LintInfo { name: "needless_for_each", group: "pedantic", level: "allow", version: "1.53.0", msrv: None, desc: "using `for_each` where a `for` loop would be simpler" },
+    LintInfo { name: "needless_late_init", group: "style", level: "warn", version: "1.59.0", msrv: None, desc: "late initializations that can be replaced by a `let` statement with an initializer" },
+    LintInfo { name: "needless_lifetimes", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using explicit lifetimes for references in function arguments when elision rules would allow omitting them" },
+    LintInfo { name: "needless_match", group: "complexity", level: "warn", version: "1.61.0", msrv: None, desc: "`match` or match-like `if let` that are unnecessary" },
+    LintInfo { name: "needless_option_as_deref", group: "complexity", level: "warn", version: "1.57.0", msrv: None, desc: "no-op use of `deref` or `deref_mut` method to `Option`." },
+    LintInfo { name: "needless_option_take", group: "complexity", level: "warn", version: "1.62.0", msrv: None, desc: "using `.as_ref().take()` on a temporary value" },
+    LintInfo { name: "needless_parens_on_range_literals", group: "style", level: "warn", version: "1.63.0", msrv: None, desc: "needless parenthesis on range literals can be removed" },
+    LintInfo { name: "needless_pass_by_value", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "functions taking arguments by value, but not consuming them in its body" },
+    LintInfo { name: "needless_question_mark", group: "complexity", level: "warn", version: "1.51.0", msrv: None, desc: "Suggest `value.inner_option` instead of `Some(value.inner_option?)`. The same goes for `Result<T, E>`." },
+    LintInfo { name: "needless_range_loop", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "for-looping over a range of indices where an iterator over items would do" },
+    LintInfo { name: "needless_return", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using a return statement like `return expr;` where an expression would suffice" },
+    LintInfo { name: "needless_splitn", group: "complexity", level: "warn", version: "1.59.0", msrv: None, desc: "usages of `str::splitn` that can be replaced with `str::split`" },
+    LintInfo { name: "needless_update", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `Foo { ..base }` when there are no missing fields" },
+    LintInfo { name: "neg_cmp_op_on_partial_ord", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "The use of negated comparison operators on partially ordered types may produce confusing code." },
+    LintInfo { name: "neg_multiply", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "multiplying integers by `-1`" },
+    LintInfo { name: "negative_feature_names", group: "cargo", level: "allow", version: "1.57.0", msrv: None, desc: "usage of a negative feature name" },
+    LintInfo { name: "never_loop", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "any loop that will always `break` or `return`" },
+    LintInfo { name: "new_ret_no_self", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "not returning type containing `Self` in a `new` method" },
+    LintInfo { name: "new_without_default", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "`pub fn new() -> Self` method without `Default` implementation" },
+    LintInfo { name: "no_effect", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "statements with no effect" },
+    LintInfo { name: "no_effect_replace", group: "suspicious", level: "warn", version: "1.63.0", msrv: None, desc: "replace with no effect" },
+    LintInfo { name: "no_effect_underscore_binding", group: "pedantic", level: "allow", version: "1.58.0", msrv: None, desc: "binding to `_` prefixed variable with no side-effect" },
+    LintInfo { name: "non_ascii_literal", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "using any literal non-ASCII chars in a string literal instead of using the `\\\\u` escape" },
+    LintInfo { name: "non_octal_unix_permissions", group: "correctness", level: "deny", version: "1.53.0", msrv: None, desc: "use of non-octal value to set unix file permissions, which will be translated into octal" },
+    LintInfo { name: "non_send_fields_in_send_ty", group: "nursery", level: "allow", version: "1.57.0", msrv: None, desc: "there is a field that is not safe to be sent to another thread in a `Send` struct" },
+    LintInfo { name: "nonminimal_bool", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "boolean expressions that can be written more concisely" },
+    LintInfo { name: "nonsensical_open_options", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "nonsensical combination of options for opening a file" },
+    LintInfo { name: "nonstandard_macro_braces", group: "nursery", level: "allow", version: "1.55.0", msrv: None, desc: "check consistent use of braces in macro" },
+    LintInfo { name: "not_unsafe_ptr_arg_deref", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "public functions dereferencing raw pointer arguments but not marked `unsafe`" },
+    LintInfo { name: "obfuscated_if_else", group: "style", level: "warn", version: "1.64.0", msrv: None, desc: "use of `.then_some(..).unwrap_or(..)` can be written more clearly with `if .. else ..`" },
+    LintInfo { name: "octal_escapes", group: "suspicious", level: "warn", version: "1.59.0", msrv: None, desc: "string escape sequences looking like octal characters" },
+    LintInfo { name: "ok_expect", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `ok().expect()`, which gives worse error messages than calling `expect` directly on the Result" },
+    LintInfo { name: "only_used_in_recursion", group: "complexity", level: "warn", version: "1.61.0", msrv: None, desc: "arguments that is only used in recursion can be removed" },
+    LintInfo { name: "op_ref", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "taking a reference to satisfy the type constraints on `==`" },
+    LintInfo { name: "option_as_ref_deref", group: "complexity", level: "warn", version: "1.42.0", msrv: Some("1.40.0"), desc: "using `as_ref().map(Deref::deref)`, which is more succinctly expressed as `as_deref()`" },
+    LintInfo { name: "option_env_unwrap", group: "correctness", level: "deny", version: "1.43.0", msrv: None, desc: "using `option_env!(...).unwrap()` to get environment variable" },
+    LintInfo { name: "option_filter_map", group: "complexity", level: "warn", version: "1.53.0", msrv: None, desc: "filtering `Option` for `Some` then force-unwrapping, which can be one type-safe operation" },
+    LintInfo { name: "option_if_let_else", group: "nursery", level: "allow", version: "1.47.0", msrv: None, desc: "reimplementation of Option::map_or" },
+    LintInfo { name: "option_map_or_none", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `Option.map_or(None, f)`, which is more succinctly expressed as `and_then(f)`" },
+    LintInfo { name: "option_map_unit_fn", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `option.map(f)`, where `f` is a function or closure that returns `()`" },
+    LintInfo { name: "option_option", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "usage of `Option<Option<T>>`" },
+    LintInfo { name: "or_fun_call", group: "perf", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using any `*or` method with a function call, which suggests `*or_else`" },
+    LintInfo { name: "or_then_unwrap", group: "complexity", level: "warn", version: "1.61.0", msrv: None, desc: "checks for `.or(…).unwrap()` calls to Options and Results." },
+    LintInfo { name: "out_of_bounds_indexing", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "out of bounds constant indexing" },
+    LintInfo { name: "overflow_check_conditional", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "overflow checks inspired by C which are likely to panic" },
+    LintInfo { name: "overly_complex_bool_expr", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "boolean expressions that contain terminals which can be eliminated" },
+    LintInfo { name: "panic", group: "restriction", level: "allow", version: "1.40.0", msrv: None, desc: "usage of the `panic!` macro" },
+    LintInfo { name: "panic_in_result_fn", group: "restriction", level: "allow", version: "1.48.0", msrv: None, desc: "functions of type `Result<..>` that contain `panic!()`, `todo!()`, `unreachable()`, `unimplemented()` or assertion" },
+    LintInfo { name: "panicking_unwrap", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "checks for calls of `unwrap[_err]()` that will always fail" },
+    LintInfo { name: "partialeq_ne_impl", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "re-implementing `PartialEq::ne`" },
+    LintInfo { name: "partialeq_to_none", group: "style", level: "warn", version: "1.64.0", msrv: None, desc: "Binary comparison to `Option<T>::None` relies on `T: PartialEq`, which is unneeded" },
+    LintInfo { name: "path_buf_push_overwrite", group: "nursery", level: "allow", version: "1.36.0", msrv: None, desc: "calling `push` with file system root on `PathBuf` can overwrite it" },
+    LintInfo { name: "pattern_type_mismatch", group: "restriction", level: "allow", version: "1.47.0", msrv: None, desc: "type of pattern does not match the expression type" },
+    LintInfo { name: "possible_missing_comma", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "possible missing comma in array" },
+    LintInfo { name: "precedence", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "operations where precedence may be unclear" },
+    LintInfo { name: "print_in_format_impl", group: "suspicious", level: "warn", version: "1.61.0", msrv: None, desc: "use of a print macro in a formatting trait impl" },
+    LintInfo { name: "print_literal", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "printing a literal with a format string" },
+    LintInfo { name: "print_stderr", group: "restriction", level: "allow", version: "1.50.0", msrv: None, desc: "printing on stderr" },
+    LintInfo { name: "print_stdout", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "printing on stdout" },
+    LintInfo { name: "print_with_newline", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `print!()` with a format string that ends in a single newline" },
+    LintInfo { name: "println_empty_string", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `println!(\\\"\\\")` with an empty string" },
+    LintInfo { name: "ptr_arg", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "fn arguments of the type `&Vec<...>` or `&String`, suggesting to use `&[...]` or `&str` instead, respectively" },
+    LintInfo { name: "ptr_as_ptr", group: "pedantic", level: "allow", version: "1.51.0", msrv: Some("1.38.0"), desc: "casting using `as` from and to raw pointers that doesn't change its mutability, where `pointer::cast` could take the place of `as`" },
+    LintInfo { name: "ptr_eq", group: "style", level: "warn", version: "1.49.0", msrv: None, desc: "use `std::ptr::eq` when comparing raw pointers" },
+    LintInfo { name: "ptr_offset_with_cast", group: "complexity", level: "warn", version: "1.30.0", msrv: None, desc: "unneeded pointer offset cast" },
+    LintInfo { name: "pub_use", group: "restriction", level: "allow", version: "1.62.0", msrv: None, desc: "restricts the usage of `pub use`" },
+    LintInfo { name: "question_mark", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "checks for expressions that could be replaced by the question mark operator" },
+    LintInfo { name: "range_minus_one", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "`x..=(y-1)` reads better as `x..y`" },
+    LintInfo { name: "range_plus_one", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "`x..(y+1)` reads better as `x..=y`" },
+    LintInfo { name: "range_zip_with_len", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "zipping iterator with a range when `enumerate()` would do" },
+    LintInfo { name: "rc_buffer", group: "restriction", level: "allow", version: "1.48.0", msrv: None, desc: "shared ownership of a buffer type" },
+    LintInfo { name: "rc_clone_in_vec_init", group: "suspicious", level: "warn", version: "1.63.0", msrv: None, desc: "initializing reference-counted pointer in `vec![elem; len]`" },
+    LintInfo { name: "rc_mutex", group: "restriction", level: "allow", version: "1.55.0", msrv: None, desc: "usage of `Rc<Mutex<T>>`" },
+    LintInfo { name: "read_zero_byte_vec", group: "correctness", level: "deny", version: "1.63.0", msrv: None, desc: "checks for reads into a zero-length `Vec`" },
+    LintInfo { name: "recursive_format_impl", group: "correctness", level: "deny", version: "1.48.0", msrv: None, desc: "Format trait method called while implementing the same Format trait" },
+    LintInfo { name: "redundant_allocation", group: "perf", level: "warn", version: "1.44.0", msrv: None, desc: "redundant allocation" },
+    LintInfo { name: "redundant_clone", group: "perf", level: "warn", version: "1.32.0", msrv: None, desc: "`clone()` of an owned value that is going to be dropped immediately" },
+    LintInfo { name: "redundant_closure", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "redundant closures, i.e., `|a| foo(a)` (which can be written as just `foo`)" },
+    LintInfo { name: "redundant_closure_call", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "throwaway closures called in the expression they are defined" },
+    LintInfo { name: "redundant_closure_for_method_calls", group: "pedantic", level: "allow", version: "1.35.0", msrv: None, desc: "redundant closures for method calls" },
+    LintInfo { name: "redundant_else", group: "pedantic", level: "allow", version: "1.50.0", msrv: None, desc: "`else` branch that can be removed without changing semantics" },
+    LintInfo { name: "redundant_feature_names", group: "cargo", level: "allow", version: "1.57.0", msrv: None, desc: "usage of a redundant feature name" },
+    LintInfo { name: "redundant_field_names", group: "style", level: "warn", version: "pre 1.29.0", msrv: Some("1.17.0"), desc: "checks for fields in struct literals where shorthands could be used" },
+    LintInfo { name: "redundant_pattern", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `name @ _` in a pattern" },
+    LintInfo { name: "redundant_pattern_matching", group: "style", level: "warn", version: "1.31.0", msrv: None, desc: "use the proper utility function avoiding an `if let`" },
+    LintInfo { name: "redundant_pub_crate", group: "nursery", level: "allow", version: "1.44.0", msrv: None, desc: "Using `pub(crate)` visibility on items that are not crate visible due to the visibility of the module that contains them." },
+    LintInfo { name: "redundant_slicing", group: "complexity", level: "warn", version: "1.51.0", msrv: None, desc: "redundant slicing of the whole range of a type" },
+    LintInfo { name: "redundant_static_lifetimes", group: "style", level: "warn", version: "1.37.0", msrv: Some("1.17.0"), desc: "Using explicit `'static` lifetime for constants or statics when elision rules would allow omitting them." },
+    LintInfo { name: "ref_binding_to_reference", group: "pedantic", level: "allow", version: "1.54.0", msrv: None, desc: "`ref` binding to a reference" },
+    LintInfo { name: "ref_option_ref", group: "pedantic", level: "allow", version: "1.49.0", msrv: None, desc: "use `Option<&T>` instead of `&Option<&T>`" },
+    LintInfo { name: "repeat_once", group: "complexity", level: "warn", version: "1.47.0", msrv: None, desc: "using `.repeat(1)` instead of `String.clone()`, `str.to_string()` or `slice.to_vec()` " },
+    LintInfo { name: "rest_pat_in_fully_bound_structs", group: "restriction", level: "allow", version: "1.43.0", msrv: None, desc: "a match on a struct that binds all fields but still uses the wildcard pattern" },
+    LintInfo { name: "result_large_err", group: "perf", level: "warn", version: "1.64.0", msrv: None, desc: "function returning `Result` with large `Err` type" },
+    LintInfo { name: "result_map_or_into_option", group: "style", level: "warn", version: "1.44.0", msrv: None, desc: "using `Result.map_or(None, Some)`, which is more succinctly expressed as `ok()`" },
+    LintInfo { name: "result_map_unit_fn", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `result.map(f)`, where `f` is a function or closure that returns `()`" },
+    LintInfo { name: "result_unit_err", group: "style", level: "warn", version: "1.49.0", msrv: None, desc: "public function returning `Result` with an `Err` type of `()`" },
+    LintInfo { name: "return_self_not_must_use", group: "pedantic", level: "allow", version: "1.59.0", msrv: None, desc: "missing `#[must_use]` annotation on a method returning `Self`" },
+    LintInfo { name: "reversed_empty_ranges", group: "correctness", level: "deny", version: "1.45.0", msrv: None, desc: "reversing the limits of range expressions, resulting in empty ranges" },
+    LintInfo { name: "same_functions_in_if_condition", group: "pedantic", level: "allow", version: "1.41.0", msrv: None, desc: "consecutive `if`s with the same function call" },
+    LintInfo { name: "same_item_push", group: "style", level: "warn", version: "1.47.0", msrv: None, desc: "the same item is pushed inside of a for loop" },
+    LintInfo { name: "same_name_method", group: "restriction", level: "allow", version: "1.57.0", msrv: None, desc: "two method with same name" },
+    LintInfo { name: "search_is_some", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using an iterator or string search followed by `is_some()` or `is_none()`, which is more succinctly expressed as a call to `any()` or `contains()` (with negation in case of `is_none()`)" },
+    LintInfo { name: "self_assignment", group: "correctness", level: "deny", version: "1.48.0", msrv: None, desc: "explicit self-assignment" },
+    LintInfo { name: "self_named_constructors", group: "style", level: "warn", version: "1.55.0", msrv: None, desc: "method should not have the same name as the type it is implemented for" },
+    LintInfo { name: "self_named_module_files", group: "restriction", level: "allow", version: "1.57.0", msrv: None, desc: "checks that module layout is consistent" },
+    LintInfo { name: "semicolon_if_nothing_returned", group: "pedantic", level: "allow", version: "1.52.0", msrv: None, desc: "add a semicolon if nothing is returned" },
+    LintInfo { name: "separated_literal_suffix", group: "restriction", level: "allow", version: "1.58.0", msrv: None, desc: "literals whose suffix is separated by an underscore" },
+    LintInfo { name: "serde_api_misuse", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "various things that will negatively affect your serde experience" },
+    LintInfo { name: "shadow_reuse", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "rebinding a name to an expression that re-uses the original value, e.g., `let x = x + 1`" },
+    LintInfo { name: "shadow_same", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "rebinding a name to itself, e.g., `let mut x = &mut x`" },
+    LintInfo { name: "shadow_unrelated", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "rebinding a name without even using the original value" },
+    LintInfo { name: "short_circuit_statement", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using a short circuit boolean condition as a statement" },
+    LintInfo { name: "should_implement_trait", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "defining a method that should be implementing a std trait" },
+    LintInfo { name: "significant_drop_in_scrutinee", group: "nursery", level: "allow", version: "1.60.0", msrv: None, desc: "warns when a temporary of a type with a drop with a significant side-effect might have a surprising lifetime" },
+    LintInfo { name: "similar_names", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "similarly named items and bindings" },
+    LintInfo { name: "single_char_add_str", group: "style", level: "warn", version: "1.49.0", msrv: None, desc: "`push_str()` or `insert_str()` used with a single-character string literal as parameter" },
+    LintInfo { name: "single_char_lifetime_names", group: "restriction", level: "allow", version: "1.60.0", msrv: None, desc: "warns against single-character lifetime names" },
+    LintInfo { name: "single_char_pattern", group: "perf", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using a single-character str where a char could be used, e.g., `_.split(\\\"x\\\")`" },
+    LintInfo { name: "single_component_path_imports", group: "style", level: "warn", version: "1.43.0", msrv: None, desc: "imports with single component path are redundant" },
+    LintInfo { name: "single_element_loop", group: "complexity", level: "warn", version: "1.49.0", msrv: None, desc: "there is no reason to have a single element loop" },
+    LintInfo { name: "single_match", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "a `match` statement with a single nontrivial arm (i.e., where the other arm is `_ => {}`) instead of `if let`" },
+    LintInfo { name: "single_match_else", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "a `match` statement with two arms where the second arm's pattern is a placeholder instead of a specific match pattern" },
+    LintInfo { name: "size_of_in_element_count", group: "correctness", level: "deny", version: "1.50.0", msrv: None, desc: "using `size_of::<T>` or `size_of_val::<T>` where a count of elements of `T` is expected" },
+    LintInfo { name: "skip_while_next", group: "complexity", level: "warn", version: "1.42.0", msrv: None, desc: "using `skip_while(p).next()`, which is more succinctly expressed as `.find(!p)`" },
+    LintInfo { name: "slow_vector_initialization", group: "perf", level: "warn", version: "1.32.0", msrv: None, desc: "slow vector initialization" },
+    LintInfo { name: "stable_sort_primitive", group: "pedantic", level: "allow", version: "1.47.0", msrv: None, desc: "use of sort() when sort_unstable() is equivalent" },
+    LintInfo { name: "std_instead_of_alloc", group: "restriction", level: "allow", version: "1.64.0", msrv: None, desc: "type is imported from std when available in alloc" },
+    LintInfo { name: "std_instead_of_core", group: "restriction", level: "allow", version: "1.64.0", msrv: None, desc: "type is imported from std when available in core" },
+    LintInfo { name: "str_to_string", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "using `to_string()` on a `&str`, which should be `to_owned()`" },
+    LintInfo { name: "string_add", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "using `x + ..` where x is a `String` instead of `push_str()`" },
+    LintInfo { name: "string_add_assign", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "using `x = x + ..` where x is a `String` instead of `push_str()`" },
+    LintInfo { name: "string_extend_chars", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `x.extend(s.chars())` where s is a `&str` or `String`" },
+    LintInfo { name: "string_from_utf8_as_bytes", group: "complexity", level: "warn", version: "1.50.0", msrv: None, desc: "casting string slices to byte slices and back" },
+    LintInfo { name: "string_lit_as_bytes", group: "nursery", level: "allow", version: "pre 1.29.0", msrv: None, desc: "calling `as_bytes` on a string literal instead of using a byte string literal" },
+    LintInfo { name: "string_slice", group: "restriction", level: "allow", version: "1.58.0", msrv: None, desc: "slicing a string" },
+    LintInfo { name: "string_to_string", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "using `to_string()` on a `String`, which should be `clone()`" },
+    LintInfo { name: "strlen_on_c_strings", group: "complexity", level: "warn", version: "1.55.0", msrv: None, desc: "using `libc::strlen` on a `CString` or `CStr` value, while `as_bytes().len()` or `to_bytes().len()` respectively can be used instead" },
+    LintInfo { name: "struct_excessive_bools", group: "pedantic", level: "allow", version: "1.43.0", msrv: None, desc: "using too many bools in a struct" },
+    LintInfo { name: "suboptimal_flops", group: "nursery", level: "allow", version: "1.43.0", msrv: None, desc: "usage of sub-optimal floating point operations" },
+    LintInfo { name: "suspicious_arithmetic_impl", group: "suspicious", level: "warn", version: "pre 1.29.0", msrv: None, desc: "suspicious use of operators in impl of arithmetic trait" },
+    LintInfo { name: "suspicious_assignment_formatting", group: "suspicious", level: "warn", version: "pre 1.29.0", msrv: None, desc: "suspicious formatting of `*=`, `-=` or `!=`" },
+    LintInfo { name: "suspicious_else_formatting", group: "suspicious", level: "warn", version: "pre 1.29.0", msrv: None, desc: "suspicious formatting of `else`" },
+    LintInfo { name: "suspicious_map", group: "suspicious", level: "warn", version: "1.39.0", msrv: None, desc: "suspicious usage of map" },
+    LintInfo { name: "suspicious_op_assign_impl", group: "suspicious", level: "warn", version: "pre 1.29.0", msrv: None, desc: "suspicious use of operators in impl of OpAssign trait" },
+    LintInfo { name: "suspicious_operation_groupings", group: "nursery", level: "allow", version: "1.50.0", msrv: None, desc: "groupings of binary operations that look suspiciously like typos" },
+    LintInfo { name: "suspicious_splitn", group: "correctness", level: "deny", version: "1.54.0", msrv: None, desc: "checks for `.splitn(0, ..)` and `.splitn(1, ..)`" },
+    LintInfo { name: "suspicious_to_owned", group: "suspicious", level: "warn", version: "1.65.0", msrv: None, desc: "calls to `to_owned` on a `Cow<'_, _>` might not do what they are expected" },
+    LintInfo { name: "suspicious_unary_op_formatting", group: "suspicious", level: "warn", version: "1.40.0", msrv: None, desc: "suspicious formatting of unary `-` or `!` on the RHS of a BinOp" },
+    LintInfo { name: "swap_ptr_to_ref", group: "suspicious", level: "warn", version: "1.63.0", msrv: None, desc: "call to `mem::swap` using pointer derived references" },
+    LintInfo { name: "tabs_in_doc_comments", group: "style", level: "warn", version: "1.41.0", msrv: None, desc: "using tabs in doc comments is not recommended" },
+    LintInfo { name: "temporary_assignment", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "assignments to temporaries" },
+    LintInfo { name: "to_digit_is_some", group: "style", level: "warn", version: "1.41.0", msrv: None, desc: "`char.is_digit()` is clearer" },
+    LintInfo { name: "to_string_in_format_args", group: "perf", level: "warn", version: "1.58.0", msrv: None, desc: "`to_string` applied to a type that implements `Display` in format args" },
+    LintInfo { name: "todo", group: "restriction", level: "allow", version: "1.40.0", msrv: None, desc: "`todo!` should not be present in production code" },
+    LintInfo { name: "too_many_arguments", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "functions with too many arguments" },
+    LintInfo { name: "too_many_lines", group: "pedantic", level: "allow", version: "1.34.0", msrv: None, desc: "functions with too many lines" },
+    LintInfo { name: "toplevel_ref_arg", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "an entire binding declared as `ref`, in a function argument or a `let` statement" },
+    LintInfo { name: "trailing_empty_array", group: "nursery", level: "allow", version: "1.58.0", msrv: None, desc: "struct with a trailing zero-sized array but without `#[repr(C)]` or another `repr` attribute" },
+    LintInfo { name: "trait_duplication_in_bounds", group: "nursery", level: "allow", version: "1.47.0", msrv: None, desc: "check if the same trait bounds are specified more than once during a generic declaration" },
+    LintInfo { name: "transmute_bytes_to_str", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "transmutes from a `&[u8]` to a `&str`" },
+    LintInfo { name: "transmute_float_to_int", group: "complexity", level: "warn", version: "1.41.0", msrv: None, desc: "transmutes from a float to an integer" },
+    LintInfo { name: "transmute_int_to_bool", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "transmutes from an integer to a `bool`" },
+    LintInfo { name: "transmute_int_to_char", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "transmutes from an integer to a `char`" },
+    LintInfo { name: "transmute_int_to_float", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "transmutes from an integer to a float" },
+    LintInfo { name: "transmute_num_to_bytes", group: "complexity", level: "warn", version: "1.58.0", msrv: None, desc: "transmutes from a number to an array of `u8`" },
+    LintInfo { name: "transmute_ptr_to_ptr", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "transmutes from a pointer to a pointer / a reference to a reference" },
+    LintInfo { name: "transmute_ptr_to_ref", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "transmutes from a pointer to a reference type" },
+    LintInfo { name: "transmute_undefined_repr", group: "nursery", level: "allow", version: "1.60.0", msrv: None, desc: "transmute to or from a type with an undefined representation" },
+    LintInfo { name: "transmutes_expressible_as_ptr_casts", group: "complexity", level: "warn", version: "1.47.0", msrv: None, desc: "transmutes that could be a pointer cast" },
+    LintInfo { name: "transmuting_null", group: "correctness", level: "deny", version: "1.35.0", msrv: None, desc: "transmutes from a null pointer to a reference, which is undefined behavior" },
+    LintInfo { name: "trim_split_whitespace", group: "style", level: "warn", version: "1.62.0", msrv: None, desc: "using `str::trim()` or alike before `str::split_whitespace`" },
+    LintInfo { name: "trivial_regex", group: "nursery", level: "allow", version: "pre 1.29.0", msrv: None, desc: "trivial regular expressions" },
+    LintInfo { name: "trivially_copy_pass_by_ref", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "functions taking small copyable arguments by reference" },
+    LintInfo { name: "try_err", group: "restriction", level: "allow", version: "1.38.0", msrv: None, desc: "return errors explicitly rather than hiding them behind a `?`" },
+    LintInfo { name: "type_complexity", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "usage of very complex types that might be better factored into `type` definitions" },
+    LintInfo { name: "type_repetition_in_bounds", group: "nursery", level: "allow", version: "1.38.0", msrv: None, desc: "types are repeated unnecessary in trait bounds use `+` instead of using `T: _, T: _`" },
+    LintInfo { name: "undocumented_unsafe_blocks", group: "restriction", level: "allow", version: "1.58.0", msrv: None, desc: "creating an unsafe block without explaining why it is safe" },
+    LintInfo { name: "undropped_manually_drops", group: "correctness", level: "deny", version: "1.49.0", msrv: None, desc: "use of safe `std::mem::drop` function to drop a std::mem::ManuallyDrop, which will not drop the inner value" },
+    LintInfo { name: "unicode_not_nfc", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "using a Unicode literal not in NFC normal form (see [Unicode tr15](http://www.unicode.org/reports/tr15/) for further information)" },
+    LintInfo { name: "unimplemented", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "`unimplemented!` should not be present in production code" },
+    LintInfo { name: "uninit_assumed_init", group: "correctness", level: "deny", version: "1.39.0", msrv: None, desc: "`MaybeUninit::uninit().assume_init()`" },
+    LintInfo { name: "uninit_vec", group: "correctness", level: "deny", version: "1.58.0", msrv: None, desc: "Vec with uninitialized data" },
+    LintInfo { name: "uninlined_format_args", group: "pedantic", level: "allow", version: "1.65.0", msrv: Some("1.58.0"), desc: "using non-inlined variables in `format!` calls" },
+    LintInfo { name: "unit_arg", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "passing unit to a function" },
+    LintInfo { name: "unit_cmp", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "comparing unit values" },
+    LintInfo { name: "unit_hash", group: "correctness", level: "deny", version: "1.58.0", msrv: None, desc: "hashing a unit value, which does nothing" },
+    LintInfo { name: "unit_return_expecting_ord", group: "correctness", level: "deny", version: "1.47.0", msrv: None, desc: "fn arguments of type Fn(...) -> Ord returning the unit type ()." },
+    LintInfo { name: "unnecessary_cast", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "cast to the same type, e.g., `x as i32` where `x: i32`" },
+    LintInfo { name: "unnecessary_filter_map", group: "complexity", level: "warn", version: "1.31.0", msrv: None, desc: "using `filter_map` when a more succinct alternative exists" },
+    LintInfo { name: "unnecessary_find_map", group: "complexity", level: "warn", version: "1.61.0", msrv: None, desc: "using `find_map` when a more succinct alternative exists" },
+    LintInfo { name: "unnecessary_fold", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `fold` when a more succinct alternative exists" },
+    LintInfo { name: "unnecessary_join", group: "pedantic", level: "allow", version: "1.61.0", msrv: None, desc: "using `.collect::<Vec<String>>().join(\\\"\\\")` on an iterator" },
+    LintInfo { name: "unnecessary_lazy_evaluations", group: "style", level: "warn", version: "1.48.0", msrv: None, desc: "using unnecessary lazy evaluation, which can be replaced with simpler eager evaluation" },
+    LintInfo { name: "unnecessary_mut_passed", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "an argument passed as a mutable reference although the callee only demands an immutable reference" },
+    LintInfo { name: "unnecessary_operation", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "outer expressions with no effect" },
+    LintInfo { name: "unnecessary_owned_empty_strings", group: "style", level: "warn", version: "1.62.0", msrv: None, desc: "detects cases of references to owned empty strings being passed as an argument to a function expecting `&str`" },
+    LintInfo { name: "unnecessary_self_imports", group: "restriction", level: "allow", version: "1.53.0", msrv: None, desc: "imports ending in `::{self}`, which can be omitted" },
+    LintInfo { name: "unnecessary_sort_by", group: "complexity", level: "warn", version: "1.46.0", msrv: None, desc: "Use of `Vec::sort_by` when `Vec::sort_by_key` or `Vec::sort` would be clearer" },
+    LintInfo { name: "unnecessary_to_owned", group: "perf", level: "warn", version: "1.59.0", msrv: None, desc: "unnecessary calls to `to_owned`-like functions" },
+    LintInfo { name: "unnecessary_unwrap", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "checks for calls of `unwrap[_err]()` that cannot fail" },
+    LintInfo { name: "unnecessary_wraps", group: "pedantic", level: "allow", version: "1.50.0", msrv: None, desc: "functions that only return `Ok` or `Some`" },
+    LintInfo { name: "unneeded_field_pattern", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "struct fields bound to a wildcard instead of using `..`" },
+    LintInfo { name: "unneeded_wildcard_pattern", group: "complexity", level: "warn", version: "1.40.0", msrv: None, desc: "tuple patterns with a wildcard pattern (`_`) is next to a rest pattern (`..`)" },
+    LintInfo { name: "unnested_or_patterns", group: "pedantic", level: "allow", version: "1.46.0", msrv: Some("1.53.0"), desc: "unnested or-patterns, e.g., `Foo(Bar) | Foo(Baz) instead of `Foo(Bar | Baz)`" },
+    LintInfo { name: "unreachable", group: "restriction", level: "allow", version: "1.40.0", msrv: None, desc: "usage of the `unreachable!` macro" },
+    LintInfo { name: "unreadable_literal", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "long literal without underscores" },
+    LintInfo { name: "unsafe_derive_deserialize", group: "pedantic", level: "allow", version: "1.45.0", msrv: None, desc: "deriving `serde::Deserialize` on a type that has methods using `unsafe`" },
+    LintInfo { name: "unsafe_removed_from_name", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "`unsafe` removed from API names on import" },
+    LintInfo { name: "unseparated_literal_suffix", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "literals whose suffix is not separated by an underscore" },
+    LintInfo { name: "unsound_collection_transmute", group: "correctness", level: "deny", version: "1.40.0", msrv: None, desc: "transmute between collections of layout-incompatible types" },
+    LintInfo { name: "unused_async", group: "pedantic", level: "allow", version: "1.54.0", msrv: None, desc: "finds async functions with no await statements" },
+    LintInfo { name: "unused_io_amount", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "unused written/read amount" },
+    LintInfo { name: "unused_peekable", group: "suspicious", level: "warn", version: "1.64.0", msrv: None, desc: "creating a peekable iterator without using any of its methods" },
+    LintInfo { name: "unused_rounding", group: "nursery", level: "allow", version: "1.63.0", msrv: None, desc: "Uselessly rounding a whole number floating-point literal" },
+    LintInfo { name: "unused_self", group: "pedantic", level: "allow", version: "1.40.0", msrv: None, desc: "methods that contain a `self` argument but don't use it" },
+    LintInfo { name: "unused_unit", group: "style", level: "warn", version: "1.31.0", msrv: None, desc: "needless unit expression" },
+    LintInfo { name: "unusual_byte_groupings", group: "style", level: "warn", version: "1.49.0", msrv: None, desc: "binary or hex literals that aren't grouped by four" },
+    LintInfo { name: "unwrap_in_result", group: "restriction", level: "allow", version: "1.48.0", msrv: None, desc: "functions of type `Result<..>` or `Option`<...> that contain `expect()` or `unwrap()`" },
+    LintInfo { name: "unwrap_or_else_default", group: "style", level: "warn", version: "1.56.0", msrv: None, desc: "using `.unwrap_or_else(Default::default)`, which is more succinctly expressed as `.unwrap_or_default()`" },
+    LintInfo { name: "unwrap_used", group: "restriction", level: "allow", version: "1.45.0", msrv: None, desc: "using `.unwrap()` on `Result` or `Option`, which should at least get a better message using `expect()`" },
+    LintInfo { name: "upper_case_acronyms", group: "style", level: "warn", version: "1.51.0", msrv: None, desc: "capitalized acronyms are against the naming convention" },
+    LintInfo { name: "use_debug", group: "restriction", level: "allow", version: "pre 1.29.0", msrv: None, desc: "use of `Debug`-based formatting" },
+    LintInfo { name: "use_self", group: "nursery", level: "allow", version: "pre 1.29.0", msrv: Some("1.37.0"), desc: "unnecessary structure name repetition whereas `Self` is applicable" },
+    LintInfo { name: "used_underscore_binding", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "using a binding which is prefixed with an underscore" },
+    LintInfo { name: "useless_asref", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `as_ref` where the types before and after the call are the same" },
+    LintInfo { name: "useless_attribute", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "use of lint attributes on `extern crate` items" },
+    LintInfo { name: "useless_conversion", group: "complexity", level: "warn", version: "1.45.0", msrv: None, desc: "calls to `Into`, `TryInto`, `From`, `TryFrom`, or `IntoIter` which perform useless conversions to the same type" },
+    LintInfo { name: "useless_format", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "useless use of `format!`" },
+    LintInfo { name: "useless_let_if_seq", group: "nursery", level: "allow", version: "pre 1.29.0", msrv: None, desc: "unidiomatic `let mut` declaration followed by initialization in `if`" },
+    LintInfo { name: "useless_transmute", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "transmutes that have the same to and from types or could be a cast/coercion" },
+    LintInfo { name: "useless_vec", group: "perf", level: "warn", version: "pre 1.29.0", msrv: None, desc: "useless `vec!`" },
+    LintInfo { name: "vec_box", group: "complexity", level: "warn", version: "1.33.0", msrv: None, desc: "usage of `Vec<Box<T>>` where T: Sized, vector elements are already on the heap" },
+    LintInfo { name: "vec_init_then_push", group: "perf", level: "warn", version: "1.51.0", msrv: None, desc: "`push` immediately after `Vec` creation" },
+    LintInfo { name: "vec_resize_to_zero", group: "correctness", level: "deny", version: "1.46.0", msrv: None, desc: "emptying a vector with `resize(0, an_int)` instead of `clear()` is probably an argument inversion mistake" },
+    LintInfo { name: "verbose_bit_mask", group: "pedantic", level: "allow", version: "pre 1.29.0", msrv: None, desc: "expressions where a bit mask is less readable than the corresponding method call" },
+    LintInfo { name: "verbose_file_reads", group: "restriction", level: "allow", version: "1.44.0", msrv: None, desc: "use of `File::read_to_end` or `File::read_to_string`" },
+    LintInfo { name: "vtable_address_comparisons", group: "correctness", level: "deny", version: "1.44.0", msrv: None, desc: "comparison with an address of a trait vtable" },
+    LintInfo { name: "while_immutable_condition", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "variables used within while expression are not mutated in the body" },
+    LintInfo { name: "while_let_loop", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "`loop { if let { ... } else break }`, which can be written as a `while let` loop" },
+    LintInfo { name: "while_let_on_iterator", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using a `while let` loop instead of a for loop on an iterator" },
+    LintInfo { name: "wildcard_dependencies", group: "cargo", level: "allow", version: "1.32.0", msrv: None, desc: "wildcard dependencies being used" },
+    LintInfo { name: "wildcard_enum_match_arm", group: "restriction", level: "allow", version: "1.34.0", msrv: None, desc: "a wildcard enum match arm using `_`" },
+    LintInfo { name: "wildcard_imports", group: "pedantic", level: "allow", version: "1.43.0", msrv: None, desc: "lint `use _::*` statements" },
+    LintInfo { name: "wildcard_in_or_patterns", group: "complexity", level: "warn", version: "1.42.0", msrv: None, desc: "a wildcard pattern used with others patterns in same match arm" },
+    LintInfo { name: "write_literal", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "writing a literal with a format string" },
+    LintInfo { name: "write_with_newline", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `write!()` with a format string that ends in a single newline" },
+    LintInfo { name: "writeln_empty_string", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `writeln!(buf, \\\"\\\")` with an empty string" },
+    LintInfo { name: "wrong_self_convention", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "defining a method named with an established prefix (like \\\"into_\\\") that takes `self` with the wrong convention" },
+    LintInfo { name: "wrong_transmute", group: "correctness", level: "deny", version: "pre 1.29.0", msrv: None, desc: "transmutes that are confusing at best, undefined behavior at worst and always useless" },
+    LintInfo { name: "zero_divided_by_zero", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "usage of `0.0 / 0.0` to obtain NaN instead of `f32::NAN` or `f64::NAN`" },
+    LintInfo { name: "zero_prefixed_literal", group: "complexity", level: "warn", version: "pre 1.29.0", msrv: None, desc: "integer literals starting with `0`" },
+    LintInfo { name: "zero_ptr", group: "style", level: "warn", version: "pre 1.29.0", msrv: None, desc: "using `0 as *{const, mut} T`" },
+    LintInfo { name: "zero_sized_map_values", group: "pedantic", level: "allow", version: "1.50.0", msrv: None, desc: "usage of map with zero-sized value type" },
+    LintInfo { name: "zst_offset", group: "correctness", level: "deny", version: "1.41.0", msrv: None, desc: "Check for offset calculations on raw pointers to zero-sized types" },
+];
+
+#[rustfmt::skip]
+pub static CONFIGS: &[ConfigInfo] = &[
+    ConfigInfo { name: "arithmetic-side-effects-allowed", ty: "FxHashSet<String>", default: "<_>::default()", doc: "Suppress checking of the passed type names.", lints: &["arithmetic_side_effects"] },
+    ConfigInfo { name: "avoid-breaking-exported-api", ty: "bool", default: "true", doc: "Suppress lints whenever the suggested change would cause breakage for other crates.", lints: &["enum_variant_names", "large_types_passed_by_value", "trivially_copy_pass_by_ref", "unnecessary_wraps", "unused_self", "upper_case_acronyms", "wrong_self_convention", "box_collection", "redundant_allocation", "rc_buffer", "vec_box", "option_option", "linkedlist", "rc_mutex"] },
+    ConfigInfo { name: "msrv", ty: "Option<String>", default: "None", doc: "The minimum rust version that the project supports", lints: &["manual_split_once", "manual_str_repeat", "cloned_instead_of_copied", "redundant_field_names", "redundant_static_lifetimes", "filter_map_next", "checked_conversions", "manual_range_contains", "use_self", "mem_replace_with_default", "manual_non_exhaustive", "option_as_ref_deref", "map_unwrap_or", "match_like_matches_macro", "manual_strip", "missing_const_for_fn", "unnested_or_patterns", "from_over_into", "ptr_as_ptr", "if_then_some_else_none", "approx_constant", "deprecated_cfg_attr", "index_refutable_slice", "map_clone", "borrow_as_ptr", "manual_bits", "err_expect", "cast_abs_to_unsigned", "uninlined_format_args"] },
+    ConfigInfo { name: "cognitive-complexity-threshold", ty: "u64", default: "25", doc: "The maximum cognitive complexity a function can have", lints: &["cognitive_complexity"] },
+    ConfigInfo { name: "disallowed-names", ty: "Vec<String>", default: "[\"foo\", \"baz\", \"quux\"]", doc: "The list of disallowed names to lint about. NB: `bar` is not here since it has legitimate uses. The value\n`\"..\"` can be used as part of the list to indicate, that the configured values should be appended to the\ndefault configuration of Clippy. By default any configuration will replace the default value.", lints: &["disallowed_names"] },
+    ConfigInfo { name: "doc-valid-idents", ty: "Vec<String>", default: "[\"KiB\", \"MiB\", \"GiB\", \"TiB\", \"PiB\", \"EiB\", \"DirectX\", \"ECMAScript\", \"GPLv2\", \"GPLv3\", \"GitHub\", \"GitLab\", \"IPv4\", \"IPv6\", \"ClojureScript\", \"CoffeeScript\", \"JavaScript\", \"PureScript\", \"TypeScript\", \"NaN\", \"NaNs\", \"OAuth\", \"GraphQL\", \"OCaml\", \"OpenGL\", \"OpenMP\", \"OpenSSH\", \"OpenSSL\", \"OpenStreetMap\", \"OpenDNS\", \"WebGL\", \"TensorFlow\", \"TrueType\", \"iOS\", \"macOS\", \"FreeBSD\", \"TeX\", \"LaTeX\", \"BibTeX\", \"BibLaTeX\", \"MinGW\", \"CamelCase\"]", doc: "The list of words this lint should not consider as identifiers needing ticks. The value\n`\"..\"` can be used as part of the list to indicate, that the configured values should be appended to the\ndefault configuration of Clippy. By default any configuraction will replace the default value. For example:\n* `doc-valid-idents = [\"ClipPy\"]` would replace the default list with `[\"ClipPy\"]`.\n* `doc-valid-idents = [\"ClipPy\", \"..\"]` would append `ClipPy` to the default list.\n\nDefault list:", lints: &["doc_markdown"] },
+    ConfigInfo { name: "too-many-arguments-threshold", ty: "u64", default: "7", doc: "The maximum number of argument a function or method can have\n\nCan also be set per path in `[[overrides]]`.", lints: &["too_many_arguments"] },
+    ConfigInfo { name: "type-complexity-threshold", ty: "u64", default: "250", doc: "The maximum complexity a type can have\n\nCan also be set per path in `[[overrides]]`.", lints: &["type_complexity"] },
+    ConfigInfo { name: "single-char-binding-names-threshold", ty: "u64", default: "4", doc: "The maximum number of single char bindings a scope may have", lints: &["many_single_char_names"] },
+    ConfigInfo { name: "too-large-for-stack", ty: "u64", default: "200", doc: "The maximum size of objects (in bytes) that will be linted. Larger objects are ok on the heap", lints: &["boxed_local", "useless_vec"] },
+    ConfigInfo { name: "enum-variant-name-threshold", ty: "u64", default: "3", doc: "The minimum number of enum variants for the lints about variant names to trigger", lints: &["enum_variant_names"] },
+    ConfigInfo { name: "enum-variant-size-threshold", ty: "u64", default: "200", doc: "The maximum size of an enum's variant to avoid box suggestion", lints: &["large_enum_variant"] },
+    ConfigInfo { name: "verbose-bit-mask-threshold", ty: "u64", default: "1", doc: "The maximum allowed size of a bit mask before suggesting to use 'trailing_zeros'", lints: &["verbose_bit_mask"] },
+    ConfigInfo { name: "literal-representation-threshold", ty: "u64", default: "16384", doc: "The lower bound for linting decimal literals", lints: &["decimal_literal_representation"] },
+    ConfigInfo { name: "trivial-copy-size-limit", ty: "Option<u64>", default: "None", doc: "The maximum size (in bytes) to consider a `Copy` type for passing by value instead of by reference.", lints: &["trivially_copy_pass_by_ref"] },
+    ConfigInfo { name: "pass-by-value-size-limit", ty: "u64", default: "256", doc: "The minimum size (in bytes) to consider a type for passing by reference instead of by value.", lints: &[] },
+    ConfigInfo { name: "too-many-lines-threshold", ty: "u64", default: "100", doc: "The maximum number of lines a function or method can have\n\nCan also be set per path in `[[overrides]]`.", lints: &["too_many_lines"] },
+    ConfigInfo { name: "array-size-threshold", ty: "u64", default: "512_000", doc: "The maximum allowed size for arrays on the stack", lints: &["large_stack_arrays", "large_const_arrays"] },
+    ConfigInfo { name: "vec-box-size-threshold", ty: "u64", default: "4096", doc: "The size of the boxed type in bytes, where boxing in a `Vec` is allowed", lints: &["vec_box"] },
+    ConfigInfo { name: "max-trait-bounds", ty: "u64", default: "3", doc: "The maximum number of bounds a trait can have to be linted", lints: &["type_repetition_in_bounds"] },
+    ConfigInfo { name: "max-struct-bools", ty: "u64", default: "3", doc: "The maximum number of bool fields a struct can have\n\nCan also be set per path in `[[overrides]]`.", lints: &["struct_excessive_bools"] },
+    ConfigInfo { name: "max-fn-params-bools", ty: "u64", default: "3", doc: "The maximum number of bool parameters a function can have\n\nCan also be set per path in `[[overrides]]`.", lints: &["fn_params_excessive_bools"] },
+    ConfigInfo { name: "warn-on-all-wildcard-imports", ty: "bool", default: "false", doc: "Whether to allow certain wildcard imports (prelude, super in tests).", lints: &["wildcard_imports"] },
+    ConfigInfo { name: "disallowed-methods", ty: "Vec<DisallowedPath>", default: "Vec::new()", doc: "The list of disallowed methods, written as fully qualified paths.", lints: &["disallowed_methods"] },
+    ConfigInfo { name: "disallowed-macros", ty: "Vec<DisallowedPath>", default: "Vec::new()", doc: "The list of disallowed macros, written as fully qualified paths.", lints: &["disallowed_macros"] },
+    ConfigInfo { name: "disallowed-imports", ty: "Vec<DisallowedPath>", default: "Vec::new()", doc: "The list of crates and modules that may not be imported, written as paths like `tokio` or `std::net`.", lints: &["disallowed_imports", "disallowed_dependencies"] },
+    ConfigInfo { name: "disallowed-types", ty: "Vec<DisallowedType>", default: "Vec::new()", doc: "The list of disallowed types, written as fully qualified paths.", lints: &["disallowed_types"] },
+    ConfigInfo { name: "unreadable-literal-lint-fractions", ty: "bool", default: "true", doc: "Should the fraction of a decimal be linted to include separators.", lints: &["unreadable_literal"] },
+    ConfigInfo { name: "upper-case-acronyms-aggressive", ty: "bool", default: "false", doc: "Enables verbose mode. Triggers if there is more than one uppercase char next to each other", lints: &["upper_case_acronyms"] },
+    ConfigInfo { name: "cargo-ignore-publish", ty: "bool", default: "false", doc: "For internal testing only, ignores the current `publish` settings in the Cargo manifest.", lints: &[] },
+    ConfigInfo { name: "standard-macro-braces", ty: "Vec<MacroMatcher>", default: "Vec::new()", doc: "Enforce the named macros always use the braces specified.\n\nA `MacroMatcher` can be added like so `{ name = \"macro_name\", brace = \"(\" }`. If the macro\nis could be used with a full path two `MacroMatcher`s have to be added one with the full path\n`crate_name::macro_name` and one with just the macro name.", lints: &["nonstandard_macro_braces"] },
+    ConfigInfo { name: "enforced-import-renames", ty: "Vec<Rename>", default: "Vec::new()", doc: "The list of imports to always rename, a fully qualified path followed by the rename.", lints: &["missing_enforced_import_renames"] },
+    ConfigInfo { name: "allowed-scripts", ty: "Vec<String>", default: "vec![\"Latin\".to_string()]", doc: "The list of unicode scripts allowed to be used in the scope.", lints: &["disallowed_script_idents"] },
+    ConfigInfo { name: "enable-raw-pointer-heuristic-for-send", ty: "bool", default: "true", doc: "Whether to apply the raw pointer heuristic to determine if a type is `Send`.", lints: &["non_send_fields_in_send_ty"] },
+    ConfigInfo { name: "max-suggested-slice-pattern-length", ty: "u64", default: "3", doc: "When Clippy suggests using a slice pattern, this is the maximum number of elements allowed in\nthe slice pattern that is suggested. If more elements would be necessary, the lint is suppressed.\nFor example, `[_, _, _, e, ..]` is a slice pattern with 4 elements.", lints: &["index_refutable_slice"] },
+    ConfigInfo { name: "await-holding-invalid-types", ty: "Vec<DisallowedType>", default: "Vec::new()", doc: "", lints: &["await_holding_invalid_type"] },
+    ConfigInfo { name: "max-include-file-size", ty: "u64", default: "1_000_000", doc: "The maximum size of a file included via `include_bytes!()` or `include_str!()`, in bytes", lints: &["large_include_file"] },
+    ConfigInfo { name: "allow-expect-in-tests", ty: "bool", default: "false", doc: "Whether `expect` should be allowed in test functions", lints: &["expect_used"] },
+    ConfigInfo { name: "allow-unwrap-in-tests", ty: "bool", default: "false", doc: "Whether `unwrap` should be allowed in test functions", lints: &["unwrap_used"] },
+    ConfigInfo { name: "allow-dbg-in-tests", ty: "bool", default: "false", doc: "Whether `dbg!` should be allowed in test functions", lints: &["dbg_macro"] },
+    ConfigInfo { name: "large-error-threshold", ty: "u64", default: "128", doc: "The maximum size of the `Err`-variant in a `Result` returned from a function\n\nCan also be set per path in `[[overrides]]`.", lints: &["result_large_err"] },
+    ConfigInfo { name: "overrides", ty: "Vec<ConfOverride>", default: "Vec::new()", doc: "Changes the configuration and lint levels for the files matching a list of path globs. For example:\n```toml\n[[overrides]]\npath = [\"tests/**\", \"src/generated/**\"]\ntoo-many-lines-threshold = 300\nlints = { \"clippy::unwrap_used\" = \"allow\" }\n```\nThe options that can be set per path say so in their documentation. Levels set with attributes in the source\ncode still take precedence, and levels forbidden with `-F` are never lowered. The levels don't apply to the\nlints checked before type checking, like `double_neg`.", lints: &[] },
+    ConfigInfo { name: "lints", ty: "BTreeMap<String, LintLevel>", default: "std::collections::BTreeMap::new()", doc: "Lint levels for all crates using this configuration, keyed by lint or lint group names. Names without a tool\nprefix are Clippy lints. For example:\n```toml\n[lints]\npedantic = \"warn\"\nneedless_return = \"allow\"\n```\nLint groups are applied before single lints, and flags on the command line take precedence.", lints: &[] },
+];
+
+#[rustfmt::skip]
+pub static DEPRECATED: &[DeprecatedInfo] = &[
+    DeprecatedInfo { name: "assign_ops", reason: "using compound assignment operators (e.g., `+=`) is harmless" },
+    DeprecatedInfo { name: "extend_from_slice", reason: "`.extend_from_slice(_)` is a faster way to extend a Vec by a slice" },
+    DeprecatedInfo { name: "filter_map", reason: "this lint has been replaced by `manual_filter_map`, a more specific lint" },
+    DeprecatedInfo { name: "find_map", reason: "this lint has been replaced by `manual_find_map`, a more specific lint" },
+    DeprecatedInfo { name: "if_let_redundant_pattern_matching", reason: "this lint has been changed to redundant_pattern_matching" },
+    DeprecatedInfo { name: "misaligned_transmute", reason: "this lint has been split into cast_ptr_alignment and transmute_ptr_to_ptr" },
+    DeprecatedInfo { name: "pub_enum_variant_names", reason: "set the `avoid-breaking-exported-api` config option to `false` to enable the `enum_variant_names` lint for public items" },
+    DeprecatedInfo { name: "range_step_by_zero", reason: "`iterator.step_by(0)` panics nowadays" },
+    DeprecatedInfo { name: "regex_macro", reason: "the regex! macro has been removed from the regex crate in 2018" },
+    DeprecatedInfo { name: "replace_consts", reason: "associated-constants `MIN`/`MAX` of integers are preferred to `{min,max}_value()` and module constants" },
+    DeprecatedInfo { name: "should_assert_eq", reason: "`assert!()` will be more flexible with RFC 2011" },
+    DeprecatedInfo { name: "unsafe_vector_initialization", reason: "the replacement suggested by this lint had substantially different behavior" },
+    DeprecatedInfo { name: "unstable_as_mut_slice", reason: "`Vec::as_mut_slice` has been stabilized in 1.7" },
+    DeprecatedInfo { name: "unstable_as_slice", reason: "`Vec::as_slice` has been stabilized in 1.7" },
+    DeprecatedInfo { name: "unused_collect", reason: "`collect` has been marked as #[must_use] in rustc and that covers all cases of this lint" },
+    DeprecatedInfo { name: "wrong_pub_self_convention", reason: "set the `avoid-breaking-exported-api` config option to `false` to enable the `wrong_self_convention` lint for public items" },
+];
+
+#[rustfmt::skip]
+pub static RENAMED: &[RenamedInfo] = &[
+    RenamedInfo { old_name: "blacklisted_name", new_name: "clippy::disallowed_names" },
+    RenamedInfo { old_name: "block_in_if_condition_expr", new_name: "clippy::blocks_in_if_conditions" },
+    RenamedInfo { old_name: "block_in_if_condition_stmt", new_name: "clippy::blocks_in_if_conditions" },
+    RenamedInfo { old_name: "box_vec", new_name: "clippy::box_collection" },
+    RenamedInfo { old_name: "const_static_lifetime", new_name: "clippy::redundant_static_lifetimes" },
+    RenamedInfo { old_name: "cyclomatic_complexity", new_name: "clippy::cognitive_complexity" },
+    RenamedInfo { old_name: "disallowed_method", new_name: "clippy::disallowed_methods" },
+    RenamedInfo { old_name: "disallowed_type", new_name: "clippy::disallowed_types" },
+    RenamedInfo { old_name: "drop_bounds", new_name: "drop_bounds" },
+    RenamedInfo { old_name: "eval_order_dependence", new_name: "clippy::mixed_read_write_in_expression" },
+    RenamedInfo { old_name: "for_loop_over_option", new_name: "clippy::for_loops_over_fallibles" },
+    RenamedInfo { old_name: "for_loop_over_result", new_name: "clippy::for_loops_over_fallibles" },
+    RenamedInfo { old_name: "identity_conversion", new_name: "clippy::useless_conversion" },
+    RenamedInfo { old_name: "if_let_some_result", new_name: "clippy::match_result_ok" },
+    RenamedInfo { old_name: "into_iter_on_array", new_name: "array_into_iter" },
+    RenamedInfo { old_name: "invalid_atomic_ordering", new_name: "invalid_atomic_ordering" },
+    RenamedInfo { old_name: "invalid_ref", new_name: "invalid_value" },
+    RenamedInfo { old_name: "logic_bug", new_name: "clippy::overly_complex_bool_expr" },
+    RenamedInfo { old_name: "mem_discriminant_non_enum", new_name: "enum_intrinsics_non_enums" },
+    RenamedInfo { old_name: "new_without_default_derive", new_name: "clippy::new_without_default" },
+    RenamedInfo { old_name: "option_and_then_some", new_name: "clippy::bind_instead_of_map" },
+    RenamedInfo { old_name: "option_expect_used", new_name: "clippy::expect_used" },
+    RenamedInfo { old_name: "option_map_unwrap_or", new_name: "clippy::map_unwrap_or" },
+    RenamedInfo { old_name: "option_map_unwrap_or_else", new_name: "clippy::map_unwrap_or" },
+    RenamedInfo { old_name: "option_unwrap_used", new_name: "clippy::unwrap_used" },
+    RenamedInfo { old_name: "panic_params", new_name: "non_fmt_panics" },
+    RenamedInfo { old_name: "positional_named_format_parameters", new_name: "named_arguments_used_positionally" },
+    RenamedInfo { old_name: "ref_in_deref", new_name: "clippy::needless_borrow" },
+    RenamedInfo { old_name: "result_expect_used", new_name: "clippy::expect_used" },
+    RenamedInfo { old_name: "result_map_unwrap_or_else", new_name: "clippy::map_unwrap_or" },
+    RenamedInfo { old_name: "result_unwrap_used", new_name: "clippy::unwrap_used" },
+    RenamedInfo { old_name: "single_char_push_str", new_name: "clippy::single_char_add_str" },
+    RenamedInfo { old_name: "stutter", new_name: "clippy::module_name_repetitions" },
+    RenamedInfo { old_name: "temporary_cstring_as_ptr", new_name: "temporary_cstring_as_ptr" },
+    RenamedInfo { old_name: "to_string_in_display", new_name: "clippy::recursive_format_impl" },
+    RenamedInfo { old_name: "unknown_clippy_lints", new_name: "unknown_lints" },
+    RenamedInfo { old_name: "unused_label", new_name: "unused_labels" },
+    RenamedInfo { old_name: "zero_width_space", new_name: "clippy::invisible_characters" },
+];
+
+/// The highest applicability of the suggestions of each lint, from the metadata collector. Unlike
+/// the lists above, it's updated by `cargo collect-metadata`.
+#[rustfmt::skip]
+pub static APPLICABILITIES: &[(&str, &str)] = &[
+    ("almost_complete_letter_range", "MaybeIncorrect"),
+    ("almost_swapped", "MaybeIncorrect"),
+    ("as_underscore", "MachineApplicable"),
+    ("assertions_on_result_states", "MachineApplicable"),
+    ("assign_op_pattern", "MachineApplicable"),
+    ("async_yields_async", "MaybeIncorrect"),
+    ("bind_instead_of_map", "MachineApplicable"),
+    ("bool_assert_comparison", "MaybeIncorrect"),
+    ("bool_to_int_with_if", "MachineApplicable"),
+    ("borrow_as_ptr", "MachineApplicable"),
+    ("borrow_deref_ref", "MachineApplicable"),
+    ("borrowed_box", "Unspecified"),
+    ("branches_sharing_code", "Unspecified"),
+    ("bytes_count_to_len", "MachineApplicable"),
+    ("bytes_nth", "MachineApplicable"),
+    ("cast_abs_to_unsigned", "MachineApplicable"),
+    ("cast_lossless", "MachineApplicable"),
+    ("cast_slice_from_raw_parts", "MachineApplicable"),
+    ("char_lit_as_u8", "MachineApplicable"),
+    ("checked_conversions", "MachineApplicable"),
+    ("clone_double_ref", "MaybeIncorrect"),
+    ("clone_on_copy", "MachineApplicable"),
+    ("clone_on_ref_ptr", "Unspecified"),
+    ("cloned_instead_of_copied", "MachineApplicable"),
+    ("cmp_owned", "MachineApplicable"),
+    ("collapsible_else_if", "MachineApplicable"),
+    ("collapsible_if", "MachineApplicable"),
+    ("collapsible_str_replace", "MachineApplicable"),
+    ("comparison_to_empty", "MachineApplicable"),
+    ("crate_in_macro_def", "MachineApplicable"),
+    ("create_dir", "MaybeIncorrect"),
+    ("dbg_macro", "MachineApplicable"),
+    ("decimal_literal_representation", "MachineApplicable"),
+    ("default_instead_of_iter_empty", "MachineApplicable"),
+    ("default_numeric_fallback", "MaybeIncorrect"),
+    ("default_trait_access", "Unspecified"),
+    ("deprecated_cfg_attr", "MachineApplicable"),
+    ("deref_addrof", "MachineApplicable"),
+    ("derivable_impls", "MachineApplicable"),
+    ("derive_partial_eq_without_eq", "MachineApplicable"),
+    ("doc_markdown", "MachineApplicable"),
+    ("double_comparisons", "MachineApplicable"),
+    ("duration_subsec", "MachineApplicable"),
+    ("empty_drop", "MaybeIncorrect"),
+    ("empty_structs_with_brackets", "MachineApplicable"),
+    ("enum_glob_use", "MachineApplicable"),
+    ("equatable_if_let", "MachineApplicable"),
+    ("err_expect", "MachineApplicable"),
+    ("excessive_precision", "MachineApplicable"),
+    ("exhaustive_enums", "MaybeIncorrect"),
+    ("exhaustive_structs", "MaybeIncorrect"),
+    ("expect_fun_call", "MachineApplicable"),
+    ("explicit_auto_deref", "MachineApplicable"),
+    ("explicit_counter_loop", "MaybeIncorrect"),
+    ("explicit_deref_methods", "MachineApplicable"),
+    ("explicit_into_iter_loop", "MachineApplicable"),
+    ("explicit_iter_loop", "MachineApplicable"),
+    ("explicit_write", "MachineApplicable"),
+    ("extend_with_drain", "MachineApplicable"),
+    ("filter_map_identity", "MachineApplicable"),
+    ("flat_map_identity", "MachineApplicable"),
+    ("flat_map_option", "MachineApplicable"),
+    ("float_equality_without_abs", "MaybeIncorrect"),
+    ("fn_to_numeric_cast", "MaybeIncorrect"),
+    ("fn_to_numeric_cast_any", "MaybeIncorrect"),
+    ("fn_to_numeric_cast_with_truncation", "MaybeIncorrect"),
+    ("from_iter_instead_of_collect", "MaybeIncorrect"),
+    ("from_str_radix_10", "MaybeIncorrect"),
+    ("get_first", "MachineApplicable"),
+    ("get_last_with_len", "MachineApplicable"),
+    ("get_unwrap", "MachineApplicable"),
+    ("identity_op", "MachineApplicable"),
+    ("implicit_clone", "MachineApplicable"),
+    ("implicit_return", "MachineApplicable"),
+    ("implicit_saturating_sub", "MachineApplicable"),
+    ("imprecise_flops", "MachineApplicable"),
+    ("inconsistent_digit_grouping", "MachineApplicable"),
+    ("inconsistent_struct_constructor", "MachineApplicable"),
+    ("index_refutable_slice", "MaybeIncorrect"),
+    ("inefficient_to_string", "MachineApplicable"),
+    ("infallible_destructuring_match", "MachineApplicable"),
+    ("init_numbered_fields", "MachineApplicable"),
+    ("inline_fn_without_body", "MachineApplicable"),
+    ("int_plus_one", "MachineApplicable"),
+    ("into_iter_on_ref", "MachineApplicable"),
+    ("invalid_null_ptr_usage", "MachineApplicable"),
+    ("invisible_characters", "MachineApplicable"),
+    ("is_digit_ascii_radix", "MachineApplicable"),
+    ("iter_cloned_collect", "MachineApplicable"),
+    ("iter_count", "MachineApplicable"),
+    ("iter_next_slice", "MachineApplicable"),
+    ("iter_nth_zero", "MachineApplicable"),
+    ("iter_on_empty_collections", "MaybeIncorrect"),
+    ("iter_on_single_items", "MaybeIncorrect"),
+    ("iter_overeager_cloned", "MachineApplicable"),
+    ("iter_skip_next", "MachineApplicable"),
+    ("iter_with_drain", "MaybeIncorrect"),
+    ("large_const_arrays", "MachineApplicable"),
+    ("large_digit_groups", "MachineApplicable"),
+    ("large_enum_variant", "MaybeIncorrect"),
+    ("large_types_passed_by_value", "MaybeIncorrect"),
+    ("len_zero", "MachineApplicable"),
+    ("let_and_return", "MachineApplicable"),
+    ("let_unit_value", "MachineApplicable"),
+    ("lossy_float_literal", "MachineApplicable"),
+    ("macro_use_imports", "MaybeIncorrect"),
+    ("manual_assert", "MachineApplicable"),
+    ("manual_async_fn", "MachineApplicable"),
+    ("manual_bits", "MachineApplicable"),
+    ("manual_filter_map", "MachineApplicable"),
+    ("manual_find", "MachineApplicable"),
+    ("manual_find_map", "MachineApplicable"),
+    ("manual_flatten", "MaybeIncorrect"),
+    ("manual_instant_elapsed", "MachineApplicable"),
+    ("manual_map", "MachineApplicable"),
+    ("manual_memcpy", "Unspecified"),
+    ("manual_non_exhaustive", "Unspecified"),
+    ("manual_ok_or", "MachineApplicable"),
+    ("manual_range_contains", "MachineApplicable"),
+    ("manual_rem_euclid", "MachineApplicable"),
+    ("manual_retain", "MachineApplicable"),
+    ("manual_saturating_arithmetic", "MachineApplicable"),
+    ("manual_split_once", "MachineApplicable"),
+    ("manual_str_repeat", "MachineApplicable"),
+    ("manual_string_new", "MachineApplicable"),
+    ("manual_swap", "MachineApplicable"),
+    ("manual_unwrap_or", "MachineApplicable"),
+    ("map_clone", "MachineApplicable"),
+    ("map_collect_result_unit", "MachineApplicable"),
+    ("map_entry", "MachineApplicable"),
+    ("map_flatten", "MachineApplicable"),
+    ("map_identity", "MachineApplicable"),
+    ("map_unwrap_or", "MachineApplicable"),
+    ("match_as_ref", "MachineApplicable"),
+    ("match_bool", "HasPlaceholders"),
+    ("match_like_matches_macro", "MaybeIncorrect"),
+    ("match_on_vec_items", "MaybeIncorrect"),
+    ("match_result_ok", "MachineApplicable"),
+    ("match_same_arms", "MaybeIncorrect"),
+    ("match_single_binding", "MachineApplicable"),
+    ("match_str_case_mismatch", "MachineApplicable"),
+    ("match_wildcard_for_single_variants", "MaybeIncorrect"),
+    ("mem_replace_option_with_none", "MachineApplicable"),
+    ("mem_replace_with_default", "MachineApplicable"),
+    ("mismatched_target_os", "MaybeIncorrect"),
+    ("misrefactored_assign_op", "MaybeIncorrect"),
+    ("missing_enforced_import_renames", "MachineApplicable"),
+    ("missing_spin_loop", "MachineApplicable"),
+    ("mistyped_literal_suffixes", "MaybeIncorrect"),
+    ("must_use_candidate", "MachineApplicable"),
+    ("must_use_unit", "MachineApplicable"),
+    ("mut_mutex_lock", "MaybeIncorrect"),
+    ("naive_bytecount", "MaybeIncorrect"),
+    ("needless_arbitrary_self_type", "MachineApplicable"),
+    ("needless_bitwise_bool", "MachineApplicable"),
+    ("needless_borrow", "MachineApplicable"),
+    ("needless_borrowed_reference", "MachineApplicable"),
+    ("needless_collect", "MaybeIncorrect"),
+    ("needless_for_each", "MachineApplicable"),
+    ("needless_match", "MachineApplicable"),
+    ("needless_option_as_deref", "MachineApplicable"),
+    ("needless_option_take", "MachineApplicable"),
+    ("needless_parens_on_range_literals", "MachineApplicable"),
+    ("needless_question_mark", "MachineApplicable"),
+    ("needless_return", "MachineApplicable"),
+    ("needless_splitn", "MachineApplicable"),
+    ("neg_multiply", "MachineApplicable"),
+    ("never_loop", "Unspecified"),
+    ("new_without_default", "MaybeIncorrect"),
+    ("non_ascii_literal", "MachineApplicable"),
+    ("non_octal_unix_permissions", "MachineApplicable"),
+    ("nonminimal_bool", "MachineApplicable"),
+    ("nonstandard_macro_braces", "MachineApplicable"),
+    ("obfuscated_if_else", "MachineApplicable"),
+    ("octal_escapes", "MaybeIncorrect"),
+    ("only_used_in_recursion", "MaybeIncorrect"),
+    ("op_ref", "MaybeIncorrect"),
+    ("option_as_ref_deref", "MachineApplicable"),
+    ("option_filter_map", "MachineApplicable"),
+    ("option_if_let_else", "MaybeIncorrect"),
+    ("option_map_or_none", "MachineApplicable"),
+    ("option_map_unit_fn", "MachineApplicable"),
+    ("or_fun_call", "HasPlaceholders"),
+    ("or_then_unwrap", "MachineApplicable"),
+    ("overly_complex_bool_expr", "Unspecified"),
+    ("partialeq_to_none", "MachineApplicable"),
+    ("path_buf_push_overwrite", "MachineApplicable"),
+    ("precedence", "MachineApplicable"),
+    ("print_in_format_impl", "HasPlaceholders"),
+    ("print_literal", "MachineApplicable"),
+    ("print_with_newline", "MachineApplicable"),
+    ("println_empty_string", "MachineApplicable"),
+    ("ptr_arg", "Unspecified"),
+    ("ptr_as_ptr", "MachineApplicable"),
+    ("ptr_eq", "MachineApplicable"),
+    ("question_mark", "MachineApplicable"),
+    ("range_minus_one", "MachineApplicable"),
+    ("range_plus_one", "MachineApplicable"),
+    ("rc_buffer", "MachineApplicable"),
+    ("rc_clone_in_vec_init", "HasPlaceholders"),
+    ("redundant_closure", "MachineApplicable"),
+    ("redundant_closure_for_method_calls", "MachineApplicable"),
+    ("redundant_field_names", "MachineApplicable"),
+    ("redundant_pattern", "MachineApplicable"),
+    ("redundant_pattern_matching", "MaybeIncorrect"),
+    ("redundant_pub_crate", "MachineApplicable"),
+    ("redundant_static_lifetimes", "MachineApplicable"),
+    ("ref_option_ref", "MaybeIncorrect"),
+    ("repeat_once", "MachineApplicable"),
+    ("result_map_or_into_option", "MachineApplicable"),
+    ("result_map_unit_fn", "MachineApplicable"),
+    ("reversed_empty_ranges", "MaybeIncorrect"),
+    ("search_is_some", "MachineApplicable"),
+    ("semicolon_if_nothing_returned", "MaybeIncorrect"),
+    ("separated_literal_suffix", "MachineApplicable"),
+    ("short_circuit_statement", "MachineApplicable"),
+    ("single_char_add_str", "MachineApplicable"),
+    ("single_char_pattern", "MachineApplicable"),
+    ("single_element_loop", "MachineApplicable"),
+    ("single_match", "HasPlaceholders"),
+    ("single_match_else", "HasPlaceholders"),
+    ("slow_vector_initialization", "Unspecified"),
+    ("stable_sort_primitive", "MachineApplicable"),
+    ("string_extend_chars", "MachineApplicable"),
+    ("string_from_utf8_as_bytes", "MachineApplicable"),
+    ("string_lit_as_bytes", "MachineApplicable"),
+    ("strlen_on_c_strings", "MachineApplicable"),
+    ("suboptimal_flops", "MachineApplicable"),
+    ("suspicious_to_owned", "MaybeIncorrect"),
+    ("swap_ptr_to_ref", "MachineApplicable"),
+    ("tabs_in_doc_comments", "MaybeIncorrect"),
+    ("to_digit_is_some", "MachineApplicable"),
+    ("to_string_in_format_args", "MachineApplicable"),
+    ("toplevel_ref_arg", "MachineApplicable"),
+    ("trait_duplication_in_bounds", "MachineApplicable"),
+    ("transmute_bytes_to_str", "MaybeIncorrect"),
+    ("transmute_float_to_int", "Unspecified"),
+    ("transmute_int_to_bool", "Unspecified"),
+    ("transmute_int_to_char", "Unspecified"),
+    ("transmute_int_to_float", "Unspecified"),
+    ("transmute_num_to_bytes", "Unspecified"),
+    ("transmute_ptr_to_ptr", "Unspecified"),
+    ("transmute_ptr_to_ref", "MachineApplicable"),
+    ("transmutes_expressible_as_ptr_casts", "MachineApplicable"),
+    ("trim_split_whitespace", "MachineApplicable"),
+    ("trivially_copy_pass_by_ref", "Unspecified"),
+    ("try_err", "MachineApplicable"),
+    ("unicode_not_nfc", "MachineApplicable"),
+    ("uninlined_format_args", "MachineApplicable"),
+    ("unit_arg", "MachineApplicable"),
+    ("unit_hash", "MaybeIncorrect"),
+    ("unnecessary_cast", "MachineApplicable"),
+    ("unnecessary_fold", "MachineApplicable"),
+    ("unnecessary_join", "MachineApplicable"),
+    ("unnecessary_lazy_evaluations", "MachineApplicable"),
+    ("unnecessary_operation", "MachineApplicable"),
+    ("unnecessary_owned_empty_strings", "MachineApplicable"),
+    ("unnecessary_self_imports", "MaybeIncorrect"),
+    ("unnecessary_sort_by", "MachineApplicable"),
+    ("unnecessary_to_owned", "MachineApplicable"),
+    ("unnecessary_unwrap", "Unspecified"),
+    ("unnecessary_wraps", "MaybeIncorrect"),
+    ("unneeded_wildcard_pattern", "MachineApplicable"),
+    ("unnested_or_patterns", "MachineApplicable"),
+    ("unreadable_literal", "MachineApplicable"),
+    ("unseparated_literal_suffix", "MachineApplicable"),
+    ("unused_rounding", "MachineApplicable"),
+    ("unused_unit", "MachineApplicable"),
+    ("unusual_byte_groupings", "MachineApplicable"),
+    ("unwrap_or_else_default", "MachineApplicable"),
+    ("upper_case_acronyms", "MaybeIncorrect"),
+    ("use_self", "MachineApplicable"),
+    ("useless_asref", "MachineApplicable"),
+    ("useless_attribute", "MaybeIncorrect"),
+    ("useless_conversion", "MachineApplicable"),
+    ("useless_let_if_seq", "HasPlaceholders"),
+    ("useless_transmute", "Unspecified"),
+    ("useless_vec", "MachineApplicable"),
+    ("vec_box", "MachineApplicable"),
+    ("vec_init_then_push", "HasPlaceholders"),
+    ("vec_resize_to_zero", "MaybeIncorrect"),
+    ("verbose_bit_mask", "MaybeIncorrect"),
+    ("while_let_loop", "HasPlaceholders"),
+    ("while_let_on_iterator", "MachineApplicable"),
+    ("wildcard_enum_match_arm", "MaybeIncorrect"),
+    ("wildcard_imports", "MachineApplicable"),
+    ("write_literal", "MachineApplicable"),
+    ("write_with_newline", "MachineApplicable"),
+    ("writeln_empty_string", "MachineApplicable"),
+    ("zero_prefixed_literal", "MaybeIncorrect"),
+    ("zero_ptr", "MachineApplicable"),
+];
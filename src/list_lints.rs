@@ -0,0 +1,152 @@
+//! `cargo clippy --list-lints`: prints the lints matching some filters, as a table or as JSON.
+
+use crate::lint_list::{self, LintInfo};
+use crate::metadata::{self, GROUPS};
+use std::fmt::Write as _;
+
+const LEVELS: [&str; 3] = ["allow", "warn", "deny"];
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ListOptions {
+    /// Only list the lints of these groups
+    groups: Vec<String>,
+    /// Only list the lints with these default levels
+    levels: Vec<String>,
+    /// Only list the lints with machine applicable suggestions
+    fixable: bool,
+    json: bool,
+}
+
+impl ListOptions {
+    /// Parses the arguments following `--list-lints`
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        while let Some(arg) = args.next() {
+            let (name, value) = match arg.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            match name.as_str() {
+                "--group" | "--level" => {
+                    let value = value
+                        .or_else(|| args.next())
+                        .ok_or_else(|| format!("`{name}` requires a value"))?;
+                    let value = value.strip_prefix("clippy::").unwrap_or(&value).to_ascii_lowercase();
+                    if name == "--group" {
+                        if !GROUPS.contains(&value.as_str()) {
+                            return Err(format!(
+                                "unknown lint group `{value}`, expected one of {}",
+                                GROUPS.join(", ")
+                            ));
+                        }
+                        options.groups.push(value);
+                    } else {
+                        if !LEVELS.contains(&value.as_str()) {
+                            return Err(format!(
+                                "unknown lint level `{value}`, expected one of {}",
+                                LEVELS.join(", ")
+                            ));
+                        }
+                        options.levels.push(value);
+                    }
+                },
+                "--fixable" => options.fixable = true,
+                "--json" => options.json = true,
+                _ => return Err(format!("unknown option `{name}` for `--list-lints`")),
+            }
+        }
+        Ok(options)
+    }
+
+    fn matches(&self, lint: &LintInfo) -> bool {
+        (self.groups.is_empty() || self.groups.iter().any(|group| group == lint.group))
+            && (self.levels.is_empty() || self.levels.iter().any(|level| level == lint.level))
+            && (!self.fixable || is_fixable(lint))
+    }
+}
+
+/// Returns `true` if `cargo clippy --fix` can fix some of the lint's warnings. The applicabilities
+/// are updated by `cargo collect-metadata`, and CI checks that they are current.
+fn is_fixable(lint: &LintInfo) -> bool {
+    lint_list::applicability(lint.name) == Some("MachineApplicable")
+}
+
+/// Prints the lints matching the options
+pub fn list(options: &ListOptions) {
+    let lints: Vec<&LintInfo> = lint_list::LINTS.iter().filter(|lint| options.matches(lint)).collect();
+
+    if options.json {
+        let lints: Vec<metadata::Lint> = lints.into_iter().map(metadata::Lint::from).collect();
+        println!("{}", serde_json::to_string_pretty(&lints).unwrap());
+        return;
+    }
+
+    let width = |column: fn(&LintInfo) -> &str| lints.iter().map(|lint| column(lint).len()).max().unwrap_or(0);
+    let name_width = width(|lint| lint.name).max("name".len());
+    let group_width = width(|lint| lint.group).max("group".len());
+    let version_width = width(|lint| lint.version).max("version".len());
+
+    let mut output = format!(
+        "{:name_width$}  {:group_width$}  {:5}  {:version_width$}  fixable\n",
+        "name", "group", "level", "version"
+    );
+    for lint in &lints {
+        let _ = writeln!(
+            output,
+            "{:name_width$}  {:group_width$}  {:5}  {:version_width$}  {}",
+            lint.name,
+            lint.group,
+            lint.level,
+            lint.version,
+            if is_fixable(lint) { "yes" } else { "no" }
+        );
+    }
+    let _ = writeln!(output, "\n{} lints", lints.len());
+    print!("{output}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_options() {
+        let args = "--group pedantic --group=clippy::style --level=warn --fixable --json"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let options = ListOptions::parse(args).unwrap();
+        assert_eq!(
+            options,
+            ListOptions {
+                groups: vec!["pedantic".into(), "style".into()],
+                levels: vec!["warn".into()],
+                fixable: true,
+                json: true,
+            }
+        );
+
+        assert!(ListOptions::parse(["--group".to_string(), "pedantik".to_string()].into_iter()).is_err());
+        assert!(ListOptions::parse(["--level".to_string()].into_iter()).is_err());
+    }
+
+    #[test]
+    fn fixable_lints() {
+        let options = ListOptions::parse(["--fixable".to_string()].into_iter()).unwrap();
+        let listed = |name| options.matches(lint_list::find(name).unwrap());
+        assert!(listed("needless_return"));
+        assert!(listed("len_zero"));
+        assert!(!listed("absurd_extreme_comparisons"));
+    }
+
+    #[test]
+    fn applicabilities_are_current() {
+        // Searched with a binary search, and only regenerated by `cargo collect-metadata`
+        assert!(lint_list::APPLICABILITIES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for (name, _) in lint_list::APPLICABILITIES {
+            assert!(
+                lint_list::find(name).is_some(),
+                "`{name}` in `APPLICABILITIES` isn't a lint"
+            );
+        }
+    }
+}
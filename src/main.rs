@@ -2,12 +2,26 @@
 // warn on lints, that are included in `rust-lang/rust`s bootstrap
 #![warn(rust_2018_idioms, unused_lifetimes)]
 
+use baseline::Baseline;
+use list_lints::ListOptions;
+use report::OutputFormat;
 use rustc_tools_util::VersionInfo;
 use std::env;
-use std::path::PathBuf;
-use std::process::{self, Command};
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
 
+mod baseline;
 mod docs;
+mod explain;
+mod lint_list;
+mod list_lints;
+mod markdown;
+mod metadata;
+mod report;
+
+const DEFAULT_BASELINE_FILE: &str = "clippy-baseline.json";
 
 const CARGO_CLIPPY_HELP: &str = r#"Checks a package to catch common mistakes and improve your Rust code.
 
@@ -19,7 +33,25 @@ Common options:
     --fix                    Automatically apply lint suggestions. This flag implies `--no-deps`
     -h, --help               Print this message
     -V, --version            Print version info and exit
-    --explain LINT           Print the documentation for a given lint
+    --explain LINT           Print the documentation for a given lint, and the configuration
+                             options affecting it with their values for the current project.
+                             For a lint group, list the lints in the group
+    --list-lints [FILTERS]   List the lints with their group, level, version and whether they can
+                             be fixed automatically. The FILTERS are `--group GROUP`,
+                             `--level LEVEL` and `--fixable`, and `--json` prints JSON instead
+    --lint-metadata          Print the groups, levels, applicability and configuration options
+                             of all lints as JSON
+    --output-format FORMAT   Write a report of all Clippy warnings to stdout, where FORMAT is one of
+                             `json`, `sarif` or `checkstyle`
+    --baseline FILE          Hide the warnings recorded in the baseline FILE
+    --update-baseline        Record all current warnings in the baseline file, which is
+                             `clippy-baseline.json` unless `--baseline` is given
+    --print-config           Print the configuration Clippy uses for each crate, and where each
+                             value comes from, instead of the warnings
+    --add-allows[=ATTR]      Add `#[allow]` (or `#[expect]` with `--add-allows=expect`) attributes
+                             for the Clippy warnings to the items containing them, instead of
+                             fixing them. This flag implies `--fix`
+    --allow-reason REASON    The `reason` given in the attributes added by `--add-allows`
 
 Other options are the same as `cargo check`.
 
@@ -60,13 +92,29 @@ pub fn main() {
     if let Some(pos) = env::args().position(|a| a == "--explain") {
         if let Some(mut lint) = env::args().nth(pos + 1) {
             lint.make_ascii_lowercase();
-            docs::explain(&lint.strip_prefix("clippy::").unwrap_or(&lint).replace('-', "_"));
+            explain::explain(&lint.strip_prefix("clippy::").unwrap_or(&lint).replace('-', "_"));
         } else {
             show_help();
         }
         return;
     }
 
+    if let Some(pos) = env::args().position(|a| a == "--list-lints") {
+        match ListOptions::parse(env::args().skip(pos + 1)) {
+            Ok(options) => list_lints::list(&options),
+            Err(e) => {
+                eprintln!("error: {e}");
+                process::exit(1);
+            },
+        }
+        return;
+    }
+
+    if env::args().any(|a| a == "--lint-metadata") {
+        metadata::print();
+        return;
+    }
+
     if let Err(code) = process(env::args().skip(2)) {
         process::exit(code);
     }
@@ -76,6 +124,10 @@ struct ClippyCmd {
     cargo_subcommand: &'static str,
     args: Vec<String>,
     clippy_args: Vec<String>,
+    output_format: Option<OutputFormat>,
+    baseline: Option<PathBuf>,
+    update_baseline: bool,
+    print_config: bool,
 }
 
 impl ClippyCmd {
@@ -86,8 +138,12 @@ impl ClippyCmd {
         let mut cargo_subcommand = "check";
         let mut args = vec![];
         let mut clippy_args: Vec<String> = vec![];
+        let mut output_format = None;
+        let mut baseline = None;
+        let mut update_baseline = false;
+        let mut print_config = false;
 
-        for arg in old_args.by_ref() {
+        while let Some(arg) = old_args.next() {
             match arg.as_str() {
                 "--fix" => {
                     cargo_subcommand = "fix";
@@ -97,14 +153,64 @@ impl ClippyCmd {
                     clippy_args.push("--no-deps".into());
                     continue;
                 },
+                "--update-baseline" => {
+                    update_baseline = true;
+                    continue;
+                },
+                "--print-config" => {
+                    print_config = true;
+                    continue;
+                },
+                "--add-allows" => {
+                    cargo_subcommand = "fix";
+                    clippy_args.push("--add-allows=allow".into());
+                    continue;
+                },
                 "--" => break,
                 _ => {},
             }
 
+            if let Some(format) = flag_value(&arg, "--output-format", &mut old_args) {
+                output_format = Some(parse_output_format(&format));
+                continue;
+            }
+            if let Some(path) = flag_value(&arg, "--baseline", &mut old_args) {
+                baseline = Some(PathBuf::from(path));
+                continue;
+            }
+            if let Some(attribute) = arg.strip_prefix("--add-allows=") {
+                if attribute != "allow" && attribute != "expect" {
+                    eprintln!("error: `--add-allows` expects `allow` or `expect`, found `{attribute}`");
+                    process::exit(1);
+                }
+                cargo_subcommand = "fix";
+                clippy_args.push(format!("--add-allows={attribute}"));
+                continue;
+            }
+            if let Some(reason) = flag_value(&arg, "--allow-reason", &mut old_args) {
+                clippy_args.push(format!("--allow-reason={reason}"));
+                continue;
+            }
+
             args.push(arg);
         }
 
         clippy_args.append(&mut (old_args.collect()));
+        if (output_format.is_some() || update_baseline) && args.iter().any(|arg| arg.starts_with("--message-format")) {
+            eprintln!(
+                "error: `--message-format` can't be used with `--output-format` or `--update-baseline`, \
+                 which read cargo's JSON messages themselves"
+            );
+            process::exit(1);
+        }
+        if update_baseline {
+            // Denied lints must not stop the compilation before all warnings are recorded
+            clippy_args.push("--update-baseline".into());
+        } else if let Some(path) = &baseline {
+            // The driver runs in the directory of the workspace, not the current one
+            let path = env::current_dir().map_or_else(|_| path.clone(), |dir| dir.join(path));
+            clippy_args.push(format!("--baseline={}", path.display()));
+        }
         if cargo_subcommand == "fix" && !clippy_args.iter().any(|arg| arg == "--no-deps") {
             clippy_args.push("--no-deps".into());
         }
@@ -113,6 +219,23 @@ impl ClippyCmd {
             cargo_subcommand,
             args,
             clippy_args,
+            output_format,
+            baseline,
+            update_baseline,
+            print_config,
+        }
+    }
+
+    /// Whether cargo's output has to be collected, instead of being shown to the user directly.
+    fn collects_diagnostics(&self) -> bool {
+        self.output_format.is_some() || self.update_baseline
+    }
+
+    fn baseline_path(&self) -> Option<&Path> {
+        match &self.baseline {
+            Some(path) => Some(path),
+            None if self.update_baseline => Some(Path::new(DEFAULT_BASELINE_FILE)),
+            None => None,
         }
     }
 
@@ -145,23 +268,104 @@ impl ClippyCmd {
             .arg(self.cargo_subcommand)
             .args(&self.args);
 
+        if self.print_config {
+            // The value changes on every run, so that cargo runs Clippy on crates it already
+            // checked.
+            let nonce = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |time| time.as_nanos());
+            cmd.env("CLIPPY_PRINT_CONFIG", nonce.to_string());
+        }
+
+        if self.collects_diagnostics() {
+            // The diagnostics are collected from cargo's JSON messages and shown to the user by
+            // `report::collect`.
+            cmd.arg("--message-format=json").stdout(Stdio::piped());
+        }
+
         cmd
     }
 }
 
+/// Returns the value of `flag` if `arg` is either `flag=value` or `flag` followed by `value`.
+///
+/// Exits with an error if `flag` is given without a value.
+fn flag_value(arg: &str, flag: &str, args: &mut impl Iterator<Item = String>) -> Option<String> {
+    let value = match arg.strip_prefix(flag)? {
+        "" => args.next(),
+        value => Some(value.strip_prefix('=')?.to_string()),
+    };
+    match value {
+        Some(value) if !value.is_empty() => Some(value),
+        _ => {
+            eprintln!("error: `{flag}` expects a value");
+            process::exit(1);
+        },
+    }
+}
+
+fn parse_output_format(format: &str) -> OutputFormat {
+    format.parse().unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        process::exit(1);
+    })
+}
+
 fn process<I>(old_args: I) -> Result<(), i32>
 where
     I: Iterator<Item = String>,
 {
     let cmd = ClippyCmd::new(old_args);
+    let collects_diagnostics = cmd.collects_diagnostics();
+    let output_format = cmd.output_format;
+    let update_baseline = cmd.update_baseline;
+    let baseline_path = cmd.baseline_path().map(Path::to_path_buf);
+    if let Some(path) = baseline_path.as_deref().filter(|_| !update_baseline) {
+        // The driver hides the warnings of the baseline, but a broken file is reported only once
+        if let Err(e) = Baseline::read(path) {
+            eprintln!("error: {e}");
+            process::exit(1);
+        }
+    }
 
     let mut cmd = cmd.into_std_cmd();
 
-    let exit_status = cmd
-        .spawn()
-        .expect("could not run cargo")
-        .wait()
-        .expect("failed to wait for cargo?");
+    let mut child = cmd.spawn().expect("could not run cargo");
+
+    let mut collected = None;
+    if collects_diagnostics {
+        let stdout = child.stdout.take().expect("cargo's stdout is piped");
+        collected = Some(
+            report::collect(BufReader::new(stdout), &mut io::stderr().lock()).expect("failed to read cargo's output"),
+        );
+    }
+
+    let exit_status = child.wait().expect("failed to wait for cargo?");
+
+    if let Some(collected) = collected {
+        if let Some(path) = baseline_path.filter(|_| update_baseline) {
+            // Crates that failed to compile would be missing from the baseline
+            if exit_status.success() {
+                Baseline::new(collected.diagnostics.iter().map(|diag| {
+                    let file = diag.primary_span().map_or("", |span| &span.file);
+                    (&*diag.lint, file, &*diag.snippet)
+                }))
+                .write(&path)
+                .unwrap_or_else(|e| panic!("failed to write `{}`: {e}", path.display()));
+                eprintln!(
+                    "Warnings recorded in the baseline `{}`: {}",
+                    path.display(),
+                    collected.diagnostics.len()
+                );
+            } else {
+                eprintln!("error: the baseline `{}` was not updated", path.display());
+            }
+        }
+        if let Some(format) = output_format {
+            report::write(&mut io::stdout().lock(), format, &collected.diagnostics)
+                .expect("failed to write the report");
+        }
+    }
 
     if exit_status.success() {
         Ok(())
@@ -172,7 +376,8 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::ClippyCmd;
+    use super::{ClippyCmd, OutputFormat};
+    use std::path::Path;
 
     #[test]
     fn fix() {
@@ -198,6 +403,79 @@ mod tests {
         assert_eq!(cmd.clippy_args.iter().filter(|arg| *arg == "--no-deps").count(), 1);
     }
 
+    #[test]
+    fn output_format() {
+        let args = "cargo clippy --output-format=sarif --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.output_format, Some(OutputFormat::Sarif));
+        assert!(cmd.args.iter().any(|arg| arg == "--all-targets"));
+        assert!(!cmd.args.iter().any(|arg| arg.contains("sarif")));
+
+        let args = "cargo clippy --output-format checkstyle"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.output_format, Some(OutputFormat::Checkstyle));
+        assert!(!cmd.args.iter().any(|arg| arg.contains("checkstyle")));
+    }
+
+    #[test]
+    fn baseline() {
+        let args = "cargo clippy --baseline=base.json"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(!cmd.collects_diagnostics());
+        assert!(!cmd.update_baseline);
+        assert_eq!(cmd.baseline_path(), Some(Path::new("base.json")));
+        assert!(
+            cmd.clippy_args
+                .iter()
+                .any(|arg| arg.starts_with("--baseline=") && arg.ends_with("base.json"))
+        );
+
+        let args = "cargo clippy --update-baseline"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.update_baseline);
+        assert!(cmd.collects_diagnostics());
+        assert_eq!(cmd.baseline_path(), Some(Path::new("clippy-baseline.json")));
+        assert!(cmd.clippy_args.iter().any(|arg| arg == "--update-baseline"));
+        assert!(!cmd.args.iter().any(|arg| arg.contains("baseline")));
+    }
+
+    #[test]
+    fn print_config() {
+        let args = "cargo clippy --print-config -- -W clippy::pedantic"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.print_config);
+        assert!(!cmd.args.iter().any(|arg| arg == "--print-config"));
+        assert!(
+            cmd.into_std_cmd()
+                .get_envs()
+                .any(|(key, _)| key == "CLIPPY_PRINT_CONFIG")
+        );
+    }
+
+    #[test]
+    fn add_allows() {
+        let args = "cargo clippy --add-allows=expect --allow-reason TODO --allow-dirty"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!("fix", cmd.cargo_subcommand);
+        assert!(cmd.args.iter().any(|arg| arg == "--allow-dirty"));
+        assert!(!cmd.args.iter().any(|arg| arg.contains("add-allows") || arg == "TODO"));
+        assert!(cmd.clippy_args.iter().any(|arg| arg == "--add-allows=expect"));
+        assert!(cmd.clippy_args.iter().any(|arg| arg == "--allow-reason=TODO"));
+        assert!(cmd.clippy_args.iter().any(|arg| arg == "--no-deps"));
+    }
+
     #[test]
     fn check() {
         let args = "cargo clippy".split_whitespace().map(ToString::to_string);
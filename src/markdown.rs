@@ -0,0 +1,221 @@
+//! Renders the markdown documentation of the lints for the terminal.
+//!
+//! Only the subset of markdown used by the lint docs is handled: headings, code blocks, inline code
+//! and links. The Rust code blocks are highlighted when the output is colored.
+
+use std::env;
+
+const HEADING: &str = "1;4";
+const INLINE_CODE: &str = "36";
+const KEYWORD: &str = "35";
+const TYPE: &str = "33";
+const MACRO: &str = "34";
+const LITERAL: &str = "32";
+const COMMENT: &str = "90";
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn",
+    "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self",
+    "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while",
+];
+
+#[derive(Clone, Copy)]
+pub struct Style {
+    color: bool,
+}
+
+impl Style {
+    /// Colors the output if stdout is a terminal, unless `NO_COLOR` is set
+    pub fn detect() -> Self {
+        Self {
+            color: env::var_os("NO_COLOR").is_none() && termize::dimensions_stdout().is_some(),
+        }
+    }
+
+    fn paint(self, ansi: &str, text: &str) -> String {
+        if self.color {
+            format!("\x1b[{ansi}m{text}\x1b[0m")
+        } else {
+            text.to_string()
+        }
+    }
+
+    pub fn heading(self, text: &str) -> String {
+        self.paint(HEADING, text)
+    }
+
+    /// Inline code keeps its backticks when the output isn't colored
+    pub fn code(self, text: &str) -> String {
+        if self.color {
+            self.paint(INLINE_CODE, text)
+        } else {
+            format!("`{text}`")
+        }
+    }
+}
+
+/// Renders `markdown` for the terminal, with the code blocks indented by four spaces
+pub fn render(markdown: &str, style: Style) -> String {
+    let mut out = String::new();
+    // `None` outside of code blocks, and whether the code is Rust inside of them
+    let mut in_rust_code = None;
+    for line in markdown.lines() {
+        if let Some(info) = line.trim_start().strip_prefix("```") {
+            in_rust_code = match in_rust_code {
+                Some(_) => None,
+                None => Some(matches!(info.trim(), "" | "rust")),
+            };
+            continue;
+        }
+        match in_rust_code {
+            Some(true) => {
+                out.push_str("    ");
+                out.push_str(&highlight_rust(line, style));
+            },
+            Some(false) => {
+                out.push_str("    ");
+                out.push_str(line);
+            },
+            None => match line.strip_prefix('#') {
+                Some(heading) if heading.trim_start_matches('#').starts_with(' ') => {
+                    out.push_str(&style.heading(heading.trim_start_matches('#').trim()));
+                },
+                _ => out.push_str(&render_inline(line, style)),
+            },
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Renders the inline code and the links of a line of text
+pub fn render_inline(line: &str, style: Style) -> String {
+    let mut out = String::new();
+    let mut rest = line;
+    while let Some(start) = rest.find(|c| c == '`' || c == '[') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(code) = rest.strip_prefix('`') {
+            if let Some(end) = code.find('`') {
+                out.push_str(&style.code(&code[..end]));
+                rest = &code[end + 1..];
+                continue;
+            }
+        } else if let Some((text, url, after)) = split_link(rest) {
+            out.push_str(&render_inline(text, style));
+            out.push_str(" (");
+            out.push_str(url);
+            out.push(')');
+            rest = after;
+            continue;
+        }
+        out.push_str(&rest[..1]);
+        rest = &rest[1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Splits `[text](url)rest` into its text, url and rest
+fn split_link(s: &str) -> Option<(&str, &str, &str)> {
+    let (text, after_text) = s.strip_prefix('[')?.split_once("](")?;
+    let (url, rest) = after_text.split_once(')')?;
+    Some((text, url, rest))
+}
+
+/// Highlights a line of Rust code. Tokens spanning several lines, like block comments, aren't
+/// recognized.
+fn highlight_rust(line: &str, style: Style) -> String {
+    if !style.color {
+        return line.to_string();
+    }
+    let mut out = String::new();
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        let (len, ansi) = if rest.starts_with("//") {
+            (rest.len(), Some(COMMENT))
+        } else if c == '"' {
+            (string_len(rest), Some(LITERAL))
+        } else if let Some(len) = char_len(rest) {
+            (len, Some(LITERAL))
+        } else if c.is_ascii_digit() {
+            let len = rest
+                .char_indices()
+                .find(|&(i, c)| !(c.is_alphanumeric() || c == '_' || (c == '.' && is_digit_at(rest, i + 1))))
+                .map_or(rest.len(), |(i, _)| i);
+            (len, Some(LITERAL))
+        } else if c.is_alphabetic() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let ident = &rest[..len];
+            if KEYWORDS.contains(&ident) {
+                (len, Some(KEYWORD))
+            } else if rest[len..].starts_with('!') {
+                (len + 1, Some(MACRO))
+            } else if c.is_uppercase() {
+                (len, Some(TYPE))
+            } else {
+                (len, None)
+            }
+        } else {
+            (c.len_utf8(), None)
+        };
+        match ansi {
+            Some(ansi) => out.push_str(&style.paint(ansi, &rest[..len])),
+            None => out.push_str(&rest[..len]),
+        }
+        rest = &rest[len..];
+    }
+    out
+}
+
+fn is_digit_at(s: &str, i: usize) -> bool {
+    s.as_bytes().get(i).map_or(false, u8::is_ascii_digit)
+}
+
+/// The length of the string literal at the start of `s`, up to the end of the line if it isn't
+/// closed
+fn string_len(s: &str) -> usize {
+    let mut escaped = false;
+    for (i, c) in s.char_indices().skip(1) {
+        match c {
+            '"' if !escaped => return i + 1,
+            '\\' => escaped = !escaped,
+            _ => escaped = false,
+        }
+    }
+    s.len()
+}
+
+/// The length of the char literal at the start of `s`, or `None` if it starts with a lifetime
+fn char_len(s: &str) -> Option<usize> {
+    let inner = s.strip_prefix('\'')?;
+    match inner.chars().next()? {
+        '\\' => inner[1..].find('\'').map(|end| end + 3),
+        '\'' => None,
+        c => inner[c.len_utf8()..].starts_with('\'').then(|| c.len_utf8() + 2),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render, Style};
+
+    #[test]
+    fn render_plain() {
+        let markdown = "### What it does\nChecks for `foo`, see [the `Foo` docs](https://example.com).\n\n```\nlet x = 1;\n```\n```toml\n# clippy.toml\n```";
+        let expected = "What it does\nChecks for `foo`, see the `Foo` docs (https://example.com).\n\n    let x = 1;\n    # clippy.toml\n";
+        assert_eq!(render(markdown, Style { color: false }), expected);
+    }
+
+    #[test]
+    fn highlight() {
+        let style = Style { color: true };
+        let rendered = render("```\nlet c = 'a'; // `x`\nfn f<'a>() {}\n```", style);
+        assert_eq!(
+            rendered,
+            "    \x1b[35mlet\x1b[0m c = \x1b[32m'a'\x1b[0m; \x1b[90m// `x`\x1b[0m\n    \x1b[35mfn\x1b[0m f<'a>() {}\n"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! The metadata of all Clippy lints and configuration options printed by
+//! `cargo clippy --lint-metadata`, for the tools that need to know about Clippy's lints.
+//!
+//! The output has a `schema_version`, which is increased whenever a field is removed or changes
+//! its meaning. Adding a field doesn't change the version.
+
+use crate::lint_list::{self, ConfigInfo, LintInfo};
+use serde::Serialize;
+
+/// The version of the format of the metadata
+pub const SCHEMA_VERSION: u32 = 1;
+
+const DOCS_LINK: &str = "https://rust-lang.github.io/rust-clippy/master/index.html";
+
+/// The lint groups, in the order of the documentation
+pub const GROUPS: [&str; 9] = [
+    "correctness",
+    "suspicious",
+    "style",
+    "complexity",
+    "perf",
+    "pedantic",
+    "restriction",
+    "cargo",
+    "nursery",
+];
+
+#[derive(Serialize)]
+struct Metadata {
+    schema_version: u32,
+    clippy_version: &'static str,
+    groups: Vec<Group>,
+    lints: Vec<Lint>,
+    configuration: Vec<Config>,
+}
+
+#[derive(Serialize)]
+struct Group {
+    name: &'static str,
+    level: &'static str,
+    lints: Vec<&'static str>,
+}
+
+#[derive(Serialize)]
+pub struct Lint {
+    name: &'static str,
+    group: &'static str,
+    level: &'static str,
+    version: &'static str,
+    /// The highest applicability of the lint's suggestions, if it has any
+    applicability: Option<&'static str>,
+    /// The Rust version the lint requires, if it's affected by the `msrv` option
+    msrv: Option<&'static str>,
+    /// The names of the configuration options affecting the lint
+    configuration: Vec<&'static str>,
+    docs: String,
+}
+
+#[derive(Serialize)]
+struct Config {
+    name: &'static str,
+    #[serde(rename = "type")]
+    ty: &'static str,
+    default: &'static str,
+    doc: &'static str,
+    lints: &'static [&'static str],
+}
+
+impl From<&'static LintInfo> for Lint {
+    fn from(lint: &'static LintInfo) -> Self {
+        Self {
+            name: lint.name,
+            group: lint.group,
+            level: lint.level,
+            version: lint.version,
+            applicability: lint_list::applicability(lint.name),
+            msrv: lint.msrv,
+            configuration: lint_list::configs_of(lint.name).map(|config| config.name).collect(),
+            docs: format!("{DOCS_LINK}#{}", lint.name),
+        }
+    }
+}
+
+impl From<&'static ConfigInfo> for Config {
+    fn from(config: &'static ConfigInfo) -> Self {
+        Self {
+            name: config.name,
+            ty: config.ty,
+            default: config.default,
+            doc: config.doc,
+            lints: config.lints,
+        }
+    }
+}
+
+fn metadata() -> Metadata {
+    let groups = GROUPS
+        .into_iter()
+        .map(|name| {
+            let lints: Vec<_> = lint_list::LINTS.iter().filter(|lint| lint.group == name).collect();
+            Group {
+                name,
+                level: lints.first().map_or("allow", |lint| lint.level),
+                lints: lints.iter().map(|lint| lint.name).collect(),
+            }
+        })
+        .collect();
+
+    Metadata {
+        schema_version: SCHEMA_VERSION,
+        clippy_version: env!("CARGO_PKG_VERSION"),
+        groups,
+        lints: lint_list::LINTS.iter().map(Lint::from).collect(),
+        configuration: lint_list::CONFIGS.iter().map(Config::from).collect(),
+    }
+}
+
+/// Prints the metadata as JSON
+pub fn print() {
+    println!("{}", serde_json::to_string_pretty(&metadata()).unwrap());
+}
@@ -0,0 +1,510 @@
+//! Collects the diagnostics emitted during a `cargo clippy --output-format=<format>` run and
+//! writes them as a single machine-readable report.
+
+use crate::lint_list;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write as _;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+const DOCS_LINK: &str = "https://rust-lang.github.io/rust-clippy/master/index.html";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json,
+    Sarif,
+    Checkstyle,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            "checkstyle" => Ok(Self::Checkstyle),
+            _ => Err(format!(
+                "unknown output format `{s}`, expected one of `json`, `sarif` or `checkstyle`"
+            )),
+        }
+    }
+}
+
+/// A message emitted by `cargo --message-format=json`. Only compiler messages are of interest.
+#[derive(Deserialize)]
+struct CargoMessage {
+    reason: String,
+    message: Option<CompilerMessage>,
+}
+
+#[derive(Deserialize)]
+pub struct CompilerMessage {
+    pub message: String,
+    pub code: Option<DiagnosticCode>,
+    pub level: String,
+    pub spans: Vec<CompilerSpan>,
+    pub children: Vec<CompilerMessage>,
+    pub rendered: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct DiagnosticCode {
+    pub code: String,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct CompilerSpan {
+    pub file_name: String,
+    pub byte_start: u32,
+    pub byte_end: u32,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub column_start: usize,
+    pub column_end: usize,
+    pub is_primary: bool,
+    pub text: Vec<SpanLine>,
+    pub suggested_replacement: Option<String>,
+    pub suggestion_applicability: Option<String>,
+}
+
+/// A line of source code covered by a span, with the columns the span highlights.
+#[derive(Clone, Deserialize)]
+pub struct SpanLine {
+    pub text: String,
+    pub highlight_start: usize,
+    pub highlight_end: usize,
+}
+
+/// A single Clippy warning as it appears in the report.
+#[derive(Serialize)]
+pub struct ClippyDiagnostic {
+    pub lint: String,
+    pub group: Option<&'static str>,
+    pub level: String,
+    pub message: String,
+    pub spans: Vec<Span>,
+    pub suggestions: Vec<Suggestion>,
+    /// The source code highlighted by the primary span
+    #[serde(skip)]
+    pub snippet: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct Span {
+    pub file: String,
+    pub byte_start: u32,
+    pub byte_end: u32,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub column_start: usize,
+    pub column_end: usize,
+    pub is_primary: bool,
+}
+
+#[derive(Serialize)]
+pub struct Suggestion {
+    pub message: String,
+    pub applicability: Option<String>,
+    pub replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+pub struct Replacement {
+    pub span: Span,
+    pub text: String,
+}
+
+impl From<&CompilerSpan> for Span {
+    fn from(span: &CompilerSpan) -> Self {
+        Self {
+            file: span.file_name.clone(),
+            byte_start: span.byte_start,
+            byte_end: span.byte_end,
+            line_start: span.line_start,
+            line_end: span.line_end,
+            column_start: span.column_start,
+            column_end: span.column_end,
+            is_primary: span.is_primary,
+        }
+    }
+}
+
+impl ClippyDiagnostic {
+    /// Converts a compiler message into a report entry. Returns `None` for anything that is not
+    /// a Clippy lint.
+    pub fn new(message: CompilerMessage) -> Option<Self> {
+        let lint = message.code.as_ref()?.code.strip_prefix("clippy::")?.to_string();
+        let suggestions = message
+            .children
+            .iter()
+            .filter(|child| child.spans.iter().any(|span| span.suggested_replacement.is_some()))
+            .map(|child| Suggestion {
+                message: child.message.clone(),
+                applicability: child
+                    .spans
+                    .iter()
+                    .find_map(|span| span.suggestion_applicability.clone()),
+                replacements: child
+                    .spans
+                    .iter()
+                    .filter_map(|span| {
+                        Some(Replacement {
+                            span: span.into(),
+                            text: span.suggested_replacement.clone()?,
+                        })
+                    })
+                    .collect(),
+            })
+            .collect();
+        let snippet = message
+            .spans
+            .iter()
+            .find(|span| span.is_primary)
+            .map(|span| {
+                span.text
+                    .iter()
+                    .map(|line| {
+                        line.text
+                            .chars()
+                            .take(line.highlight_end.saturating_sub(1))
+                            .skip(line.highlight_start.saturating_sub(1))
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .unwrap_or_default();
+
+        Some(Self {
+            group: lint_list::find(&lint).map(|info| info.group),
+            lint,
+            level: message.level,
+            message: message.message,
+            spans: message.spans.iter().map(Span::from).collect(),
+            suggestions,
+            snippet,
+        })
+    }
+
+    /// The span the diagnostic points at.
+    pub fn primary_span(&self) -> Option<&Span> {
+        self.spans
+            .iter()
+            .find(|span| span.is_primary)
+            .or_else(|| self.spans.first())
+    }
+}
+
+/// The diagnostics of a `cargo clippy` run.
+#[derive(Default)]
+pub struct Collected {
+    /// The Clippy diagnostics that were shown to the user
+    pub diagnostics: Vec<ClippyDiagnostic>,
+}
+
+/// Reads `cargo --message-format=json` output, forwarding everything meant for humans to `human`,
+/// and collects the Clippy diagnostics in it. Diagnostics emitted more than once (e.g. for the
+/// library and the test target of a crate) are only collected once.
+pub fn collect(input: impl BufRead, human: &mut impl Write) -> io::Result<Collected> {
+    let mut seen = HashSet::new();
+    let mut collected = Collected::default();
+
+    for line in input.lines() {
+        let line = line?;
+        let message = match serde_json::from_str::<CargoMessage>(&line) {
+            Ok(CargoMessage {
+                reason,
+                message: Some(message),
+            }) if reason == "compiler-message" => message,
+            Ok(_) => continue,
+            Err(_) => {
+                // Not a JSON message, e.g. the output of a build script.
+                writeln!(human, "{line}")?;
+                continue;
+            },
+        };
+
+        if let Some(rendered) = &message.rendered {
+            write!(human, "{rendered}")?;
+        }
+        if let Some(diag) = ClippyDiagnostic::new(message) {
+            let key = (diag.lint.clone(), diag.primary_span().cloned(), diag.message.clone());
+            if seen.insert(key) {
+                collected.diagnostics.push(diag);
+            }
+        }
+    }
+
+    Ok(collected)
+}
+
+/// Writes the report for the given diagnostics in the requested format.
+pub fn write(out: &mut impl Write, format: OutputFormat, diagnostics: &[ClippyDiagnostic]) -> io::Result<()> {
+    match format {
+        OutputFormat::Json => serde_json::to_writer_pretty(&mut *out, diagnostics)?,
+        OutputFormat::Sarif => serde_json::to_writer_pretty(&mut *out, &sarif(diagnostics))?,
+        OutputFormat::Checkstyle => out.write_all(checkstyle(diagnostics).as_bytes())?,
+    }
+    writeln!(out)
+}
+
+fn sarif_level(level: &str) -> &'static str {
+    match level {
+        "error" | "error: internal compiler error" => "error",
+        "warning" => "warning",
+        _ => "note",
+    }
+}
+
+fn sarif_region(span: &Span) -> serde_json::Value {
+    json!({
+        "startLine": span.line_start,
+        "startColumn": span.column_start,
+        "endLine": span.line_end,
+        "endColumn": span.column_end,
+        "byteOffset": span.byte_start,
+        "byteLength": span.byte_end - span.byte_start,
+    })
+}
+
+/// Builds a SARIF 2.1.0 log with one run and one rule per emitted lint.
+fn sarif(diagnostics: &[ClippyDiagnostic]) -> serde_json::Value {
+    let mut rules = BTreeMap::new();
+    for diag in diagnostics {
+        rules.entry(diag.lint.as_str()).or_insert(diag.group);
+    }
+    let rule_index = |lint: &str| rules.keys().position(|&name| name == lint);
+
+    let results = diagnostics
+        .iter()
+        .map(|diag| {
+            let fixes = diag
+                .suggestions
+                .iter()
+                .map(|suggestion| {
+                    let mut changes = BTreeMap::<&str, Vec<_>>::new();
+                    for replacement in &suggestion.replacements {
+                        changes.entry(&replacement.span.file).or_default().push(json!({
+                            "deletedRegion": sarif_region(&replacement.span),
+                            "insertedContent": { "text": replacement.text },
+                        }));
+                    }
+                    json!({
+                        "description": { "text": suggestion.message },
+                        "artifactChanges": changes
+                            .into_iter()
+                            .map(|(file, replacements)| json!({
+                                "artifactLocation": { "uri": file },
+                                "replacements": replacements,
+                            }))
+                            .collect::<Vec<_>>(),
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            json!({
+                "ruleId": format!("clippy::{}", diag.lint),
+                "ruleIndex": rule_index(&diag.lint),
+                "level": sarif_level(&diag.level),
+                "message": { "text": diag.message },
+                "locations": diag.primary_span().map(|span| json!({
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": span.file },
+                        "region": sarif_region(span),
+                    }
+                })).into_iter().collect::<Vec<_>>(),
+                "fixes": fixes,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let rules = rules
+        .iter()
+        .map(|(lint, group)| {
+            json!({
+                "id": format!("clippy::{lint}"),
+                "name": lint,
+                "helpUri": format!("{DOCS_LINK}#{lint}"),
+                "properties": { "group": group },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "clippy",
+                    "informationUri": "https://github.com/rust-lang/rust-clippy",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }],
+    })
+}
+
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Builds a Checkstyle XML report, grouping the diagnostics by file.
+fn checkstyle(diagnostics: &[ClippyDiagnostic]) -> String {
+    let mut by_file = BTreeMap::<&str, Vec<_>>::new();
+    for diag in diagnostics {
+        if let Some(span) = diag.primary_span() {
+            by_file.entry(&span.file).or_default().push((span, diag));
+        }
+    }
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle version=\"4.3\">\n");
+    for (file, diags) in by_file {
+        let _ = writeln!(out, "  <file name=\"{}\">", escape_xml(file));
+        for (span, diag) in diags {
+            let severity = match diag.level.as_str() {
+                "error" => "error",
+                "warning" => "warning",
+                _ => "info",
+            };
+            let _ = writeln!(
+                out,
+                "    <error line=\"{}\" column=\"{}\" severity=\"{severity}\" message=\"{}\" source=\"clippy::{}\"/>",
+                span.line_start,
+                span.column_start,
+                escape_xml(&diag.message),
+                diag.lint,
+            );
+        }
+        out.push_str("  </file>\n");
+    }
+    out.push_str("</checkstyle>");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checkstyle, collect, escape_xml, sarif, Collected};
+
+    /// A `needless_return` warning with a suggestion, as emitted by `cargo clippy
+    /// --message-format=json`
+    const NEEDLESS_RETURN: &str = r#"{"reason":"compiler-message","package_id":"demo 0.1.0 (path+file:///demo)","manifest_path":"/demo/Cargo.toml","target":{"kind":["lib"],"crate_types":["lib"],"name":"demo","src_path":"/demo/src/lib.rs","edition":"2021","doc":true,"doctest":true,"test":true},"message":{"rendered":"warning: unneeded `return` statement\n","children":[{"children":[],"code":null,"level":"note","message":"`#[warn(clippy::needless_return)]` on by default","rendered":null,"spans":[]},{"children":[],"code":null,"level":"help","message":"remove `return`","rendered":null,"spans":[{"byte_end":34,"byte_start":25,"column_end":14,"column_start":5,"expansion":null,"file_name":"src/lib.rs","is_primary":true,"label":null,"line_end":2,"line_start":2,"suggested_replacement":"1","suggestion_applicability":"MachineApplicable","text":[{"highlight_end":14,"highlight_start":5,"text":"    return 1;"}]}]}],"code":{"code":"clippy::needless_return","explanation":null},"level":"warning","message":"unneeded `return` statement","spans":[{"byte_end":34,"byte_start":25,"column_end":14,"column_start":5,"expansion":null,"file_name":"src/lib.rs","is_primary":true,"label":null,"line_end":2,"line_start":2,"suggested_replacement":null,"suggestion_applicability":null,"text":[{"highlight_end":14,"highlight_start":5,"text":"    return 1;"}]}]}}"#;
+
+    /// A warning whose message needs escaping in XML
+    const DBG_MACRO: &str = r#"{"reason":"compiler-message","package_id":"demo 0.1.0 (path+file:///demo)","message":{"rendered":"warning: `dbg!` with <&\"> in it\n","children":[],"code":{"code":"clippy::dbg_macro","explanation":null},"level":"warning","message":"`dbg!` with <&\"> in it","spans":[{"byte_end":60,"byte_start":53,"column_end":12,"column_start":5,"expansion":null,"file_name":"src/main.rs","is_primary":true,"label":null,"line_end":4,"line_start":4,"suggested_replacement":null,"suggestion_applicability":null,"text":[{"highlight_end":12,"highlight_start":5,"text":"    dbg!(x);"}]}]}}"#;
+
+    const NOT_CLIPPY: &str = r#"{"reason":"compiler-message","package_id":"demo 0.1.0 (path+file:///demo)","message":{"rendered":"warning: unused variable: `x`\n","children":[],"code":{"code":"unused_variables","explanation":null},"level":"warning","message":"unused variable: `x`","spans":[{"byte_end":20,"byte_start":19,"column_end":10,"column_start":9,"expansion":null,"file_name":"src/lib.rs","is_primary":true,"label":null,"line_end":5,"line_start":5,"suggested_replacement":null,"suggestion_applicability":null,"text":[]}]}}"#;
+
+    const BUILD_FINISHED: &str = r#"{"reason":"build-finished","success":true}"#;
+
+    fn collect_all(lines: &[&str]) -> Collected {
+        collect(lines.join("\n").as_bytes(), &mut Vec::new()).unwrap()
+    }
+
+    #[test]
+    fn test_collect_dedup() {
+        // The library and test targets of a crate emit the same warning twice
+        let collected = collect_all(&[NEEDLESS_RETURN, NOT_CLIPPY, NEEDLESS_RETURN, DBG_MACRO, BUILD_FINISHED]);
+        let lints: Vec<_> = collected.diagnostics.iter().map(|diag| diag.lint.as_str()).collect();
+        assert_eq!(lints, ["needless_return", "dbg_macro"]);
+
+        let diag = &collected.diagnostics[0];
+        assert_eq!(diag.group, Some("style"));
+        assert_eq!(diag.snippet, "return 1;");
+        assert_eq!(diag.suggestions.len(), 1);
+        assert_eq!(diag.suggestions[0].applicability.as_deref(), Some("MachineApplicable"));
+        assert_eq!(diag.suggestions[0].replacements[0].text, "1");
+    }
+
+    #[test]
+    fn test_collect_human_output() {
+        let mut human = Vec::new();
+        let collected = collect(
+            [NEEDLESS_RETURN, "build script output", NOT_CLIPPY, BUILD_FINISHED]
+                .join("\n")
+                .as_bytes(),
+            &mut human,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(human).unwrap(),
+            "warning: unneeded `return` statement\nbuild script output\nwarning: unused variable: `x`\n"
+        );
+        assert_eq!(collected.diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn test_sarif() {
+        let collected = collect_all(&[NEEDLESS_RETURN, DBG_MACRO]);
+        let log = sarif(&collected.diagnostics);
+        assert_eq!(log["version"], "2.1.0");
+
+        let run = &log["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0]["id"], "clippy::dbg_macro");
+        assert_eq!(rules[1]["id"], "clippy::needless_return");
+
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "clippy::needless_return");
+        assert_eq!(result["ruleIndex"], 1);
+        assert_eq!(result["level"], "warning");
+        assert_eq!(result["message"]["text"], "unneeded `return` statement");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/lib.rs");
+        let region = &location["region"];
+        assert_eq!(
+            (
+                &region["startLine"],
+                &region["startColumn"],
+                &region["endLine"],
+                &region["endColumn"]
+            ),
+            (&2.into(), &5.into(), &2.into(), &14.into())
+        );
+        assert_eq!((&region["byteOffset"], &region["byteLength"]), (&25.into(), &9.into()));
+
+        let replacement = &result["fixes"][0]["artifactChanges"][0]["replacements"][0];
+        assert_eq!(replacement["insertedContent"]["text"], "1");
+        assert_eq!(run["results"][1]["ruleIndex"], 0);
+    }
+
+    #[test]
+    fn test_checkstyle() {
+        let collected = collect_all(&[NEEDLESS_RETURN, DBG_MACRO]);
+        assert_eq!(
+            checkstyle(&collected.diagnostics),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+            <checkstyle version=\"4.3\">\n  \
+              <file name=\"src/lib.rs\">\n    \
+                <error line=\"2\" column=\"5\" severity=\"warning\" message=\"unneeded `return` statement\" source=\"clippy::needless_return\"/>\n  \
+              </file>\n  \
+              <file name=\"src/main.rs\">\n    \
+                <error line=\"4\" column=\"5\" severity=\"warning\" message=\"`dbg!` with &lt;&amp;&quot;&gt; in it\" source=\"clippy::dbg_macro\"/>\n  \
+              </file>\n\
+            </checkstyle>"
+        );
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(escape_xml("<&\"'>\nx"), "&lt;&amp;&quot;&apos;&gt;&#10;x");
+    }
+}
@@ -211,6 +211,14 @@ fn run_ui_toml() {
                 if file_path.extension() != Some(OsStr::new("rs")) {
                     continue;
                 }
+                let headers = TomlTestHeaders::parse(&file_path)?;
+                let _conf_dir = VarGuard::set(
+                    "CLIPPY_CONF_DIR",
+                    headers
+                        .conf_dir
+                        .map_or_else(|| dir_path.clone(), |conf_dir| dir_path.join(conf_dir)),
+                );
+                let _rust_version = VarGuard::set("CARGO_PKG_RUST_VERSION", headers.rust_version.unwrap_or_default());
                 let paths = compiletest::common::TestPaths {
                     file: file_path,
                     base: config.src_base.clone(),
@@ -242,6 +250,38 @@ fn run_ui_toml() {
     }
 }
 
+/// The headers of a `ui-toml` test, written as `// <name>: <value>` lines:
+///
+/// - `clippy-conf-dir`: the directory to look for the configuration in, relative to the directory
+///   of the test, for the configurations in nested directories or inheriting from each other
+/// - `cargo-rust-version`: the `rust-version` of the package, which Clippy reads from
+///   `CARGO_PKG_RUST_VERSION`
+///
+/// The errors in the configuration are expected in the `.stderr` file, like the lints.
+#[derive(Default)]
+struct TomlTestHeaders {
+    conf_dir: Option<PathBuf>,
+    rust_version: Option<String>,
+}
+
+impl TomlTestHeaders {
+    fn parse(path: &Path) -> io::Result<Self> {
+        let mut headers = Self::default();
+        for line in fs::read_to_string(path)?.lines() {
+            let header = match line.strip_prefix("//") {
+                Some(header) => header.trim(),
+                None => continue,
+            };
+            if let Some(conf_dir) = header.strip_prefix("clippy-conf-dir:") {
+                headers.conf_dir = Some(PathBuf::from(conf_dir.trim()));
+            } else if let Some(rust_version) = header.strip_prefix("cargo-rust-version:") {
+                headers.rust_version = Some(rust_version.trim().to_string());
+            }
+        }
+        Ok(headers)
+    }
+}
+
 fn run_ui_cargo() {
     fn run_tests(
         config: &compiletest::Config,
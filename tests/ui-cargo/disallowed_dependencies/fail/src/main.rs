@@ -0,0 +1,3 @@
+// compile-flags: --crate-name=disallowed_dependencies
+
+fn main() {}
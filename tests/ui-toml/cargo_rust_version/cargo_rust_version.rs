@@ -0,0 +1,11 @@
+// cargo-rust-version: 1.44
+
+// `str::strip_prefix` was stabilized in 1.44, so `manual_strip` isn't emitted for the
+// `rust-version` of `Cargo.toml`
+
+fn main() {
+    let s = "hello, world!";
+    if s.starts_with("hello, ") {
+        assert_eq!(s["hello, ".len()..].to_uppercase(), "WORLD!");
+    }
+}
@@ -0,0 +1,11 @@
+// cargo-rust-version: 1.44
+// clippy-conf-dir: conf
+
+// The `msrv` of `clippy.toml` takes precedence over the `rust-version` of `Cargo.toml`
+
+fn main() {
+    let s = "hello, world!";
+    if s.starts_with("hello, ") {
+        assert_eq!(s["hello, ".len()..].to_uppercase(), "WORLD!");
+    }
+}
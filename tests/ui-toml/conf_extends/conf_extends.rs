@@ -0,0 +1,12 @@
+// The configuration extends `base/clippy.toml`: the lists of disallowed names are merged, the
+// threshold is replaced and the lint levels of both files apply.
+
+fn main() {
+    let toto = 1;
+    let tata = 2;
+    let _ = toto + tata + needless_return();
+}
+
+fn needless_return() -> u8 {
+    return 1;
+}
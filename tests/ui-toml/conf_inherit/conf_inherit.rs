@@ -0,0 +1,10 @@
+// clippy-conf-dir: inner
+
+// `inner/clippy.toml` inherits from the `clippy.toml` of the parent directory: the lists of
+// disallowed names are merged and the threshold is replaced.
+
+fn main() {
+    let toto = 1;
+    let tata = 2;
+    let _ = toto + tata;
+}
@@ -0,0 +1,3 @@
+// clippy-conf-dir: cycle/a
+
+fn main() {}
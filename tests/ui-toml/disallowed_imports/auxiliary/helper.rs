@@ -0,0 +1,11 @@
+pub mod net {
+    pub struct Socket;
+
+    pub fn connect() -> Socket {
+        Socket
+    }
+}
+
+pub mod fmt {
+    pub fn pretty() {}
+}
@@ -0,0 +1,27 @@
+// aux-build:helper.rs
+
+#![warn(clippy::disallowed_imports)]
+
+extern crate helper;
+
+use helper::net::{self, Socket};
+use std::net::Ipv4Addr;
+use std::sync::Arc;
+
+fn main() {
+    let _: Socket = net::connect();
+    ::helper::fmt::pretty();
+    let _ = Ipv4Addr::LOCALHOST;
+    let _ = std::net::Ipv6Addr::LOCALHOST;
+    let _ = std::collections::HashMap::<u8, u8>::new();
+    transport::connect();
+    let _ = Arc::new(1);
+}
+
+mod transport {
+    use std::net::TcpStream;
+
+    pub fn connect() {
+        let _ = TcpStream::connect("localhost:80");
+    }
+}
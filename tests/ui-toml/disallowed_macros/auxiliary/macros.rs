@@ -0,0 +1,15 @@
+#[macro_export]
+macro_rules! expr_macro {
+    () => {
+        1
+    };
+}
+
+#[macro_export]
+macro_rules! item_macro {
+    () => {
+        pub struct S;
+    };
+}
+
+pub use expr_macro as renamed;
@@ -0,0 +1,25 @@
+// aux-build:macros.rs
+
+#![warn(clippy::disallowed_macros)]
+
+extern crate macros;
+
+use macros::renamed as other_name;
+use std::println as say;
+
+fn main() {
+    println!("one");
+    say!("two");
+    let _ = macros::expr_macro!();
+    let _ = other_name!();
+    let _ = 2 + macros::expr_macro!();
+    prototype::run();
+}
+
+macros::item_macro!();
+
+mod prototype {
+    pub fn run() {
+        println!("allowed here");
+    }
+}
@@ -0,0 +1,59 @@
+#![warn(clippy::disallowed_methods, clippy::disallowed_types)]
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+struct Sink;
+
+impl Write for Sink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn trait_methods(file: &mut std::fs::File, sink: &mut Sink, writer: &mut impl Write) {
+    let _ = file.flush();
+    let _ = sink.flush();
+    let _ = writer.flush();
+    let _ = Write::flush(sink);
+}
+
+fn patterns(_: &Mutex<()>) {
+    let _ = std::process::Command::new("ls").status();
+    let _ = std::process::id();
+    let _ = vec![1].into_boxed_slice();
+    // `into_iter` is a method of `IntoIterator`, not of `Vec`
+    let _ = vec![1].into_iter();
+}
+
+// `Arc` is defined in `alloc::sync` and re-exported in `std::sync`
+fn reexported(arc: Arc<u8>) -> std::sync::Weak<u8> {
+    Arc::downgrade(&arc)
+}
+
+mod cli {
+    pub fn run() {
+        let _ = std::process::Command::new("ls").status();
+    }
+}
+
+mod sync {
+    pub mod imp {
+        pub fn lock(lock: &std::sync::Mutex<()>) {
+            drop(lock.lock());
+        }
+    }
+}
+
+fn main() {
+    let mut sink = Sink;
+    trait_methods(&mut std::fs::File::open("x").unwrap(), &mut Sink, &mut sink);
+    patterns(&Mutex::new(()));
+    cli::run();
+    sync::imp::lock(&Mutex::new(()));
+    let _ = reexported(Arc::new(1));
+}
@@ -0,0 +1,19 @@
+#![allow(clippy::missing_docs_in_private_items, clippy::blanket_clippy_restriction_lints)]
+
+fn needless_return() -> u8 {
+    return 1;
+}
+
+fn unwrap(x: Option<u8>) -> u8 {
+    x.unwrap()
+}
+
+fn expect(x: Option<u8>) -> u8 {
+    x.expect("value")
+}
+
+fn main() {
+    needless_return();
+    unwrap(Some(1));
+    expect(Some(1));
+}
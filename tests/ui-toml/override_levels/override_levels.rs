@@ -0,0 +1,39 @@
+#![feature(lint_reasons)]
+#![allow(dead_code)]
+
+// The levels of `clippy.toml` apply to the whole file, and the attributes in it take precedence
+// over them
+
+fn unwrap(x: Option<u8>) -> u8 {
+    x.unwrap()
+}
+
+fn expect(x: Option<u8>) -> u8 {
+    x.expect("value")
+}
+
+fn needless_return() -> u8 {
+    return 1;
+}
+
+// `double_neg` is checked before type checking, where the levels of `clippy.toml` don't apply
+fn double_neg(x: i32) -> i32 {
+    --x
+}
+
+#[expect(clippy::unwrap_used)]
+fn expected_unwrap(x: Option<u8>) -> u8 {
+    x.unwrap()
+}
+
+#[expect(clippy::needless_return)]
+fn expected_needless_return() -> u8 {
+    return 1;
+}
+
+#[warn(clippy::needless_return)]
+fn warned_needless_return() -> u8 {
+    return 1;
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+// compile-flags: -F clippy::needless_return
+#![allow(dead_code)]
+
+// The levels forbidden on the command line can't be changed by `clippy.toml`
+
+fn needless_return() -> u8 {
+    return 1;
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+#![warn(clippy::too_many_lines, clippy::struct_excessive_bools)]
+#![allow(dead_code)]
+
+// The first `[[overrides]]` table of `clippy.toml` applies to this file.
+
+fn too_many_arguments(_: u8, _: u8, _: u8) {}
+
+fn three_lines() {
+    println!("This is good.");
+    println!("This is good.");
+    println!("This is good.");
+}
+
+fn four_lines() {
+    println!("This is bad.");
+    println!("This is bad.");
+    println!("This is bad.");
+    println!("This is bad.");
+}
+
+fn unwrap(x: Option<u8>) -> u8 {
+    x.unwrap()
+}
+
+#[allow(clippy::unwrap_used)]
+fn unwrap_allowed(x: Option<u8>) -> u8 {
+    x.unwrap()
+}
+
+struct TwoBools {
+    a: bool,
+    b: bool,
+}
+
+struct ThreeBools {
+    a: bool,
+    b: bool,
+    c: bool,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+// clippy-conf-dir: inner
+// cargo-rust-version: 1.60
+// rustc-env:CLIPPY_PRINT_CONFIG=1
+// compile-flags: --crate-name print_config
+
+// `cargo clippy --print-config` shows where each value comes from: the inheriting file, the
+// inherited one, both of them for merged lists, or the defaults. The MSRV comes from `Cargo.toml`.
+
+fn main() {}
@@ -0,0 +1,37 @@
+// run-rustfix
+// rustc-env:CLIPPY_ARGS=--add-allows=expect__CLIPPY_HACKERY__--allow-reason=existing warnings
+#![feature(lint_reasons)]
+#![warn(clippy::needless_return, clippy::double_neg)]
+#![allow(dead_code)]
+
+fn outer() -> i32 {
+    fn inner() -> i32 {
+        return 1;
+    }
+    // The attribute goes on the function, not on the closure or the array length
+    let closure = |x: i32| --x;
+    let array = [0; { let x = 2_i32; --x as usize }];
+    inner() + closure(1) + array.len() as i32
+}
+
+#[inline]
+#[allow(clippy::double_neg)]
+fn with_attribute(x: i32) -> i32 {
+    return --x;
+}
+
+struct S;
+
+impl S {
+    fn method(&self) -> i32 {
+        return 1;
+    }
+}
+
+trait T {
+    fn provided(&self) -> i32 {
+        return 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,48 @@
+#![allow(dead_code, clippy::derivable_impls, clippy::single_match)]
+
+#[clippy::author]
+struct Point {
+    x: i32,
+    y: (u8, [u8; 4]),
+}
+
+#[clippy::author]
+enum Shape {
+    Empty,
+    Circle(f64),
+}
+
+#[clippy::author]
+type Callback<'a> = &'a mut dyn Fn(*const u8);
+
+#[clippy::author]
+trait Area {
+    #[clippy::author]
+    fn area(&self) -> f64;
+}
+
+#[clippy::author]
+impl Area for Shape {
+    #[clippy::author]
+    fn area(&self) -> f64 {
+        match *self {
+            Shape::Empty => 0.0,
+            Shape::Circle(r) => r * r,
+        }
+    }
+}
+
+#[clippy::author = "lint"]
+impl Default for Point {
+    fn default() -> Self {
+        Self { x: 0, y: (0, [0; 4]) }
+    }
+}
+
+fn main() {
+    match [0, 1, 2] {
+        #[clippy::author]
+        [_first, .., ref _last @ 1..=9] => {},
+        _ => {},
+    }
+}
@@ -0,0 +1,33 @@
+#![feature(stmt_expr_attributes)]
+
+fn main() {
+    let opt = Some(1);
+
+    #[clippy::author = "lint"]
+    if let Some(x) = opt {
+        let _ = ::std::cmp::min(x, 4);
+    }
+
+    #[clippy::author = "lint"]
+    if opt.is_none() {
+        let _ = Vec::<u8>::new();
+    }
+
+    #[clippy::author = "lint"]
+    for y in 0..10 {
+        let _ = y as u64;
+    }
+
+    #[clippy::author = "lint"]
+    let _ = opt.map(|x| x + 1);
+
+    let _ = is_some(opt);
+}
+
+#[clippy::author = "lint"]
+fn is_some(v: Option<i32>) -> bool {
+    match v {
+        None => false,
+        Some(_) => true,
+    }
+}